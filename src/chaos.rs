@@ -1,3 +1,4 @@
+pub mod analysis;
 pub mod benchmark;
 pub mod data;
 mod execution;
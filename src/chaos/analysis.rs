@@ -0,0 +1,42 @@
+mod basins;
+mod control;
+mod density;
+mod dimension;
+mod embedding;
+mod entropy;
+mod kd_tree;
+mod kuramoto;
+mod lattice;
+mod lyapunov;
+mod network;
+mod parameter_plane;
+mod periodicity;
+mod poincare;
+mod recurrence;
+mod return_map;
+mod rotation;
+mod scaling;
+mod spectrum;
+mod trajectory;
+mod zero_one;
+pub use self::basins::*;
+pub use self::control::*;
+pub use self::density::*;
+pub use self::dimension::*;
+pub use self::embedding::*;
+pub use self::entropy::*;
+pub use self::kd_tree::*;
+pub use self::kuramoto::*;
+pub use self::lattice::*;
+pub use self::lyapunov::*;
+pub use self::network::*;
+pub use self::parameter_plane::*;
+pub use self::periodicity::*;
+pub use self::poincare::*;
+pub use self::recurrence::*;
+pub use self::return_map::*;
+pub use self::rotation::*;
+pub use self::scaling::*;
+pub use self::spectrum::*;
+pub use self::trajectory::*;
+pub use self::zero_one::*;
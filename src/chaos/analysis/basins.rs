@@ -0,0 +1,345 @@
+use super::lyapunov::state_vec;
+use super::trajectory::{check_dimensions, evaluate_cells, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{
+    data::*,
+    functions::{DiscreteMap, OdeSolverTrait},
+};
+use anyhow::{bail, Error};
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Attractor found by clustering the final states of a mesh of initial states.
+pub struct Attractor {
+    // number of initial states that end on this attractor
+    pub basin_size: usize,
+    pub centroid: Vec<ChaosFloat>,
+    // number of grid cells visited by the final states, one for a fixed point
+    pub num_cells: usize,
+}
+
+pub struct Basins {
+    // attractor index per initial state in mesh order, None for diverging samples
+    pub labels: Vec<Option<usize>>,
+    // sorted by decreasing basin size
+    pub attractors: Vec<Attractor>,
+}
+
+impl Basins {
+    pub fn num_diverged(&self) -> usize {
+        self.labels.iter().filter(|label| label.is_none()).count()
+    }
+}
+
+pub struct BasinResult {
+    pub pars: (&'static str, Vec<f64>),
+    // state index and range of both mesh axes
+    pub x_axis: (usize, (ChaosFloat, ChaosFloat)),
+    pub y_axis: (usize, (ChaosFloat, ChaosFloat)),
+    // number of initial states per mesh axis
+    pub grid_size: usize,
+    // one entry per discrete map or differential system
+    pub basins: Vec<Basins>,
+}
+
+impl BasinResult {
+    /// Attractor labels of a data set row-major with the y-axis as outer index.
+    pub fn grid_labels(&self, index: usize) -> Vec<Option<ChaosFloat>> {
+        let n = self.grid_size;
+        let labels = &self.basins[index].labels;
+        // the first mesh axis is the outer index of the initial states
+        (0..n)
+            .flat_map(|y| (0..n).map(move |x| labels[x * n + y].map(|l| l as ChaosFloat)))
+            .collect()
+    }
+}
+
+pub fn basins_of_attraction(
+    schema: TrajectorySchema,
+    num_cells: usize,
+) -> Result<BasinResult, Error> {
+    let TrajectorySchema {
+        num_samples,
+        init_distr,
+        discrete_map_vec,
+        diff_system_vec,
+        pars,
+        conf,
+        ..
+    } = schema;
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Basins of attraction are only available for state distributions!"),
+    };
+    let mesh_axes: Vec<(usize, (ChaosFloat, ChaosFloat))> = init_distr
+        .iter()
+        .enumerate()
+        .filter_map(|(i, distr)| match distr {
+            InitialDistributionVariant::Mesh(mesh) => Some((i, (mesh.start, mesh.end))),
+            _ => None,
+        })
+        .collect();
+    let [x_axis, y_axis] = mesh_axes[..] else {
+        bail!("Basins of attraction need exactly two mesh distributions as initial grid!")
+    };
+    let basins = if let Some(map_vec) = discrete_map_vec {
+        map_vec.basins(num_samples, &init_distr, &conf, num_cells)?
+    } else if let Some(system_vec) = diff_system_vec {
+        system_vec.basins(num_samples, &init_distr, &conf, num_cells)?
+    } else {
+        bail!("Cannot compute basins of attraction without a chaotic function!")
+    };
+    Ok(BasinResult {
+        pars,
+        x_axis,
+        y_axis,
+        grid_size: num_samples,
+        basins,
+    })
+}
+
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (root_a, root_b) = (find_root(parents, a), find_root(parents, b));
+    if root_a != root_b {
+        parents[root_a.max(root_b)] = root_a.min(root_b);
+    }
+}
+
+fn neighbor_offsets(num_dims: usize) -> Vec<Vec<i64>> {
+    (0..3_usize.pow(num_dims as u32))
+        .map(|code| {
+            (0..num_dims)
+                .map(|axis| (code / 3_usize.pow(axis as u32) % 3) as i64 - 1)
+                .collect::<Vec<i64>>()
+        })
+        .filter(|offset| offset.iter().any(|o| *o != 0))
+        .collect()
+}
+
+/// Clusters samples whose final states visit the same or adjacent cells of a grid
+/// with `num_cells` cells along the largest extent of all final states.
+/// Each tail holds the final states of a sample as consecutive coordinates.
+pub fn label_attractors(
+    tails: &[Option<Vec<ChaosFloat>>],
+    num_dims: usize,
+    num_cells: usize,
+) -> Basins {
+    let (mins, maxs) = tails
+        .iter()
+        .flatten()
+        .flat_map(|tail| tail.chunks(num_dims))
+        .fold(
+            (
+                vec![ChaosFloat::INFINITY; num_dims],
+                vec![ChaosFloat::NEG_INFINITY; num_dims],
+            ),
+            |(mut mins, mut maxs), p| {
+                p.iter().enumerate().for_each(|(axis, x)| {
+                    mins[axis] = mins[axis].min(*x);
+                    maxs[axis] = maxs[axis].max(*x);
+                });
+                (mins, maxs)
+            },
+        );
+    let extent = mins
+        .iter()
+        .zip(maxs.iter())
+        .map(|(min, max)| max - min)
+        .fold(0.0, ChaosFloat::max);
+    let cell_size = if extent > 0.0 && extent.is_finite() {
+        extent / num_cells.max(1) as ChaosFloat
+    } else {
+        1.0 // all final states coincide
+    };
+    let cell_of = |p: &[ChaosFloat]| -> Vec<i64> {
+        p.iter()
+            .zip(mins.iter())
+            .map(|(x, min)| ((x - min) / cell_size).floor() as i64)
+            .collect()
+    };
+    let mut parents: Vec<usize> = (0..tails.len()).collect();
+    // first sample that visited a cell
+    let mut cells: HashMap<Vec<i64>, usize> = HashMap::new();
+    tails.iter().enumerate().for_each(|(sample, tail)| {
+        tail.iter()
+            .flat_map(|tail| tail.chunks(num_dims))
+            .for_each(|p| match cells.entry(cell_of(p)) {
+                Entry::Occupied(entry) => union(&mut parents, *entry.get(), sample),
+                Entry::Vacant(entry) => {
+                    entry.insert(sample);
+                }
+            });
+    });
+    // final states that converge to a point on a cell border may end in adjacent cells
+    let offsets = neighbor_offsets(num_dims);
+    cells.iter().for_each(|(cell, sample)| {
+        offsets.iter().for_each(|offset| {
+            let neighbor: Vec<i64> = cell.iter().zip(offset.iter()).map(|(c, o)| c + o).collect();
+            if let Some(other) = cells.get(&neighbor) {
+                union(&mut parents, *sample, *other);
+            }
+        });
+    });
+    // basin size, sum of final states and number of cells per root sample
+    let mut clusters: HashMap<usize, (usize, Vec<ChaosFloat>, usize)> = HashMap::new();
+    tails.iter().enumerate().for_each(|(sample, tail)| {
+        if let Some(last) = tail.as_ref().and_then(|tail| tail.chunks(num_dims).last()) {
+            let root = find_root(&mut parents, sample);
+            let (size, sum, _) = clusters
+                .entry(root)
+                .or_insert_with(|| (0, vec![0.0; num_dims], 0));
+            *size += 1;
+            sum.iter_mut().zip(last.iter()).for_each(|(s, x)| *s += x);
+        }
+    });
+    cells.values().for_each(|sample| {
+        let root = find_root(&mut parents, *sample);
+        if let Some((_, _, num_cells)) = clusters.get_mut(&root) {
+            *num_cells += 1;
+        }
+    });
+    let mut roots: Vec<usize> = clusters.keys().copied().collect();
+    roots.sort_by_key(|root| (std::cmp::Reverse(clusters[root].0), *root));
+    let root_labels: HashMap<usize, usize> = roots
+        .iter()
+        .enumerate()
+        .map(|(label, root)| (*root, label))
+        .collect();
+    let labels = tails
+        .iter()
+        .enumerate()
+        .map(|(sample, tail)| match tail {
+            Some(tail) if !tail.is_empty() => Some(root_labels[&find_root(&mut parents, sample)]),
+            _ => None,
+        })
+        .collect();
+    let attractors = roots
+        .iter()
+        .map(|root| {
+            let (basin_size, sum, num_cells) = &clusters[root];
+            Attractor {
+                basin_size: *basin_size,
+                centroid: sum.iter().map(|s| s / *basin_size as ChaosFloat).collect(),
+                num_cells: *num_cells,
+            }
+        })
+        .collect();
+    Basins { labels, attractors }
+}
+
+pub fn discrete_basins<V, D>(
+    maps: &[D],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &TrajectoryConf,
+    num_cells: usize,
+) -> Result<Vec<Basins>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    D: DiscreteMap<State = V> + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    Ok(evaluate_cells(maps, |map| {
+        let tails: Vec<Option<Vec<ChaosFloat>>> = init_data
+            .data()
+            .iter()
+            .map(|y0| {
+                let mut y = y0.as_ref()?.clone();
+                let mut t: Time = 0.0;
+                let mut tail = Vec::with_capacity(conf.num_steps * num_dims);
+                for step in 0..(conf.num_transient + conf.num_steps) {
+                    map.execute(&mut y, &t);
+                    t += 1.0;
+                    if !y.is_valid() {
+                        return None;
+                    }
+                    if step >= conf.num_transient {
+                        tail.extend(state_vec(&y, num_dims));
+                    }
+                }
+                Some(tail)
+            })
+            .collect();
+        label_attractors(&tails, num_dims, num_cells)
+    }))
+}
+
+pub fn continuous_basins<V, O>(
+    solvers: &[O],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &TrajectoryConf,
+    num_cells: usize,
+) -> Result<Vec<Basins>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    O: OdeSolverTrait<State = V> + Clone + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    Ok(evaluate_cells(solvers, |solver| {
+        let mut solver = solver.clone();
+        let mut states = init_data.data().clone();
+        solver.initial_states(&mut states);
+        solver.execute(&mut states, conf.num_transient);
+        let mut tails: Vec<Option<Vec<ChaosFloat>>> = states
+            .iter()
+            .map(|state| {
+                state
+                    .as_ref()
+                    .map(|_| Vec::with_capacity(conf.num_steps * num_dims))
+            })
+            .collect();
+        for _ in 0..conf.num_steps {
+            solver.execute(&mut states, 1);
+            states
+                .iter()
+                .zip(tails.iter_mut())
+                .for_each(|(state, tail)| match (state, tail.as_mut()) {
+                    (Some(y), Some(tail)) => tail.extend(state_vec(y, num_dims)),
+                    _ => *tail = None, // escaped
+                });
+        }
+        label_attractors(&tails, num_dims, num_cells)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_label_attractors() {
+        let fixed_point = |x: ChaosFloat, y: ChaosFloat| Some(vec![x, y, x, y]);
+        let tails = vec![
+            // both sides of a cell border converge to the same fixed point
+            fixed_point(1.0 - 1e-12, 1.0),
+            fixed_point(1.0 + 1e-12, 1.0),
+            None,
+            fixed_point(10.0, 10.0),
+            // period two orbit visited at both phases
+            Some(vec![0.0, 0.0, 0.0, 5.0]),
+            Some(vec![0.0, 5.0, 0.0, 0.0]),
+            fixed_point(1.0, 1.0),
+        ];
+        let basins = label_attractors(&tails, 2, 100);
+        assert_eq!(basins.attractors.len(), 3);
+        assert_eq!(
+            basins.labels,
+            vec![Some(0), Some(0), None, Some(2), Some(1), Some(1), Some(0)]
+        );
+        assert_eq!(basins.num_diverged(), 1);
+        assert_eq!(basins.attractors[0].basin_size, 3);
+        assert_eq!(basins.attractors[0].num_cells, 2);
+        assert_eq!(basins.attractors[1].num_cells, 2);
+        assert_eq!(basins.attractors[2].centroid, vec![10.0, 10.0]);
+    }
+}
@@ -0,0 +1,606 @@
+use super::trajectory::{check_dimensions, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{
+    data::*,
+    functions::{
+        rk4_step, DiscreteMap, Henon, Logistic, SimpleDiscreteMap, NUM_RK4_SUBSTEPS, ODE_STEP_SIZE,
+    },
+};
+use anyhow::{bail, Error};
+use nalgebra::{SMatrix, SVector};
+use ode_solvers::System;
+
+// iterations of the uncontrolled map that seed the periodic orbit search
+const NUM_SEEDS: usize = 500;
+const MAX_NEWTON_STEPS: usize = 50;
+const ORBIT_TOLERANCE: ChaosFloat = 1e-10;
+// points closer than this belong to the same orbit
+const DISTINCT_TOLERANCE: ChaosFloat = 1e-6;
+const FINITE_DIFFERENCE: ChaosFloat = 1e-7;
+const NUM_POWER_ITERATIONS: usize = 100;
+
+pub struct ControlConf {
+    // recorded step at which the control is switched on
+    pub switch_on: usize,
+    // OGY: period of the target orbit and its index among all orbits found
+    pub period: usize,
+    pub orbit: usize,
+    // OGY: largest allowed perturbation of the control parameter
+    pub max_perturbation: ChaosFloat,
+    // Pyragas: gain K and delay τ of the feedback in the controlled variable
+    pub gain: ChaosFloat,
+    pub delay: Time,
+    pub variable: usize,
+}
+
+pub struct ControlledEvolution {
+    // states per recorded step, ends early if the state escaped
+    pub states: Vec<Vec<ChaosFloat>>,
+    // parameter perturbation (OGY) or feedback force (Pyragas) per recorded step
+    pub control_signals: Vec<ChaosFloat>,
+    // target orbit of the OGY control, empty for Pyragas or if no orbit was found
+    pub target_orbit: Vec<Vec<ChaosFloat>>,
+}
+
+pub struct ControlResult {
+    pub pars: (&'static str, Vec<f64>),
+    // OGY control of a discrete map or Pyragas control of a flow
+    pub is_discrete: bool,
+    pub switch_on: usize,
+    // time between two recorded states
+    pub sample_time: Time,
+    // one entry per discrete map or differential system
+    pub evolutions: Vec<ControlledEvolution>,
+}
+
+impl ControlResult {
+    /// Mean absolute control signal over the second half of the controlled phase, close to 0 on a stabilized orbit.
+    pub fn final_control_effort(&self, index: usize) -> Option<ChaosFloat> {
+        let signals = self
+            .evolutions
+            .get(index)?
+            .control_signals
+            .get(self.switch_on..)?;
+        let signals = &signals[signals.len() / 2..];
+        if signals.is_empty() {
+            None
+        } else {
+            Some(signals.iter().map(|s| s.abs()).sum::<ChaosFloat>() / signals.len() as ChaosFloat)
+        }
+    }
+}
+
+/// Maps with a parameter that OGY control may perturb.
+pub trait ControlParameter: Sized {
+    fn perturbed(&self, delta: ChaosFloat) -> Self;
+}
+
+impl ControlParameter for SimpleDiscreteMap<Logistic> {
+    fn perturbed(&self, delta: ChaosFloat) -> Self {
+        Self::new(Logistic {
+            r: self.conf().r + delta,
+        })
+    }
+}
+
+impl ControlParameter for SimpleDiscreteMap<Henon> {
+    fn perturbed(&self, delta: ChaosFloat) -> Self {
+        Self::new(Henon {
+            a: self.conf().a + delta,
+            b: self.conf().b,
+        })
+    }
+}
+
+fn iterate<const N: usize, M: DiscreteMap<State = SVector<ChaosFloat, N>>>(
+    map: &M,
+    z: &SVector<ChaosFloat, N>,
+) -> SVector<ChaosFloat, N> {
+    let mut next = *z;
+    map.execute(&mut next, &0.0);
+    next
+}
+
+fn jacobian<const N: usize, M: DiscreteMap<State = SVector<ChaosFloat, N>>>(
+    map: &M,
+    z: &SVector<ChaosFloat, N>,
+) -> SMatrix<ChaosFloat, N, N> {
+    let mut jacobian = SMatrix::<ChaosFloat, N, N>::zeros();
+    for k in 0..N {
+        let mut offset = SVector::<ChaosFloat, N>::zeros();
+        offset[k] = FINITE_DIFFERENCE;
+        let column =
+            (iterate(map, &(z + offset)) - iterate(map, &(z - offset))) / (2.0 * FINITE_DIFFERENCE);
+        jacobian.set_column(k, &column);
+    }
+    jacobian
+}
+
+fn parameter_derivative<const N: usize, M>(
+    map: &M,
+    z: &SVector<ChaosFloat, N>,
+) -> SVector<ChaosFloat, N>
+where
+    M: DiscreteMap<State = SVector<ChaosFloat, N>> + ControlParameter,
+{
+    (iterate(&map.perturbed(FINITE_DIFFERENCE), z) - iterate(&map.perturbed(-FINITE_DIFFERENCE), z))
+        / (2.0 * FINITE_DIFFERENCE)
+}
+
+/// Newton's method on F^p(z) - z, returns the orbit if z converges to a point of minimal period p.
+fn refine_orbit<const N: usize, M: DiscreteMap<State = SVector<ChaosFloat, N>>>(
+    map: &M,
+    seed: &SVector<ChaosFloat, N>,
+    period: usize,
+) -> Option<Vec<SVector<ChaosFloat, N>>>
+where
+    SVector<ChaosFloat, N>: ValidStateCheck,
+{
+    let identity = SMatrix::<ChaosFloat, N, N>::identity();
+    let mut z = *seed;
+    for _ in 0..MAX_NEWTON_STEPS {
+        let mut image = z;
+        let mut monodromy = identity;
+        for _ in 0..period {
+            monodromy = jacobian(map, &image) * monodromy;
+            image = iterate(map, &image);
+        }
+        let residual = image - z;
+        if !image.is_valid() {
+            return None;
+        }
+        if residual.norm() < ORBIT_TOLERANCE {
+            let mut orbit = vec![z];
+            for _ in 1..period {
+                orbit.push(iterate(map, orbit.last()?));
+            }
+            // points of a shorter orbit repeat before the full period
+            let is_minimal = orbit
+                .iter()
+                .skip(1)
+                .all(|point| (point - z).norm() > DISTINCT_TOLERANCE);
+            return is_minimal.then_some(orbit);
+        }
+        z -= (monodromy - identity).try_inverse()? * residual;
+    }
+    None
+}
+
+fn same_orbit<const N: usize>(a: &[SVector<ChaosFloat, N>], b: &[SVector<ChaosFloat, N>]) -> bool {
+    b.iter()
+        .any(|point| (point - a[0]).norm() < DISTINCT_TOLERANCE)
+}
+
+// mean distance of the orbit points to the closest states of the uncontrolled trajectory
+fn orbit_distance<const N: usize>(
+    orbit: &[SVector<ChaosFloat, N>],
+    seeds: &[SVector<ChaosFloat, N>],
+) -> ChaosFloat {
+    orbit
+        .iter()
+        .map(|point| {
+            seeds
+                .iter()
+                .map(|seed| (seed - point).norm())
+                .fold(ChaosFloat::INFINITY, ChaosFloat::min)
+        })
+        .sum::<ChaosFloat>()
+        / orbit.len() as ChaosFloat
+}
+
+/// Distinct periodic orbits of minimal period p, the orbits that the uncontrolled trajectory
+/// approaches most closely come first. Each orbit starts with its point of smallest first component.
+pub fn find_periodic_orbits<const N: usize, M: DiscreteMap<State = SVector<ChaosFloat, N>>>(
+    map: &M,
+    seeds: &[SVector<ChaosFloat, N>],
+    period: usize,
+) -> Vec<Vec<SVector<ChaosFloat, N>>>
+where
+    SVector<ChaosFloat, N>: ValidStateCheck,
+{
+    let mut orbits: Vec<Vec<SVector<ChaosFloat, N>>> = Vec::new();
+    for seed in seeds {
+        if let Some(mut orbit) = refine_orbit(map, seed, period) {
+            if !orbits.iter().any(|known| same_orbit(known, &orbit)) {
+                let start = (0..orbit.len())
+                    .min_by(|i, j| orbit[*i][0].total_cmp(&orbit[*j][0]))
+                    .unwrap_or_default();
+                orbit.rotate_left(start);
+                orbits.push(orbit);
+            }
+        }
+    }
+    // orbits far from the chaotic attractor are rarely approached by OGY control
+    orbits.sort_by(|a, b| orbit_distance(a, seeds).total_cmp(&orbit_distance(b, seeds)));
+    orbits
+}
+
+// left eigenvector of the dominant, i.e. unstable, eigenvalue of the monodromy matrix
+fn unstable_direction<const N: usize>(
+    monodromy: &SMatrix<ChaosFloat, N, N>,
+) -> SVector<ChaosFloat, N> {
+    let transposed = monodromy.transpose();
+    let mut direction = SVector::<ChaosFloat, N>::repeat(1.0);
+    for _ in 0..NUM_POWER_ITERATIONS {
+        let next = transposed * direction;
+        let norm = next.norm();
+        if norm < ChaosFloat::EPSILON {
+            break;
+        }
+        direction = next / norm;
+    }
+    direction
+}
+
+struct OgyController<const N: usize> {
+    orbit: Vec<SVector<ChaosFloat, N>>,
+    jacobians: Vec<SMatrix<ChaosFloat, N, N>>,
+    parameter_derivatives: Vec<SVector<ChaosFloat, N>>,
+    unstable_directions: Vec<SVector<ChaosFloat, N>>,
+    max_perturbation: ChaosFloat,
+}
+
+impl<const N: usize> OgyController<N> {
+    fn new<M>(map: &M, orbit: Vec<SVector<ChaosFloat, N>>, max_perturbation: ChaosFloat) -> Self
+    where
+        M: DiscreteMap<State = SVector<ChaosFloat, N>> + ControlParameter,
+    {
+        let jacobians: Vec<SMatrix<ChaosFloat, N, N>> =
+            orbit.iter().map(|point| jacobian(map, point)).collect();
+        let parameter_derivatives = orbit
+            .iter()
+            .map(|point| parameter_derivative(map, point))
+            .collect();
+        let period = orbit.len();
+        let unstable_directions = (0..period)
+            .map(|i| {
+                let monodromy = (0..period)
+                    .fold(SMatrix::<ChaosFloat, N, N>::identity(), |m, k| {
+                        jacobians[(i + k) % period] * m
+                    });
+                unstable_direction(&monodromy)
+            })
+            .collect();
+        Self {
+            orbit,
+            jacobians,
+            parameter_derivatives,
+            unstable_directions,
+            max_perturbation,
+        }
+    }
+
+    /// Perturbation that moves the next state onto the stable manifold of the next orbit point.
+    fn perturbation(&self, z: &SVector<ChaosFloat, N>) -> ChaosFloat {
+        let nearest = (0..self.orbit.len()).min_by(|i, j| {
+            (z - self.orbit[*i])
+                .norm()
+                .total_cmp(&(z - self.orbit[*j]).norm())
+        });
+        let i = match nearest {
+            Some(i) => i,
+            None => return 0.0,
+        };
+        let unstable = &self.unstable_directions[(i + 1) % self.orbit.len()];
+        let sensitivity = unstable.dot(&self.parameter_derivatives[i]);
+        if sensitivity.abs() < ChaosFloat::EPSILON {
+            return 0.0;
+        }
+        let delta = -unstable.dot(&(self.jacobians[i] * (z - self.orbit[i]))) / sensitivity;
+        // OGY waits until the chaotic motion comes close to the orbit
+        if delta.abs() <= self.max_perturbation {
+            delta
+        } else {
+            0.0
+        }
+    }
+}
+
+fn initial_state<const N: usize>(
+    init_distr: &[InitialDistributionVariant],
+) -> Result<SVector<ChaosFloat, N>, Error>
+where
+    SVector<ChaosFloat, N>: FromStateVec,
+{
+    check_dimensions(init_distr, N)?;
+    match ChaosData::<SVector<ChaosFloat, N>>::new(1, init_distr)
+        .data_filtered()
+        .first()
+    {
+        Some(state) => Ok(**state),
+        None => bail!("The initial distribution must provide a valid state!"),
+    }
+}
+
+fn ogy_evolution<const N: usize, M>(
+    map: &M,
+    initial: &SVector<ChaosFloat, N>,
+    conf: &ControlConf,
+    trajectory_conf: &TrajectoryConf,
+) -> ControlledEvolution
+where
+    M: DiscreteMap<State = SVector<ChaosFloat, N>> + ControlParameter,
+    SVector<ChaosFloat, N>: ValidStateCheck,
+{
+    let mut seeds = Vec::with_capacity(NUM_SEEDS);
+    let mut z = *initial;
+    for _ in 0..NUM_SEEDS {
+        z = iterate(map, &z);
+        if !z.is_valid() {
+            break;
+        }
+        seeds.push(z);
+    }
+    let orbits = find_periodic_orbits(map, &seeds, conf.period);
+    let controller = orbits
+        .get(conf.orbit.min(orbits.len().saturating_sub(1)))
+        .map(|orbit| OgyController::new(map, orbit.clone(), conf.max_perturbation));
+    let mut evolution = ControlledEvolution {
+        states: Vec::with_capacity(trajectory_conf.num_steps),
+        control_signals: Vec::with_capacity(trajectory_conf.num_steps),
+        target_orbit: controller
+            .as_ref()
+            .map(|controller| {
+                controller
+                    .orbit
+                    .iter()
+                    .map(|point| point.iter().copied().collect())
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    let mut z = *initial;
+    for step in 0..(trajectory_conf.num_transient + trajectory_conf.num_steps) {
+        let recorded_step = step.checked_sub(trajectory_conf.num_transient);
+        let delta = match (&controller, recorded_step) {
+            (Some(controller), Some(recorded_step)) if recorded_step >= conf.switch_on => {
+                controller.perturbation(&z)
+            }
+            _ => 0.0,
+        };
+        z = iterate(&map.perturbed(delta), &z);
+        if !z.is_valid() {
+            break;
+        }
+        if recorded_step.is_some() {
+            evolution.states.push(z.iter().copied().collect());
+            evolution.control_signals.push(delta);
+        }
+    }
+    evolution
+}
+
+/// OGY control of a periodic orbit by small perturbations of the map parameter.
+pub fn ogy_control<const N: usize, M>(
+    maps: &[M],
+    init_distr: &[InitialDistributionVariant],
+    conf: &ControlConf,
+    trajectory_conf: &TrajectoryConf,
+) -> Result<Vec<ControlledEvolution>, Error>
+where
+    M: DiscreteMap<State = SVector<ChaosFloat, N>> + ControlParameter,
+    SVector<ChaosFloat, N>: FromStateVec + ValidStateCheck,
+{
+    if conf.period == 0 {
+        bail!("The target orbit needs a period of at least 1!");
+    }
+    let initial = initial_state(init_distr)?;
+    Ok(maps
+        .iter()
+        .map(|map| ogy_evolution(map, &initial, conf, trajectory_conf))
+        .collect())
+}
+
+// velocity of the flow with the delayed feedback K (x(t - τ) - x(t)) in the controlled variable
+fn pyragas_velocity<const N: usize, T: System<Time, SVector<ChaosFloat, N>>>(
+    system: &T,
+    t: Time,
+    z: &SVector<ChaosFloat, N>,
+    force: ChaosFloat,
+    variable: usize,
+) -> SVector<ChaosFloat, N> {
+    let mut dz = SVector::<ChaosFloat, N>::zeros();
+    system.system(t, z, &mut dz);
+    dz[variable] += force;
+    dz
+}
+
+fn pyragas_evolution<const N: usize, T: System<Time, SVector<ChaosFloat, N>>>(
+    system: &T,
+    initial: &SVector<ChaosFloat, N>,
+    conf: &ControlConf,
+    trajectory_conf: &TrajectoryConf,
+) -> ControlledEvolution
+where
+    SVector<ChaosFloat, N>: ValidStateCheck,
+{
+    let dt = ODE_STEP_SIZE / NUM_RK4_SUBSTEPS as Time;
+    let delay_steps = ((conf.delay / dt).round() as usize).max(1);
+    let switch_on = (trajectory_conf.num_transient + conf.switch_on) * NUM_RK4_SUBSTEPS;
+    let mut evolution = ControlledEvolution {
+        states: Vec::with_capacity(trajectory_conf.num_steps),
+        control_signals: Vec::with_capacity(trajectory_conf.num_steps),
+        target_orbit: Vec::new(),
+    };
+    // controlled variable per substep for the delayed feedback
+    let mut history = vec![initial[conf.variable]];
+    let mut z = *initial;
+    let num_substeps =
+        (trajectory_conf.num_transient + trajectory_conf.num_steps) * NUM_RK4_SUBSTEPS;
+    for n in 0..num_substeps {
+        let t = n as Time * dt;
+        // the delayed value is interpolated linearly within the substep
+        let delayed = (n >= switch_on && n >= delay_steps)
+            .then(|| (history[n - delay_steps], history[n + 1 - delay_steps]));
+        let force = |s: Time, current: ChaosFloat| {
+            delayed.map_or(0.0, |(start, end)| {
+                conf.gain * (start + (s - t) / dt * (end - start) - current)
+            })
+        };
+        let c = conf.variable;
+        let signal = force(t, z[c]);
+        rk4_step(std::slice::from_mut(&mut z), t, dt, |s, stage| {
+            vec![pyragas_velocity(
+                system,
+                s,
+                &stage[0],
+                force(s, stage[0][c]),
+                c,
+            )]
+        });
+        if !z.is_valid() {
+            break;
+        }
+        history.push(z[c]);
+        let step = (n + 1) / NUM_RK4_SUBSTEPS;
+        if (n + 1) % NUM_RK4_SUBSTEPS == 0 && step > trajectory_conf.num_transient {
+            evolution.states.push(z.iter().copied().collect());
+            evolution.control_signals.push(signal);
+        }
+    }
+    evolution
+}
+
+/// Pyragas control of a periodic orbit by delayed feedback, the delay τ matches the orbit period.
+pub fn pyragas_control<const N: usize, T>(
+    systems: &[T],
+    init_distr: &[InitialDistributionVariant],
+    conf: &ControlConf,
+    trajectory_conf: &TrajectoryConf,
+) -> Result<Vec<ControlledEvolution>, Error>
+where
+    T: System<Time, SVector<ChaosFloat, N>>,
+    SVector<ChaosFloat, N>: FromStateVec + ValidStateCheck,
+{
+    if conf.variable >= N {
+        bail!("The controlled variable must be one of the {N} state variables!");
+    }
+    let initial = initial_state(init_distr)?;
+    Ok(systems
+        .iter()
+        .map(|system| pyragas_evolution(system, &initial, conf, trajectory_conf))
+        .collect())
+}
+
+pub fn chaos_control(schema: TrajectorySchema, conf: &ControlConf) -> Result<ControlResult, Error> {
+    let TrajectorySchema {
+        init_distr,
+        discrete_map_vec,
+        diff_system_vec,
+        pars,
+        conf: trajectory_conf,
+        ..
+    } = schema;
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Chaos control is only available for state distributions!"),
+    };
+    if conf.switch_on >= trajectory_conf.num_steps {
+        bail!("The control must be switched on before the last recorded step!");
+    }
+    let (is_discrete, evolutions) = match (discrete_map_vec, diff_system_vec) {
+        (Some(map_vec), _) => (
+            true,
+            map_vec.controlled(&init_distr, conf, &trajectory_conf)?,
+        ),
+        (None, Some(solver_vec)) => (
+            false,
+            solver_vec.controlled(&init_distr, conf, &trajectory_conf)?,
+        ),
+        (None, None) => bail!("Chaos control needs a discrete map or a differential system!"),
+    };
+    Ok(ControlResult {
+        pars,
+        is_discrete,
+        switch_on: conf.switch_on,
+        sample_time: if is_discrete { 1.0 } else { ODE_STEP_SIZE },
+        evolutions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::functions::Rossler;
+    fn init_distr(values: &[ChaosFloat]) -> Vec<InitialDistributionVariant> {
+        values
+            .iter()
+            .map(|value| InitialDistributionVariant::Fixed(Fixed { value: *value }))
+            .collect()
+    }
+    fn conf(period: usize) -> ControlConf {
+        ControlConf {
+            switch_on: 100,
+            period,
+            orbit: 0,
+            max_perturbation: 0.1,
+            gain: 0.2,
+            delay: 5.88,
+            variable: 1,
+        }
+    }
+    #[test]
+    fn test_ogy_control() -> Result<(), Error> {
+        let trajectory_conf = TrajectoryConf {
+            num_transient: 100,
+            num_steps: 3000,
+        };
+        let logistic = SimpleDiscreteMap::new(Logistic { r: 3.9 });
+        let evolutions = ogy_control(&[logistic], &init_distr(&[0.2]), &conf(1), &trajectory_conf)?;
+        let fixpoint = 1.0 - 1.0 / 3.9;
+        assert!((evolutions[0].target_orbit[0][0] - fixpoint).abs() < 1e-9);
+        assert_eq!(evolutions[0].states.len(), 3000);
+        let last = evolutions[0].states.last().expect("no escape");
+        assert!(
+            (last[0] - fixpoint).abs() < 1e-6,
+            "x={} is controlled",
+            last[0]
+        );
+        // the period 2 orbit of the Hénon map
+        let henon = SimpleDiscreteMap::new(Henon::default());
+        let evolutions = ogy_control(
+            &[henon],
+            &init_distr(&[0.1, 0.1]),
+            &conf(2),
+            &trajectory_conf,
+        )?;
+        let orbit = &evolutions[0].target_orbit;
+        assert_eq!(orbit.len(), 2);
+        let states = &evolutions[0].states;
+        let last = &states[states.len() - 2..];
+        assert!(last
+            .iter()
+            .all(|state| orbit.iter().any(|point| (state[0] - point[0]).abs() < 1e-6)));
+        assert!(evolutions[0].control_signals[..100]
+            .iter()
+            .all(|s| *s == 0.0));
+        Ok(())
+    }
+    #[test]
+    fn test_pyragas_control() -> Result<(), Error> {
+        let trajectory_conf = TrajectoryConf {
+            num_transient: 100,
+            num_steps: 2000,
+        };
+        let rossler = Rossler {
+            a: 0.2,
+            b: 0.2,
+            c: 5.7,
+        };
+        let result = ControlResult {
+            pars: ("a", vec![0.2]),
+            is_discrete: false,
+            switch_on: 100,
+            sample_time: ODE_STEP_SIZE,
+            evolutions: pyragas_control(
+                &[rossler],
+                &init_distr(&[1.0, 1.0, 0.0]),
+                &conf(1),
+                &trajectory_conf,
+            )?,
+        };
+        assert_eq!(result.evolutions[0].states.len(), 2000);
+        let effort = result.final_control_effort(0).expect("no escape");
+        assert!(effort < 1e-2, "feedback {effort} vanishes on the orbit");
+        Ok(())
+    }
+}
@@ -0,0 +1,391 @@
+use super::lyapunov::state_vec;
+use super::trajectory::{check_dimensions, evaluate_cells, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{
+    data::*,
+    functions::{DiscreteMap, OdeSolverTrait},
+};
+use anyhow::{bail, Error};
+
+pub const MAX_DENSITY_BINS: usize = 1000;
+
+#[derive(Clone, Copy)]
+pub struct DensityConf {
+    pub x_component: usize,
+    // a second component for a 2D histogram
+    pub y_component: Option<usize>,
+    // number of bins along each axis
+    pub num_bins: usize,
+}
+
+impl Default for DensityConf {
+    fn default() -> Self {
+        Self {
+            x_component: 0,
+            y_component: None,
+            num_bins: 100,
+        }
+    }
+}
+
+trait StateAccumulator: Send {
+    fn add(&mut self, state: &[ChaosFloat]);
+}
+
+// extent of the visited states in the histogram components
+#[derive(Clone)]
+struct Bounds {
+    components: Vec<usize>,
+    ranges: Option<Vec<(ChaosFloat, ChaosFloat)>>,
+}
+
+impl StateAccumulator for Bounds {
+    fn add(&mut self, state: &[ChaosFloat]) {
+        let ranges = self.ranges.get_or_insert_with(|| {
+            self.components
+                .iter()
+                .map(|c| (state[*c], state[*c]))
+                .collect()
+        });
+        ranges
+            .iter_mut()
+            .zip(self.components.iter())
+            .for_each(|((low, high), c)| {
+                *low = low.min(state[*c]);
+                *high = high.max(state[*c]);
+            });
+    }
+}
+
+impl Bounds {
+    fn merge(self, other: Self) -> Self {
+        let ranges = match (self.ranges, other.ranges) {
+            (Some(ranges), Some(other_ranges)) => Some(
+                ranges
+                    .into_iter()
+                    .zip(other_ranges)
+                    .map(|((low, high), (other_low, other_high))| {
+                        (low.min(other_low), high.max(other_high))
+                    })
+                    .collect(),
+            ),
+            (ranges, other_ranges) => ranges.or(other_ranges),
+        };
+        Self {
+            components: self.components,
+            ranges,
+        }
+    }
+}
+
+/// Counts of the visited states in equally sized bins, row-major with the y-axis as outer index.
+#[derive(Clone)]
+pub struct Histogram {
+    components: Vec<usize>,
+    ranges: Vec<(ChaosFloat, ChaosFloat)>,
+    num_bins: usize,
+    counts: Vec<usize>,
+    num_states: usize,
+}
+
+impl StateAccumulator for Histogram {
+    fn add(&mut self, state: &[ChaosFloat]) {
+        let mut index = 0;
+        for (c, (low, high)) in self.components.iter().zip(self.ranges.iter()).rev() {
+            let x = state[*c];
+            if x < *low || x > *high {
+                return;
+            }
+            let bin = ((x - low) / (high - low) * self.num_bins as ChaosFloat) as usize;
+            index = index * self.num_bins + bin.min(self.num_bins - 1);
+        }
+        self.counts[index] += 1;
+        self.num_states += 1;
+    }
+}
+
+impl Histogram {
+    fn new(components: Vec<usize>, ranges: Vec<(ChaosFloat, ChaosFloat)>, num_bins: usize) -> Self {
+        let num_bins = num_bins.max(1);
+        let ranges: Vec<(ChaosFloat, ChaosFloat)> = ranges
+            .into_iter()
+            .map(|(low, high)| {
+                if high - low > ChaosFloat::EPSILON * high.abs().max(1.0) {
+                    (low, high)
+                } else {
+                    (low - 0.5, high + 0.5) // a fixpoint
+                }
+            })
+            .collect();
+        Self {
+            counts: vec![0; num_bins.pow(ranges.len() as u32)],
+            components,
+            ranges,
+            num_bins,
+            num_states: 0,
+        }
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.num_states
+    }
+
+    pub fn num_bins(&self) -> usize {
+        self.num_bins
+    }
+
+    pub fn ranges(&self) -> &[(ChaosFloat, ChaosFloat)] {
+        &self.ranges
+    }
+
+    pub fn labels(&self) -> Vec<String> {
+        self.components
+            .iter()
+            .map(|c| format!("State{}", c + 1))
+            .collect()
+    }
+
+    pub fn bin_centers(&self, axis: usize) -> Vec<ChaosFloat> {
+        let (low, high) = self.ranges[axis];
+        let width = (high - low) / self.num_bins as ChaosFloat;
+        (0..self.num_bins)
+            .map(|bin| low + (bin as ChaosFloat + 0.5) * width)
+            .collect()
+    }
+
+    /// Fraction of the states per bin divided by the bin volume, integrates to one.
+    pub fn density(&self) -> Vec<ChaosFloat> {
+        let volume: ChaosFloat = self
+            .ranges
+            .iter()
+            .map(|(low, high)| (high - low) / self.num_bins as ChaosFloat)
+            .product();
+        let norm = self.num_states.max(1) as ChaosFloat * volume;
+        self.counts
+            .iter()
+            .map(|count| *count as ChaosFloat / norm)
+            .collect()
+    }
+
+    /// Bin centers and densities as comma separated values with a header line.
+    pub fn to_csv(&self) -> String {
+        let density = self.density();
+        let mut csv = format!("{},Density\n", self.labels().join(","));
+        let x_centers = self.bin_centers(0);
+        match self.ranges.len() {
+            1 => x_centers
+                .iter()
+                .zip(density.iter())
+                .for_each(|(x, d)| csv.push_str(&format!("{x},{d}\n"))),
+            _ => {
+                let y_centers = self.bin_centers(1);
+                y_centers.iter().enumerate().for_each(|(iy, y)| {
+                    x_centers.iter().enumerate().for_each(|(ix, x)| {
+                        let d = density[iy * self.num_bins + ix];
+                        csv.push_str(&format!("{x},{y},{d}\n"));
+                    })
+                });
+            }
+        }
+        csv
+    }
+}
+
+pub struct DensityResult {
+    pub pars: (&'static str, Vec<f64>),
+    // one histogram per discrete map or differential system with common bins
+    pub histograms: Vec<Histogram>,
+}
+
+fn density_components(conf: &DensityConf, num_dims: usize) -> Result<Vec<usize>, Error> {
+    let components: Vec<usize> = std::iter::once(conf.x_component)
+        .chain(conf.y_component)
+        .collect();
+    if components.iter().any(|c| *c >= num_dims) {
+        bail!("The selected state component does not exist!");
+    }
+    Ok(components)
+}
+
+fn common_histogram(bounds: Vec<Bounds>, conf: &DensityConf) -> Result<Histogram, Error> {
+    match bounds.into_iter().reduce(Bounds::merge) {
+        Some(Bounds {
+            components,
+            ranges: Some(ranges),
+        }) => Ok(Histogram::new(components, ranges, conf.num_bins)),
+        _ => bail!("All samples escaped!"),
+    }
+}
+
+fn accumulate_discrete<V, D, A>(
+    maps: &[D],
+    init_data: &ChaosData<V>,
+    num_dims: usize,
+    conf: &TrajectoryConf,
+    accumulator: &A,
+) -> Vec<A>
+where
+    V: StateIndex + ValidStateCheck + Clone + Sync,
+    D: DiscreteMap<State = V> + Sync,
+    A: StateAccumulator + Clone + Sync,
+{
+    evaluate_cells(maps, |map| {
+        let mut accumulator = accumulator.clone();
+        init_data.data_filtered().into_iter().for_each(|y0| {
+            let mut y = y0.clone();
+            let mut t: Time = 0.0;
+            for step in 0..(conf.num_transient + conf.num_steps) {
+                map.execute(&mut y, &t);
+                t += 1.0;
+                if !y.is_valid() {
+                    break;
+                }
+                if step >= conf.num_transient {
+                    accumulator.add(&state_vec(&y, num_dims));
+                }
+            }
+        });
+        accumulator
+    })
+}
+
+fn accumulate_continuous<V, O, A>(
+    solvers: &[O],
+    init_data: &ChaosData<V>,
+    num_dims: usize,
+    conf: &TrajectoryConf,
+    accumulator: &A,
+) -> Vec<A>
+where
+    V: StateIndex + ValidStateCheck + Clone + Sync,
+    O: OdeSolverTrait<State = V> + Clone + Sync,
+    A: StateAccumulator + Clone + Sync,
+{
+    evaluate_cells(solvers, |solver| {
+        let mut accumulator = accumulator.clone();
+        let mut solver = solver.clone();
+        let mut states = init_data.data().clone();
+        solver.initial_states(&mut states);
+        solver.execute(&mut states, conf.num_transient);
+        for _ in 0..conf.num_steps {
+            solver.execute(&mut states, 1);
+            states
+                .iter()
+                .flatten()
+                .for_each(|y| accumulator.add(&state_vec(y, num_dims)));
+        }
+        accumulator
+    })
+}
+
+// the bins are fitted to the visited states in a first run of the same samples
+pub fn discrete_densities<V, D>(
+    maps: &[D],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &TrajectoryConf,
+    density_conf: &DensityConf,
+) -> Result<Vec<Histogram>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    D: DiscreteMap<State = V> + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    let components = density_components(density_conf, num_dims)?;
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    let bounds = Bounds {
+        components,
+        ranges: None,
+    };
+    let bounds = accumulate_discrete(maps, &init_data, num_dims, conf, &bounds);
+    let histogram = common_histogram(bounds, density_conf)?;
+    Ok(accumulate_discrete(
+        maps, &init_data, num_dims, conf, &histogram,
+    ))
+}
+
+pub fn continuous_densities<V, O>(
+    solvers: &[O],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &TrajectoryConf,
+    density_conf: &DensityConf,
+) -> Result<Vec<Histogram>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    O: OdeSolverTrait<State = V> + Clone + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    let components = density_components(density_conf, num_dims)?;
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    let bounds = Bounds {
+        components,
+        ranges: None,
+    };
+    let bounds = accumulate_continuous(solvers, &init_data, num_dims, conf, &bounds);
+    let histogram = common_histogram(bounds, density_conf)?;
+    Ok(accumulate_continuous(
+        solvers, &init_data, num_dims, conf, &histogram,
+    ))
+}
+
+pub fn invariant_densities(
+    schema: TrajectorySchema,
+    density_conf: &DensityConf,
+) -> Result<DensityResult, Error> {
+    let TrajectorySchema {
+        num_samples,
+        init_distr,
+        discrete_map_vec,
+        diff_system_vec,
+        pars,
+        conf,
+        ..
+    } = schema;
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Invariant densities are only available for state distributions!"),
+    };
+    let histograms = if let Some(map_vec) = discrete_map_vec {
+        map_vec.densities(num_samples, &init_distr, &conf, density_conf)?
+    } else if let Some(system_vec) = diff_system_vec {
+        system_vec.densities(num_samples, &init_distr, &conf, density_conf)?
+    } else {
+        bail!("Cannot compute invariant densities without a chaotic function!")
+    };
+    Ok(DensityResult { pars, histograms })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_logistic_density() {
+        let mut x = 0.1234;
+        let states: Vec<Vec<ChaosFloat>> = (0..100_000)
+            .map(|_| {
+                x = 4.0 * x * (1.0 - x);
+                vec![x]
+            })
+            .collect();
+        let mut bounds = Bounds {
+            components: vec![0],
+            ranges: None,
+        };
+        states.iter().for_each(|state| bounds.add(state));
+        let mut histogram =
+            common_histogram(vec![bounds], &DensityConf::default()).expect("visited states");
+        states.iter().for_each(|state| histogram.add(state));
+        assert_eq!(histogram.num_states(), states.len());
+        let density = histogram.density();
+        let width = 1.0 / histogram.num_bins() as ChaosFloat;
+        let integral: ChaosFloat = density.iter().map(|d| d * width).sum();
+        assert!((integral - 1.0).abs() < 1e-2, "integral is {integral}");
+        // the invariant density is 1 / (pi * sqrt(x * (1 - x)))
+        let center = density[50];
+        assert!((center - 0.637).abs() < 0.05, "density at 0.5 is {center}");
+        assert!(density[0] > 5.0 * center && density[99] > 5.0 * center);
+        assert_eq!(histogram.to_csv().lines().count(), 101);
+    }
+}
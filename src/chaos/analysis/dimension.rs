@@ -0,0 +1,280 @@
+use super::kd_tree::KdTree;
+use super::scaling::{fit_scaling_range, LinearFit};
+use super::trajectory::evaluate_cells;
+use crate::chaos::data::ChaosFloat;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+const NUM_RADII: usize = 32;
+// smallest radius relative to the largest extent of the point cloud
+const MIN_RADIUS_FRACTION: ChaosFloat = 1e-3;
+// pairs are only counted up to this fraction of the extent since larger radii saturate
+const MAX_RADIUS_FRACTION: ChaosFloat = 0.25;
+const MIN_SCALING_POINTS: usize = 6;
+// box sizes are the extent divided by 2^1..2^NUM_BOX_LEVELS
+const NUM_BOX_LEVELS: usize = 14;
+// smaller boxes hold too few points to cover an attractor
+const MIN_POINTS_PER_BOX: usize = 2;
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum DimensionEstimator {
+    #[default]
+    Correlation,
+    BoxCounting,
+}
+
+impl From<DimensionEstimator> for &'static str {
+    fn from(val: DimensionEstimator) -> Self {
+        match val {
+            DimensionEstimator::Correlation => "Correlation Sum",
+            DimensionEstimator::BoxCounting => "Box Counting",
+        }
+    }
+}
+
+impl DimensionEstimator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            DimensionEstimator::Correlation => "D2",
+            DimensionEstimator::BoxCounting => "D0",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum PointSource {
+    #[default]
+    Ensemble,
+    Trajectory,
+}
+
+impl From<PointSource> for &'static str {
+    fn from(val: PointSource) -> Self {
+        match val {
+            PointSource::Ensemble => "Ensemble",
+            PointSource::Trajectory => "Trajectory",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct CorrelationConf {
+    pub max_points: usize,
+    // pairs closer in time than this number of recorded steps are excluded
+    pub theiler_window: usize,
+}
+
+impl Default for CorrelationConf {
+    fn default() -> Self {
+        Self {
+            max_points: 2000,
+            theiler_window: 0,
+        }
+    }
+}
+
+/// Scaling of a log-log relation with the fitted index range.
+#[derive(Clone, Debug)]
+pub struct ScalingCurve {
+    pub log_x: Vec<ChaosFloat>,
+    pub log_y: Vec<ChaosFloat>,
+    pub fit: Option<((usize, usize), LinearFit)>,
+}
+
+impl ScalingCurve {
+    pub fn new(log_x: Vec<ChaosFloat>, log_y: Vec<ChaosFloat>) -> Self {
+        let fit = fit_scaling_range(&log_x, &log_y, MIN_SCALING_POINTS);
+        Self { log_x, log_y, fit }
+    }
+
+    pub fn scaling_fit(&self) -> Option<LinearFit> {
+        self.fit.map(|(_, fit)| fit)
+    }
+}
+
+pub(super) fn max_extent(points: &[Vec<ChaosFloat>]) -> ChaosFloat {
+    let num_dims = points.first().map_or(0, |p| p.len());
+    (0..num_dims)
+        .map(|axis| {
+            let (min, max) = points.iter().fold(
+                (ChaosFloat::INFINITY, ChaosFloat::NEG_INFINITY),
+                |(min, max), p| (min.min(p[axis]), max.max(p[axis])),
+            );
+            max - min
+        })
+        .fold(0.0, ChaosFloat::max)
+}
+
+/// Grassberger-Procaccia correlation sums C(r) as log10 C over log10 r with
+/// the correlation dimension D2 as slope of the fitted scaling range.
+pub fn correlation_sums(
+    points: &[Vec<ChaosFloat>],
+    conf: &CorrelationConf,
+) -> Option<ScalingCurve> {
+    let stride = points.len().div_ceil(conf.max_points.max(2)).max(1);
+    let points: Vec<Vec<ChaosFloat>> = points.iter().step_by(stride).cloned().collect();
+    let num_points = points.len();
+    let extent = max_extent(&points);
+    if num_points < 2 || extent <= 0.0 || !extent.is_finite() {
+        return None;
+    }
+    let theiler_window = conf.theiler_window.div_ceil(stride);
+    let log_min = (MIN_RADIUS_FRACTION * extent).log10();
+    let log_max = (MAX_RADIUS_FRACTION * extent).log10();
+    let log_radii: Vec<ChaosFloat> = (0..NUM_RADII)
+        .map(|i| log_min + (log_max - log_min) * i as ChaosFloat / (NUM_RADII - 1) as ChaosFloat)
+        .collect();
+    let radii: Vec<ChaosFloat> = log_radii.iter().map(|r| 10_f64.powf(*r)).collect();
+    let max_radius = radii[NUM_RADII - 1];
+    let tree = KdTree::new(&points);
+    let indices: Vec<usize> = (0..num_points).collect();
+    // pair counts per point with the first radius that exceeds the distance
+    let histograms = evaluate_cells(&indices, |i| {
+        let mut histogram = [0_usize; NUM_RADII];
+        tree.for_each_within(&points[*i], max_radius, &mut |j, distance| {
+            if j > *i + theiler_window {
+                let bin = radii.partition_point(|r| *r <= distance);
+                if bin < NUM_RADII {
+                    histogram[bin] += 1;
+                }
+            }
+        });
+        histogram
+    });
+    let mut counts = [0_usize; NUM_RADII];
+    histograms.iter().for_each(|histogram| {
+        counts
+            .iter_mut()
+            .zip(histogram.iter())
+            .for_each(|(count, n)| *count += n)
+    });
+    let num_pairs = (num_points - theiler_window.min(num_points - 1)) as ChaosFloat
+        * (num_points - 1 - theiler_window.min(num_points - 1)) as ChaosFloat
+        / 2.0;
+    if num_pairs <= 0.0 {
+        return None;
+    }
+    let mut cumulative = 0;
+    let (log_x, log_y): (Vec<_>, Vec<_>) = counts
+        .iter()
+        .zip(log_radii)
+        .filter_map(|(count, log_r)| {
+            cumulative += count;
+            if cumulative > 0 {
+                Some((log_r, (cumulative as ChaosFloat / num_pairs).log10()))
+            } else {
+                None
+            }
+        })
+        .unzip();
+    Some(ScalingCurve::new(log_x, log_y))
+}
+
+/// Number of boxes N(e) that cover the points as log10 N over log10 1/e.
+/// With labels only boxes that contain points of both kinds are counted,
+/// e.g. the boundary between escaped and bounded samples of a fractal.
+pub fn box_counts(points: &[Vec<ChaosFloat>], inside: Option<&[bool]>) -> Option<ScalingCurve> {
+    let num_points = points.len();
+    let extent = max_extent(points);
+    if num_points < 2 || extent <= 0.0 || !extent.is_finite() {
+        return None;
+    }
+    let num_dims = points[0].len();
+    let mins: Vec<ChaosFloat> = (0..num_dims)
+        .map(|axis| {
+            points
+                .iter()
+                .map(|p| p[axis])
+                .fold(ChaosFloat::INFINITY, ChaosFloat::min)
+        })
+        .collect();
+    let (log_x, log_y): (Vec<_>, Vec<_>) = (1..=NUM_BOX_LEVELS)
+        .map(|level| {
+            let num_boxes_per_axis = 1_i64 << level;
+            let size = extent / num_boxes_per_axis as ChaosFloat;
+            // per box whether it contains points inside and outside
+            let mut boxes: HashMap<Vec<i64>, (bool, bool)> = HashMap::new();
+            points.iter().enumerate().for_each(|(i, p)| {
+                let key = p
+                    .iter()
+                    .zip(mins.iter())
+                    // points on the upper bound belong to the last box
+                    .map(|(x, min)| (((x - min) / size).floor() as i64).min(num_boxes_per_axis - 1))
+                    .collect();
+                let (has_inside, has_outside) = boxes.entry(key).or_default();
+                match inside {
+                    Some(inside) if !inside[i] => *has_outside = true,
+                    _ => *has_inside = true,
+                }
+            });
+            let count = match inside {
+                Some(_) => boxes.values().filter(|(i, o)| *i && *o).count(),
+                None => boxes.len(),
+            };
+            (size, count)
+        })
+        .take_while(|(_, count)| inside.is_some() || count * MIN_POINTS_PER_BOX <= num_points)
+        .filter(|(_, count)| *count > 0)
+        .map(|(size, count)| ((1.0 / size).log10(), (count as ChaosFloat).log10()))
+        .unzip();
+    if log_x.len() < 2 {
+        return None;
+    }
+    Some(ScalingCurve::new(log_x, log_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_correlation_dimension_of_line_and_square() {
+        let line: Vec<Vec<ChaosFloat>> = (0..1000)
+            .map(|i| {
+                let t = i as ChaosFloat / 1000.0;
+                vec![t, 0.5 * t]
+            })
+            .collect();
+        let conf = CorrelationConf::default();
+        let d2 = correlation_sums(&line, &conf)
+            .and_then(|curve| curve.scaling_fit())
+            .expect("line has an extent")
+            .slope;
+        assert!((d2 - 1.0).abs() < 0.1, "D2 of a line is {d2}");
+        let square: Vec<Vec<ChaosFloat>> = (0..40)
+            .flat_map(|i| (0..40).map(move |j| vec![i as ChaosFloat, j as ChaosFloat]))
+            .collect();
+        let d2 = correlation_sums(&square, &conf)
+            .and_then(|curve| curve.scaling_fit())
+            .expect("square has an extent")
+            .slope;
+        assert!((d2 - 2.0).abs() < 0.2, "D2 of a square is {d2}");
+    }
+
+    #[test]
+    fn test_box_counting_of_square_and_circle_boundary() {
+        let grid: Vec<Vec<ChaosFloat>> = (0..256)
+            .flat_map(|i| (0..256).map(move |j| vec![i as ChaosFloat, j as ChaosFloat]))
+            .collect();
+        let fit = box_counts(&grid, None)
+            .and_then(|curve| curve.scaling_fit())
+            .expect("square has an extent");
+        assert!(
+            (fit.slope - 2.0).abs() < 0.05,
+            "D0 of a square is {}",
+            fit.slope
+        );
+        let inside: Vec<bool> = grid
+            .iter()
+            .map(|p| (p[0] - 127.5).powi(2) + (p[1] - 127.5).powi(2) < 100.0_f64.powi(2))
+            .collect();
+        let fit = box_counts(&grid, Some(&inside))
+            .and_then(|curve| curve.scaling_fit())
+            .expect("circle has a boundary");
+        assert!(
+            (fit.slope - 1.0).abs() < 0.1,
+            "D0 of a circle is {}",
+            fit.slope
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use super::kd_tree::KdTree;
+use super::trajectory::RecordedTrajectories;
+use crate::chaos::data::ChaosFloat;
+use anyhow::{bail, Error};
+
+pub const MAX_EMBEDDING_DIMENSION: usize = 8;
+const NUM_INFORMATION_BINS: usize = 32;
+// a neighbour is false if the next delay coordinate stretches the distance by this factor
+const FNN_DISTANCE_RATIO: ChaosFloat = 10.0;
+// or if the stretched distance exceeds this multiple of the standard deviation
+const FNN_ATTRACTOR_RATIO: ChaosFloat = 2.0;
+// the embedding dimension unfolds all but this fraction of neighbours
+const FNN_FRACTION: ChaosFloat = 0.01;
+
+#[derive(Clone, Copy)]
+pub struct EmbeddingConf {
+    // state component of the observed time series
+    pub component: usize,
+    // largest delay of the average mutual information
+    pub max_delay: usize,
+    // chosen from the first minimum of the mutual information if None
+    pub delay: Option<usize>,
+    // chosen from the false nearest neighbours if None
+    pub dimension: Option<usize>,
+}
+
+impl Default for EmbeddingConf {
+    fn default() -> Self {
+        Self {
+            component: 0,
+            max_delay: 50,
+            delay: None,
+            dimension: None,
+        }
+    }
+}
+
+pub struct Embedding {
+    // average mutual information per delay, starting at zero
+    pub mutual_information: Vec<ChaosFloat>,
+    pub delay: usize,
+    // fraction of false nearest neighbours per dimension, starting at one
+    pub false_neighbours: Vec<ChaosFloat>,
+    pub dimension: usize,
+    // first three delay coordinates of the reconstructed attractor
+    pub reconstructed: Vec<Vec<ChaosFloat>>,
+    // the recorded states of the original system
+    pub original: Vec<Vec<ChaosFloat>>,
+}
+
+pub struct EmbeddingResult {
+    pub pars: (&'static str, Vec<f64>),
+    // one entry per discrete map or differential system
+    pub embeddings: Vec<Option<Embedding>>,
+}
+
+/// Average mutual information in nats between x(t) and x(t + delay) for all delays up to `max_delay`.
+pub fn mutual_information(series: &[ChaosFloat], max_delay: usize) -> Vec<ChaosFloat> {
+    let (min, max) = series.iter().fold(
+        (ChaosFloat::INFINITY, ChaosFloat::NEG_INFINITY),
+        |(min, max), x| (min.min(*x), max.max(*x)),
+    );
+    if series.is_empty() || min >= max {
+        return Vec::new();
+    }
+    let bins: Vec<usize> = series
+        .iter()
+        .map(|x| {
+            let bin = ((x - min) / (max - min) * NUM_INFORMATION_BINS as ChaosFloat) as usize;
+            bin.min(NUM_INFORMATION_BINS - 1)
+        })
+        .collect();
+    (0..=max_delay.min(series.len() - 1))
+        .map(|delay| {
+            let num_pairs = series.len() - delay;
+            let mut joint = vec![0_usize; NUM_INFORMATION_BINS * NUM_INFORMATION_BINS];
+            let mut marginal_x = [0_usize; NUM_INFORMATION_BINS];
+            let mut marginal_y = [0_usize; NUM_INFORMATION_BINS];
+            (0..num_pairs).for_each(|t| {
+                let (x, y) = (bins[t], bins[t + delay]);
+                joint[x * NUM_INFORMATION_BINS + y] += 1;
+                marginal_x[x] += 1;
+                marginal_y[y] += 1;
+            });
+            let n = num_pairs as ChaosFloat;
+            joint
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .map(|(index, count)| {
+                    let p = *count as ChaosFloat / n;
+                    let p_x = marginal_x[index / NUM_INFORMATION_BINS] as ChaosFloat / n;
+                    let p_y = marginal_y[index % NUM_INFORMATION_BINS] as ChaosFloat / n;
+                    p * (p / (p_x * p_y)).ln()
+                })
+                .sum()
+        })
+        .collect()
+}
+
+// first local minimum, or the global minimum of a monotone decay
+fn first_minimum(values: &[ChaosFloat]) -> usize {
+    (1..values.len().saturating_sub(1))
+        .find(|lag| values[*lag] < values[lag - 1] && values[*lag] <= values[lag + 1])
+        .or_else(|| (1..values.len()).min_by(|a, b| values[*a].total_cmp(&values[*b])))
+        .unwrap_or(1)
+}
+
+pub fn delay_embedding(
+    series: &[ChaosFloat],
+    dimension: usize,
+    delay: usize,
+) -> Vec<Vec<ChaosFloat>> {
+    let span = (dimension.max(1) - 1) * delay;
+    (0..series.len().saturating_sub(span))
+        .map(|t| (0..dimension).map(|k| series[t + k * delay]).collect())
+        .collect()
+}
+
+/// Fraction of false nearest neighbours (Kennel et al.) of the delay embeddings in 1..=max_dimension.
+pub fn false_nearest_neighbours(
+    series: &[ChaosFloat],
+    delay: usize,
+    max_dimension: usize,
+) -> Vec<ChaosFloat> {
+    let n = series.len() as ChaosFloat;
+    let mean = series.iter().sum::<ChaosFloat>() / n;
+    let deviation = (series
+        .iter()
+        .map(|x| (x - mean).powi(2))
+        .sum::<ChaosFloat>()
+        / n)
+        .sqrt();
+    (1..=max_dimension)
+        .map_while(|dimension| {
+            // the next delay coordinate must exist for every point
+            let num_points = series.len().checked_sub(dimension * delay)?;
+            let points = delay_embedding(
+                &series[..num_points + (dimension - 1) * delay],
+                dimension,
+                delay,
+            );
+            let tree = KdTree::new(&points);
+            let (mut num_false, mut num_checked) = (0, 0);
+            points.iter().enumerate().for_each(|(i, point)| {
+                // temporal neighbours within one delay are excluded
+                let neighbour = tree.nearest(point, |j| i.abs_diff(j) <= delay);
+                if let Some((j, distance)) = neighbour {
+                    if distance > 0.0 {
+                        let extension =
+                            (series[i + dimension * delay] - series[j + dimension * delay]).abs();
+                        let stretched = (distance.powi(2) + extension.powi(2)).sqrt();
+                        if extension / distance > FNN_DISTANCE_RATIO
+                            || stretched / deviation > FNN_ATTRACTOR_RATIO
+                        {
+                            num_false += 1;
+                        }
+                        num_checked += 1;
+                    }
+                }
+            });
+            (num_checked > 0).then(|| num_false as ChaosFloat / num_checked as ChaosFloat)
+        })
+        .collect()
+}
+
+pub fn embed_series(
+    series: &[ChaosFloat],
+    conf: &EmbeddingConf,
+    original: Vec<Vec<ChaosFloat>>,
+) -> Option<Embedding> {
+    let mutual_information = mutual_information(series, conf.max_delay);
+    if mutual_information.is_empty() {
+        return None; // a constant series
+    }
+    let delay = conf
+        .delay
+        .unwrap_or_else(|| first_minimum(&mutual_information))
+        .max(1);
+    let false_neighbours = false_nearest_neighbours(series, delay, MAX_EMBEDDING_DIMENSION);
+    let dimension = conf.dimension.unwrap_or_else(|| {
+        false_neighbours
+            .iter()
+            .position(|fraction| *fraction < FNN_FRACTION)
+            .map_or(false_neighbours.len(), |index| index + 1)
+            .max(1)
+    });
+    Some(Embedding {
+        mutual_information,
+        delay,
+        false_neighbours,
+        dimension,
+        reconstructed: delay_embedding(series, 3, delay),
+        original,
+    })
+}
+
+/// Delay embeddings of the first trajectory per data set that did not escape.
+pub fn embedding_analysis(
+    recorded: RecordedTrajectories,
+    conf: &EmbeddingConf,
+) -> Result<EmbeddingResult, Error> {
+    if conf.component >= recorded.num_dims {
+        bail!("The selected state component does not exist!");
+    }
+    let embeddings = (0..recorded.trajectories.len())
+        .map(|index| {
+            let trajectory = recorded.complete_trajectories(index).into_iter().next()?;
+            let series: Vec<ChaosFloat> = trajectory
+                .iter()
+                .map(|state| state[conf.component])
+                .collect();
+            embed_series(&series, conf, trajectory.clone())
+        })
+        .collect();
+    Ok(EmbeddingResult {
+        pars: recorded.pars,
+        embeddings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_embedding() {
+        // the Henon map is reconstructed from x(t) and x(t + 1)
+        let (mut x, mut y) = (0.1, 0.1);
+        let henon: Vec<ChaosFloat> = (0..3000)
+            .map(|_| {
+                (x, y) = (1.0 - 1.4 * x * x + y, 0.3 * x);
+                x
+            })
+            .skip(100)
+            .collect();
+        let conf = EmbeddingConf {
+            delay: Some(1),
+            ..Default::default()
+        };
+        let embedding = embed_series(&henon, &conf, Vec::new()).expect("chaotic series");
+        assert_eq!(embedding.dimension, 2);
+        assert!(embedding.false_neighbours[0] > 0.5);
+        assert_eq!(embedding.reconstructed.len(), henon.len() - 2);
+        // the x component of the Lorenz flow has its first minimum at about 0.16 time units
+        let lorenz = |s: [ChaosFloat; 3]| {
+            [
+                10.0 * (s[1] - s[0]),
+                s[0] * (28.0 - s[2]) - s[1],
+                s[0] * s[1] - 8.0 / 3.0 * s[2],
+            ]
+        };
+        let step_size = 0.02;
+        let mut state = [1.0, 1.0, 1.0];
+        let series: Vec<ChaosFloat> = (0..5000)
+            .map(|_| {
+                // explicit midpoint steps
+                let k = lorenz(state);
+                let k = lorenz(std::array::from_fn(|i| state[i] + 0.5 * step_size * k[i]));
+                (0..3).for_each(|i| state[i] += step_size * k[i]);
+                state[0]
+            })
+            .skip(2000)
+            .collect();
+        let embedding =
+            embed_series(&series, &EmbeddingConf::default(), Vec::new()).expect("chaotic series");
+        assert!(
+            (6..=10).contains(&embedding.delay),
+            "delay is {}",
+            embedding.delay
+        );
+        assert_eq!(embedding.dimension, 3);
+    }
+}
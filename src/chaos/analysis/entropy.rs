@@ -0,0 +1,295 @@
+use super::scaling::linear_fit;
+use super::trajectory::{
+    evaluate_cells, record_trajectories, RecordedTrajectories, TrajectorySchema,
+};
+use super::zero_one::zero_one_test;
+use crate::chaos::{data::*, functions::DiscreteMap};
+use anyhow::{bail, Error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+pub const MAX_PERMUTATION_ORDER: usize = 7;
+// number of sampled points of the interval for counting laps of f^n
+const NUM_LAP_SAMPLES: usize = 1 << 16;
+// laps are only resolved if they contain enough sampled points
+const MAX_LAPS: usize = NUM_LAP_SAMPLES / 128;
+const MAX_LAP_ITERATIONS: usize = 40;
+// smaller differences of sampled values are rounding errors of converged orbits
+const LAP_TOLERANCE: ChaosFloat = 1e-10;
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum EntropyMeasure {
+    #[default]
+    Permutation,
+    Topological,
+    ZeroOne,
+}
+
+impl From<EntropyMeasure> for &'static str {
+    fn from(val: EntropyMeasure) -> Self {
+        match val {
+            EntropyMeasure::Permutation => "Permutation Entropy",
+            EntropyMeasure::Topological => "Topological Entropy",
+            EntropyMeasure::ZeroOne => "0-1 Test",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct EntropyConf {
+    pub measure: EntropyMeasure,
+    // state component of the observed time series
+    pub component: usize,
+    // length of the ordinal patterns
+    pub order: usize,
+    // number of steps between two values of an ordinal pattern or of the 0-1 test
+    pub delay: usize,
+}
+
+impl Default for EntropyConf {
+    fn default() -> Self {
+        Self {
+            measure: Default::default(),
+            component: 0,
+            order: 4,
+            delay: 1,
+        }
+    }
+}
+
+pub struct EntropyCurve {
+    pub pars: (&'static str, Vec<f64>),
+    pub measure: EntropyMeasure,
+    // one entry per discrete map or differential system
+    pub entropies: Vec<Option<ChaosFloat>>,
+}
+
+/// Shannon entropy of the ordinal patterns of a time series normalized by log(order!).
+pub fn permutation_entropy(
+    series: &[ChaosFloat],
+    order: usize,
+    delay: usize,
+) -> Option<ChaosFloat> {
+    let order = order.clamp(2, MAX_PERMUTATION_ORDER);
+    let delay = delay.max(1);
+    let span = (order - 1) * delay;
+    if series.len() <= span {
+        return None;
+    }
+    let mut counts: HashMap<Vec<usize>, usize> = HashMap::new();
+    let num_patterns = series.len() - span;
+    (0..num_patterns).for_each(|start| {
+        let mut pattern: Vec<usize> = (0..order).collect();
+        // ties are ordered by time
+        pattern.sort_by(|a, b| series[start + a * delay].total_cmp(&series[start + b * delay]));
+        *counts.entry(pattern).or_default() += 1;
+    });
+    let entropy: ChaosFloat = counts
+        .values()
+        .map(|count| {
+            let p = *count as ChaosFloat / num_patterns as ChaosFloat;
+            -p * p.ln()
+        })
+        .sum();
+    let max_entropy: ChaosFloat = (2..=order).map(|k| (k as ChaosFloat).ln()).sum();
+    Some(entropy / max_entropy)
+}
+
+// monotone pieces of the sampled values, jumps by about one are wraps of a circle map
+fn count_laps(values: &[ChaosFloat], is_circle: bool) -> usize {
+    let mut laps = 1;
+    let mut direction = 0.0;
+    values.windows(2).for_each(|w| {
+        let mut difference = w[1] - w[0];
+        if is_circle {
+            difference -= difference.round();
+        }
+        if difference.abs() > LAP_TOLERANCE {
+            let sign = difference.signum();
+            if direction != 0.0 && sign != direction {
+                laps += 1;
+            }
+            direction = sign;
+        }
+    });
+    laps
+}
+
+/// Topological entropy of an interval map as the growth rate of the number of laps of f^n.
+/// The interval is sampled as the image of `base_interval`.
+pub fn lap_entropy(
+    f: impl Fn(ChaosFloat) -> ChaosFloat,
+    base_interval: (ChaosFloat, ChaosFloat),
+    is_circle: bool,
+) -> Option<ChaosFloat> {
+    let (low, high) = base_interval;
+    let sample =
+        |i: usize| low + (high - low) * i as ChaosFloat / (NUM_LAP_SAMPLES - 1) as ChaosFloat;
+    let (min, max) = (0..NUM_LAP_SAMPLES)
+        .map(|i| f(sample(i)))
+        .filter(|x| x.is_finite())
+        .fold(
+            (ChaosFloat::INFINITY, ChaosFloat::NEG_INFINITY),
+            |(min, max), x| (min.min(x), max.max(x)),
+        );
+    if min >= max {
+        return Some(0.0); // constant map
+    }
+    let mut values: Vec<ChaosFloat> = (0..NUM_LAP_SAMPLES)
+        .map(|i| min + (max - min) * i as ChaosFloat / (NUM_LAP_SAMPLES - 1) as ChaosFloat)
+        .collect();
+    let mut log_laps = Vec::new();
+    for _ in 0..MAX_LAP_ITERATIONS {
+        values.iter_mut().for_each(|x| *x = f(*x));
+        if values.iter().any(|x| !x.is_finite()) {
+            break;
+        }
+        let laps = count_laps(&values, is_circle);
+        log_laps.push((laps as ChaosFloat).ln());
+        if laps > MAX_LAPS {
+            break;
+        }
+    }
+    // the growth rate is fitted over the later iterations
+    let start = log_laps.len() / 2;
+    let iterations: Vec<ChaosFloat> = (start..log_laps.len()).map(|n| n as ChaosFloat).collect();
+    match linear_fit(&iterations, &log_laps[start..]) {
+        Some(fit) => Some(fit.slope.max(0.0)),
+        None => log_laps.first().copied(), // a single iteration
+    }
+}
+
+pub fn lap_entropies<D>(
+    maps: &[D],
+    base_interval: (ChaosFloat, ChaosFloat),
+    is_circle: bool,
+) -> Vec<Option<ChaosFloat>>
+where
+    D: DiscreteMap<State = State1> + Sync,
+{
+    evaluate_cells(maps, |map| {
+        lap_entropy(
+            |x| {
+                let mut state = State1::new(x);
+                map.execute(&mut state, &0.0);
+                state[0]
+            },
+            base_interval,
+            is_circle,
+        )
+    })
+}
+
+fn series_measures(
+    recorded: RecordedTrajectories,
+    conf: &EntropyConf,
+) -> Result<Vec<Option<ChaosFloat>>, Error> {
+    if conf.component >= recorded.num_dims {
+        bail!("The selected state component does not exist!");
+    }
+    let measure = |series: &[ChaosFloat]| match conf.measure {
+        EntropyMeasure::ZeroOne => {
+            // oversampled flows look regular to the 0-1 test
+            let series: Vec<ChaosFloat> =
+                series.iter().step_by(conf.delay.max(1)).copied().collect();
+            zero_one_test(&series)
+        }
+        _ => permutation_entropy(series, conf.order, conf.delay),
+    };
+    Ok((0..recorded.trajectories.len())
+        .map(|index| {
+            // mean over all samples that did not escape
+            let values: Vec<ChaosFloat> = recorded
+                .complete_trajectories(index)
+                .into_iter()
+                .filter_map(|trajectory| {
+                    let series: Vec<ChaosFloat> = trajectory
+                        .iter()
+                        .map(|state| state[conf.component])
+                        .collect();
+                    measure(&series)
+                })
+                .collect();
+            if values.is_empty() {
+                None
+            } else {
+                Some(values.iter().sum::<ChaosFloat>() / values.len() as ChaosFloat)
+            }
+        })
+        .collect())
+}
+
+pub fn entropy_curve(schema: TrajectorySchema, conf: &EntropyConf) -> Result<EntropyCurve, Error> {
+    let pars = schema.pars.clone();
+    let entropies = match conf.measure {
+        EntropyMeasure::Permutation | EntropyMeasure::ZeroOne => {
+            series_measures(record_trajectories(schema)?, conf)?
+        }
+        EntropyMeasure::Topological => match schema.discrete_map_vec {
+            Some(map_vec) => map_vec.topological_entropies()?,
+            None => bail!("Topological entropy is only estimated for discrete maps!"),
+        },
+    };
+    Ok(EntropyCurve {
+        pars,
+        measure: conf.measure,
+        entropies,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_permutation_and_lap_entropy() {
+        let increasing: Vec<ChaosFloat> = (0..100).map(|i| i as ChaosFloat).collect();
+        assert_eq!(permutation_entropy(&increasing, 3, 1), Some(0.0));
+        // the fully chaotic logistic map misses the decreasing pattern of order 3
+        let mut x = 0.1234;
+        let logistic: Vec<ChaosFloat> = (0..20_000)
+            .map(|_| {
+                x = 4.0 * x * (1.0 - x);
+                x
+            })
+            .collect();
+        let entropy = permutation_entropy(&logistic, 3, 1).expect("long series");
+        assert!(entropy > 0.8 && entropy < 0.95, "entropy is {entropy}");
+        let entropy = lap_entropy(|x| 4.0 * x * (1.0 - x), (0.0, 1.0), false).expect("finite");
+        assert!((entropy - 2_f64.ln()).abs() < 1e-3, "entropy is {entropy}");
+        let entropy = lap_entropy(|x| 3.2 * x * (1.0 - x), (0.0, 1.0), false).expect("finite");
+        assert!(entropy < 1e-3, "entropy is {entropy}");
+        // rigid rotation of the circle has no turning points
+        let entropy = lap_entropy(|x| (x + 0.3).rem_euclid(1.0), (0.0, 1.0), true).expect("finite");
+        assert!(entropy < 1e-3, "entropy is {entropy}");
+    }
+
+    #[test]
+    fn test_zero_one_of_trajectories() -> Result<(), Error> {
+        let logistic = |r: ChaosFloat| -> Vec<Vec<ChaosFloat>> {
+            let mut x = 0.1234;
+            (0..1000)
+                .map(|_| {
+                    x = r * x * (1.0 - x);
+                    vec![x]
+                })
+                .collect()
+        };
+        let recorded = RecordedTrajectories {
+            pars: ("r", vec![4.0, 3.5]),
+            num_dims: 1,
+            sample_time: 1.0,
+            trajectories: vec![vec![logistic(4.0)], vec![logistic(3.5)]],
+        };
+        let conf = EntropyConf {
+            measure: EntropyMeasure::ZeroOne,
+            ..Default::default()
+        };
+        let values = series_measures(recorded, &conf)?;
+        let chaotic = values[0].expect("complete trajectory");
+        assert!(chaotic > 0.9, "K is {chaotic}");
+        let periodic = values[1].expect("complete trajectory");
+        assert!(periodic.abs() < 0.1, "K is {periodic}");
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use super::lyapunov::euclidean_distance;
+use crate::chaos::data::ChaosFloat;
+
+/// Static k-d tree over points of equal dimension, stored as an implicitly balanced index array.
+pub struct KdTree<'a> {
+    points: &'a [Vec<ChaosFloat>],
+    indices: Vec<usize>,
+    num_dims: usize,
+}
+
+impl<'a> KdTree<'a> {
+    pub fn new(points: &'a [Vec<ChaosFloat>]) -> Self {
+        let num_dims = points.first().map_or(0, |p| p.len());
+        let mut indices: Vec<usize> = (0..points.len()).collect();
+        Self::build(points, &mut indices, 0, num_dims);
+        Self {
+            points,
+            indices,
+            num_dims,
+        }
+    }
+
+    fn build(points: &[Vec<ChaosFloat>], indices: &mut [usize], depth: usize, num_dims: usize) {
+        if indices.len() <= 1 || num_dims == 0 {
+            return;
+        }
+        let axis = depth % num_dims;
+        let mid = indices.len() / 2;
+        indices.select_nth_unstable_by(mid, |a, b| points[*a][axis].total_cmp(&points[*b][axis]));
+        let (lower, upper) = indices.split_at_mut(mid);
+        Self::build(points, lower, depth + 1, num_dims);
+        Self::build(points, &mut upper[1..], depth + 1, num_dims);
+    }
+
+    /// Calls `visit(index, distance)` for every point closer than `radius` to `query`.
+    pub fn for_each_within(
+        &self,
+        query: &[ChaosFloat],
+        radius: ChaosFloat,
+        visit: &mut impl FnMut(usize, ChaosFloat),
+    ) {
+        self.search(query, radius, visit, 0, self.indices.len(), 0);
+    }
+
+    fn search(
+        &self,
+        query: &[ChaosFloat],
+        radius: ChaosFloat,
+        visit: &mut impl FnMut(usize, ChaosFloat),
+        lo: usize,
+        hi: usize,
+        depth: usize,
+    ) {
+        if lo >= hi || self.num_dims == 0 {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let index = self.indices[mid];
+        let point = &self.points[index];
+        let distance = euclidean_distance(query, point);
+        if distance < radius {
+            visit(index, distance);
+        }
+        let axis = depth % self.num_dims;
+        let offset = query[axis] - point[axis];
+        if offset - radius < 0.0 {
+            self.search(query, radius, visit, lo, mid, depth + 1);
+        }
+        if offset + radius >= 0.0 {
+            self.search(query, radius, visit, mid + 1, hi, depth + 1);
+        }
+    }
+
+    /// Closest point to `query` whose index is not excluded, with its distance.
+    pub fn nearest(
+        &self,
+        query: &[ChaosFloat],
+        exclude: impl Fn(usize) -> bool,
+    ) -> Option<(usize, ChaosFloat)> {
+        let mut best = None;
+        self.nearest_search(query, &exclude, &mut best, 0, self.indices.len(), 0);
+        best
+    }
+
+    fn nearest_search(
+        &self,
+        query: &[ChaosFloat],
+        exclude: &impl Fn(usize) -> bool,
+        best: &mut Option<(usize, ChaosFloat)>,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+    ) {
+        if lo >= hi || self.num_dims == 0 {
+            return;
+        }
+        let is_closer = |distance: ChaosFloat, best: &Option<(usize, ChaosFloat)>| match best {
+            Some((_, best_distance)) => distance < *best_distance,
+            None => true,
+        };
+        let mid = lo + (hi - lo) / 2;
+        let index = self.indices[mid];
+        let point = &self.points[index];
+        let distance = euclidean_distance(query, point);
+        if !exclude(index) && is_closer(distance, best) {
+            *best = Some((index, distance));
+        }
+        let axis = depth % self.num_dims;
+        let offset = query[axis] - point[axis];
+        let (near, far) = if offset < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.nearest_search(query, exclude, best, near.0, near.1, depth + 1);
+        // the other half can only be closer than the splitting plane
+        if is_closer(offset.abs(), best) {
+            self.nearest_search(query, exclude, best, far.0, far.1, depth + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_kd_tree_radius_query() {
+        let points: Vec<Vec<ChaosFloat>> = (0..10)
+            .flat_map(|i| (0..10).map(move |j| vec![i as ChaosFloat, j as ChaosFloat]))
+            .collect();
+        let tree = KdTree::new(&points);
+        let mut neighbors = Vec::new();
+        tree.for_each_within(&[5.0, 5.0], 1.1, &mut |index, _| neighbors.push(index));
+        neighbors.sort();
+        assert_eq!(neighbors, vec![45, 54, 55, 56, 65]);
+        let nearest = tree.nearest(&[5.2, 4.9], |index| index == 55);
+        assert_eq!(nearest.map(|(index, _)| index), Some(65));
+    }
+}
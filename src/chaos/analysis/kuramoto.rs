@@ -0,0 +1,162 @@
+use super::trajectory::{check_dimensions, evaluate_cells, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{
+    data::*,
+    execution::OdeSystemSolverVec,
+    functions::{order_parameter, KuramotoSolver, OdeSolverTrait},
+};
+use anyhow::{bail, Error};
+
+pub struct KuramotoResult {
+    pub pars: (&'static str, Vec<f64>),
+    pub num_transient: usize,
+    // order parameter r per step for each coupling strength, starts with the transient
+    pub order_parameters: Vec<Vec<ChaosFloat>>,
+}
+
+impl KuramotoResult {
+    /// Time average of r after the transient, i.e. the stationary level of synchronization.
+    pub fn mean_order_parameter(&self, index: usize) -> Option<ChaosFloat> {
+        let stationary = self
+            .order_parameters
+            .get(index)?
+            .get(self.num_transient..)?;
+        if stationary.is_empty() {
+            None
+        } else {
+            Some(stationary.iter().sum::<ChaosFloat>() / stationary.len() as ChaosFloat)
+        }
+    }
+}
+
+fn order_parameter_evolution(
+    solver: &KuramotoSolver,
+    init_data: &ChaosData<State2>,
+    conf: &TrajectoryConf,
+) -> Vec<ChaosFloat> {
+    let mut solver = solver.clone();
+    let mut states = init_data.data().clone();
+    solver.initial_states(&mut states);
+    let num_steps = conf.num_transient + conf.num_steps;
+    let mut order_parameters = Vec::with_capacity(num_steps);
+    for _ in 0..num_steps {
+        solver.execute(&mut states, 1);
+        match order_parameter(&states) {
+            Some((r, _)) => order_parameters.push(r),
+            None => break, // all oscillators escaped
+        }
+    }
+    order_parameters
+}
+
+/// Networks of all coupling strengths start from the same oscillators, one per sample.
+pub fn kuramoto_order_parameters(
+    schema: TrajectorySchema,
+    coupling: Option<CouplingMatrix>,
+) -> Result<KuramotoResult, Error> {
+    let TrajectorySchema {
+        num_samples,
+        init_distr,
+        diff_system_vec,
+        pars,
+        conf,
+        ..
+    } = schema;
+    let solvers = match diff_system_vec {
+        Some(OdeSystemSolverVec::Kuramoto(solvers)) => solvers,
+        _ => bail!("Choose the Kuramoto model as differential system!"),
+    };
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("The Kuramoto model needs a distribution of phases and natural frequencies!"),
+    };
+    check_dimensions(&init_distr, 2)?;
+    // meshes may change the number of samples
+    let init_data = ChaosData::<State2>::new(num_samples, &init_distr);
+    let num_oscillators = init_data.total_num_points();
+    let solvers: Vec<KuramotoSolver> = match coupling {
+        Some(coupling) => {
+            if coupling.num_nodes() != num_oscillators {
+                bail!(
+                    "The coupling matrix has {} rows but there are {num_oscillators} oscillators!",
+                    coupling.num_nodes()
+                );
+            }
+            solvers
+                .into_iter()
+                .map(|mut solver| {
+                    solver.set_coupling(coupling.clone());
+                    solver
+                })
+                .collect()
+        }
+        None => solvers,
+    };
+    Ok(KuramotoResult {
+        pars,
+        num_transient: conf.num_transient,
+        order_parameters: evaluate_cells(&solvers, |solver| {
+            order_parameter_evolution(solver, &init_data, &conf)
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::functions::Kuramoto;
+    #[test]
+    fn test_kuramoto_order_parameters() -> Result<(), Error> {
+        // identical oscillators with phases spread over less than a half circle
+        let init_distr = vec![
+            InitialDistributionVariant::Linspace(Linspace {
+                low: 0.0,
+                high: 3.0,
+            }),
+            InitialDistributionVariant::Fixed(Fixed { value: 0.0 }),
+        ];
+        let schema = |coupling: Option<CouplingMatrix>| {
+            kuramoto_order_parameters(
+                TrajectorySchema {
+                    num_samples: 3,
+                    init_distr: InitialDistributionConfig::States(init_distr.clone()),
+                    discrete_map_vec: None,
+                    diff_system_vec: Some(OdeSystemSolverVec::Kuramoto(vec![
+                        KuramotoSolver::new(Kuramoto { k: 0.0 }),
+                        KuramotoSolver::new(Kuramoto { k: 2.0 }),
+                    ])),
+                    pars: ("k", vec![0.0, 2.0]),
+                    conf: TrajectoryConf {
+                        num_transient: 200,
+                        num_steps: 100,
+                    },
+                    imported: None,
+                },
+                coupling,
+            )
+        };
+        let result = schema(None)?;
+        assert_eq!(result.order_parameters[0].len(), 300);
+        let (initial_r, _) = order_parameter(&[
+            Some(State2::new(0.0, 0.0)),
+            Some(State2::new(1.5, 0.0)),
+            Some(State2::new(3.0, 0.0)),
+        ])
+        .expect("three oscillators");
+        let uncoupled = result.mean_order_parameter(0).expect("no escapes");
+        assert!(
+            (uncoupled - initial_r).abs() < 1e-9,
+            "uncoupled phases rest"
+        );
+        let synchronized = result.mean_order_parameter(1).expect("no escapes");
+        assert!(synchronized > 0.999, "r={synchronized} locks to 1");
+        // a chain 0 - 1 - 2 synchronizes too, an empty network does not
+        let chain = CouplingMatrix::from_text("0 1 0\n1 0 1\n0 1 0")?;
+        let result = schema(Some(chain))?;
+        assert!(result.mean_order_parameter(1).expect("no escapes") > 0.99);
+        let empty = CouplingMatrix::from_text("0 0 0\n0 0 0\n0 0 0")?;
+        let result = schema(Some(empty))?;
+        assert!((result.mean_order_parameter(1).expect("no escapes") - initial_r).abs() < 1e-9);
+        assert!(schema(Some(CouplingMatrix::from_text("0 1\n1 0")?)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use super::trajectory::{check_dimensions, evaluate_cells, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{data::*, functions::DiscreteMap};
+use anyhow::{bail, Error};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum LatticeTopology {
+    #[default]
+    Ring,
+    Grid,
+}
+
+impl From<LatticeTopology> for &'static str {
+    fn from(val: LatticeTopology) -> Self {
+        match val {
+            LatticeTopology::Ring => "Ring",
+            LatticeTopology::Grid => "2D Grid",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct LatticeConf {
+    pub topology: LatticeTopology,
+    // number of sites of the ring or along each axis of the grid
+    pub num_sites: usize,
+    // strength ε of the diffusive coupling to the nearest neighbours
+    pub coupling: ChaosFloat,
+}
+
+impl Default for LatticeConf {
+    fn default() -> Self {
+        Self {
+            topology: Default::default(),
+            num_sites: 100,
+            coupling: 0.3,
+        }
+    }
+}
+
+impl LatticeConf {
+    pub fn total_num_sites(&self) -> usize {
+        match self.topology {
+            LatticeTopology::Ring => self.num_sites,
+            LatticeTopology::Grid => self.num_sites * self.num_sites,
+        }
+    }
+
+    // periodic boundaries, the grid is stored row by row
+    fn neighbours(&self, site: usize) -> Vec<usize> {
+        let n = self.num_sites;
+        match self.topology {
+            LatticeTopology::Ring => vec![(site + n - 1) % n, (site + 1) % n],
+            LatticeTopology::Grid => {
+                let (row, column) = (site / n, site % n);
+                vec![
+                    ((row + n - 1) % n) * n + column,
+                    ((row + 1) % n) * n + column,
+                    row * n + (column + n - 1) % n,
+                    row * n + (column + 1) % n,
+                ]
+            }
+        }
+    }
+}
+
+pub struct LatticeEvolution {
+    // site values per recorded step, ends early if a site escaped
+    pub space_time: Vec<Vec<ChaosFloat>>,
+    // standard deviation over all sites per recorded step
+    pub sync_errors: Vec<ChaosFloat>,
+}
+
+impl LatticeEvolution {
+    pub fn mean_sync_error(&self) -> Option<ChaosFloat> {
+        if self.sync_errors.is_empty() {
+            None
+        } else {
+            Some(self.sync_errors.iter().sum::<ChaosFloat>() / self.sync_errors.len() as ChaosFloat)
+        }
+    }
+}
+
+pub struct LatticeResult {
+    pub pars: (&'static str, Vec<f64>),
+    pub conf: LatticeConf,
+    // one entry per discrete map
+    pub evolutions: Vec<LatticeEvolution>,
+}
+
+fn sync_error(sites: &[ChaosFloat]) -> ChaosFloat {
+    let n = sites.len() as ChaosFloat;
+    let mean = sites.iter().sum::<ChaosFloat>() / n;
+    (sites.iter().map(|x| (x - mean).powi(2)).sum::<ChaosFloat>() / n).sqrt()
+}
+
+/// Kaneko's diffusive coupling x' = (1 - ε) f(x) + ε mean(f(neighbours)) of the local map f.
+fn lattice_step<D: DiscreteMap<State = State1>>(
+    map: &D,
+    conf: &LatticeConf,
+    sites: &[ChaosFloat],
+    t: &Time,
+) -> Option<Vec<ChaosFloat>> {
+    let mut local = Vec::with_capacity(sites.len());
+    for x in sites {
+        let mut state = State1::new(*x);
+        map.execute(&mut state, t);
+        if !state.is_valid() {
+            return None;
+        }
+        local.push(state[0]);
+    }
+    Some(
+        (0..local.len())
+            .map(|site| {
+                let neighbours = conf.neighbours(site);
+                let neighbour_mean = neighbours.iter().map(|j| local[*j]).sum::<ChaosFloat>()
+                    / neighbours.len() as ChaosFloat;
+                (1.0 - conf.coupling) * local[site] + conf.coupling * neighbour_mean
+            })
+            .collect(),
+    )
+}
+
+fn evolve_lattice<D: DiscreteMap<State = State1>>(
+    map: &D,
+    conf: &LatticeConf,
+    initial_sites: &[ChaosFloat],
+    trajectory_conf: &TrajectoryConf,
+) -> LatticeEvolution {
+    let mut sites = initial_sites.to_vec();
+    let mut evolution = LatticeEvolution {
+        space_time: Vec::with_capacity(trajectory_conf.num_steps),
+        sync_errors: Vec::with_capacity(trajectory_conf.num_steps),
+    };
+    for step in 0..(trajectory_conf.num_transient + trajectory_conf.num_steps) {
+        sites = match lattice_step(map, conf, &sites, &(step as Time)) {
+            Some(sites) => sites,
+            None => break,
+        };
+        if step >= trajectory_conf.num_transient {
+            evolution.sync_errors.push(sync_error(&sites));
+            evolution.space_time.push(sites.clone());
+        }
+    }
+    evolution
+}
+
+pub fn discrete_lattices<D>(
+    maps: &[D],
+    conf: &LatticeConf,
+    initial_sites: &[ChaosFloat],
+    trajectory_conf: &TrajectoryConf,
+) -> Vec<LatticeEvolution>
+where
+    D: DiscreteMap<State = State1> + Sync,
+{
+    evaluate_cells(maps, |map| {
+        evolve_lattice(map, conf, initial_sites, trajectory_conf)
+    })
+}
+
+/// Lattices of all maps start from the same sites, one sample of the initial distribution per site.
+pub fn coupled_map_lattice(
+    schema: TrajectorySchema,
+    conf: &LatticeConf,
+) -> Result<LatticeResult, Error> {
+    let TrajectorySchema {
+        init_distr,
+        discrete_map_vec,
+        pars,
+        conf: trajectory_conf,
+        ..
+    } = schema;
+    let map_vec = match discrete_map_vec {
+        Some(map_vec) => map_vec,
+        None => bail!("Coupled map lattices need a discrete map as local dynamics!"),
+    };
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Coupled map lattices are only available for state distributions!"),
+    };
+    check_dimensions(&init_distr, 1)?;
+    let num_sites = conf.total_num_sites();
+    let initial_sites: Vec<ChaosFloat> = ChaosData::<State1>::new(num_sites, &init_distr)
+        .data_filtered()
+        .into_iter()
+        .take(num_sites)
+        .map(|state| state[0])
+        .collect();
+    if initial_sites.len() < num_sites {
+        bail!("The initial distribution must provide a value for each of the {num_sites} sites!");
+    }
+    let evolutions = map_vec.lattices(conf, &initial_sites, &trajectory_conf)?;
+    Ok(LatticeResult {
+        pars,
+        conf: *conf,
+        evolutions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::functions::Logistic;
+    #[test]
+    fn test_logistic_lattice() {
+        let logistic = Logistic { r: 4.0 };
+        let trajectory_conf = TrajectoryConf {
+            num_transient: 0,
+            num_steps: 200,
+        };
+        let initial_sites = [0.1, 0.35, 0.7];
+        // uncoupled chaotic sites stay apart
+        let conf = LatticeConf {
+            topology: LatticeTopology::Ring,
+            num_sites: 3,
+            coupling: 0.0,
+        };
+        let evolution = evolve_lattice(&logistic, &conf, &initial_sites, &trajectory_conf);
+        assert_eq!(evolution.space_time.len(), 200);
+        assert!(evolution.mean_sync_error().expect("bounded sites") > 0.1);
+        // differences between sites shrink by (1 - 3ε/2) |f'| <= 0.4 on a ring of three sites
+        let conf = LatticeConf {
+            coupling: 0.6,
+            ..conf
+        };
+        let evolution = evolve_lattice(&logistic, &conf, &initial_sites, &trajectory_conf);
+        assert!(evolution.sync_errors.last().expect("bounded sites") < &1e-9);
+        // the grid couples four neighbours with periodic boundaries
+        let conf = LatticeConf {
+            topology: LatticeTopology::Grid,
+            num_sites: 3,
+            coupling: 0.5,
+        };
+        assert_eq!(conf.total_num_sites(), 9);
+        let mut neighbours = conf.neighbours(0);
+        neighbours.sort();
+        assert_eq!(neighbours, vec![1, 2, 3, 6]);
+    }
+}
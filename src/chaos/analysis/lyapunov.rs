@@ -0,0 +1,75 @@
+use crate::chaos::data::{ChaosFloat, FromStateVec, StateIndex};
+
+// initial distance between a trajectory and its twin
+pub const LYAPUNOV_PERTURBATION: ChaosFloat = 1e-8;
+
+pub fn state_vec<V: StateIndex>(v: &V, num_dims: usize) -> Vec<ChaosFloat> {
+    (0..num_dims).map(|i| v.ind(i)).collect()
+}
+
+pub fn perturbed_state<V: StateIndex + FromStateVec>(v: &V, num_dims: usize) -> V {
+    let mut twin = state_vec(v, num_dims);
+    twin[0] += LYAPUNOV_PERTURBATION;
+    V::from(twin)
+}
+
+pub fn euclidean_distance(a: &[ChaosFloat], b: &[ChaosFloat]) -> ChaosFloat {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<ChaosFloat>()
+        .sqrt()
+}
+
+/// Benettin renormalization: returns the logarithmic stretch of the separation since the last
+/// renormalization and the twin state pulled back to the initial perturbation distance.
+pub fn renormalize_twin(base: &[ChaosFloat], twin: &[ChaosFloat]) -> (ChaosFloat, Vec<ChaosFloat>) {
+    let distance = euclidean_distance(base, twin);
+    if distance < ChaosFloat::MIN_POSITIVE {
+        // twin collapsed onto the base trajectory (superstable orbit)
+        let mut renormalized = base.to_vec();
+        renormalized[0] += LYAPUNOV_PERTURBATION;
+        return (
+            (ChaosFloat::EPSILON / LYAPUNOV_PERTURBATION).ln(),
+            renormalized,
+        );
+    }
+    let scale = LYAPUNOV_PERTURBATION / distance;
+    let renormalized = base
+        .iter()
+        .zip(twin.iter())
+        .map(|(b, t)| b + (t - b) * scale)
+        .collect();
+    ((distance / LYAPUNOV_PERTURBATION).ln(), renormalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_renormalize_twin() {
+        let base = [1.0, 2.0];
+        let twin = [1.0 + 2.0 * LYAPUNOV_PERTURBATION, 2.0];
+        let (stretch, renormalized) = renormalize_twin(&base, &twin);
+        assert!((stretch - 2.0_f64.ln()).abs() < 1e-6);
+        let distance = euclidean_distance(&base, &renormalized);
+        assert!((distance - LYAPUNOV_PERTURBATION).abs() < 1e-12);
+    }
+    #[test]
+    fn test_logistic_lyapunov_exponent() {
+        // fully chaotic logistic map has the exponent ln(2)
+        let logistic = |x: f64| 4.0 * x * (1.0 - x);
+        let (mut x, mut x_twin) = (0.1, 0.1 + LYAPUNOV_PERTURBATION);
+        let num_steps = 10_000;
+        let mut log_stretch = 0.0;
+        for _ in 0..num_steps {
+            x = logistic(x);
+            x_twin = logistic(x_twin);
+            let (stretch, renormalized) = renormalize_twin(&[x], &[x_twin]);
+            log_stretch += stretch;
+            x_twin = renormalized[0];
+        }
+        let lyapunov = log_stretch / num_steps as f64;
+        assert!((lyapunov - 2.0_f64.ln()).abs() < 0.05);
+    }
+}
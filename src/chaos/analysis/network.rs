@@ -0,0 +1,217 @@
+use super::trajectory::{check_dimensions, evaluate_cells, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{
+    data::*,
+    functions::{rk4_step, NUM_RK4_SUBSTEPS, ODE_STEP_SIZE},
+};
+use anyhow::{bail, Error};
+use nalgebra::SVector;
+use ode_solvers::System;
+
+pub struct NetworkConf {
+    // row i holds the couplings from all nodes into node i
+    pub matrix: CouplingMatrix,
+    // strength σ of the diffusive coupling
+    pub coupling: ChaosFloat,
+    // index of the state variable that is coupled
+    pub variable: usize,
+}
+
+pub struct NetworkEvolution {
+    // coupled variable per node and recorded step, ends early if a node escaped
+    pub node_values: Vec<Vec<ChaosFloat>>,
+    // root mean square distance of the nodes to their mean state per recorded step
+    pub sync_errors: Vec<ChaosFloat>,
+}
+
+impl NetworkEvolution {
+    pub fn mean_sync_error(&self) -> Option<ChaosFloat> {
+        if self.sync_errors.is_empty() {
+            None
+        } else {
+            Some(self.sync_errors.iter().sum::<ChaosFloat>() / self.sync_errors.len() as ChaosFloat)
+        }
+    }
+}
+
+pub struct NetworkResult {
+    pub pars: (&'static str, Vec<f64>),
+    // one entry per differential system
+    pub evolutions: Vec<NetworkEvolution>,
+}
+
+fn sync_error<const D: usize>(nodes: &[SVector<ChaosFloat, D>]) -> ChaosFloat {
+    let n = nodes.len() as ChaosFloat;
+    let mean = nodes.iter().sum::<SVector<ChaosFloat, D>>() / n;
+    (nodes
+        .iter()
+        .map(|node| (node - mean).norm_squared())
+        .sum::<ChaosFloat>()
+        / n)
+        .sqrt()
+}
+
+/// dxᵢ = f(xᵢ) + σ ∑ⱼ Aᵢⱼ (xⱼ - xᵢ) in the coupled variable, all other variables evolve freely.
+fn network_velocities<const D: usize, T: System<Time, SVector<ChaosFloat, D>>>(
+    system: &T,
+    conf: &NetworkConf,
+    t: Time,
+    nodes: &[SVector<ChaosFloat, D>],
+) -> Vec<SVector<ChaosFloat, D>> {
+    let c = conf.variable;
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            let mut dx = SVector::<ChaosFloat, D>::zeros();
+            system.system(t, x_i, &mut dx);
+            let diffusion: ChaosFloat = nodes
+                .iter()
+                .enumerate()
+                .map(|(j, x_j)| conf.matrix.weight(i, j) * (x_j[c] - x_i[c]))
+                .sum();
+            dx[c] += conf.coupling * diffusion;
+            dx
+        })
+        .collect()
+}
+
+fn evolve_network<const D: usize, T: System<Time, SVector<ChaosFloat, D>>>(
+    system: &T,
+    conf: &NetworkConf,
+    initial_nodes: &[SVector<ChaosFloat, D>],
+    trajectory_conf: &TrajectoryConf,
+) -> NetworkEvolution
+where
+    SVector<ChaosFloat, D>: ValidStateCheck,
+{
+    let mut nodes = initial_nodes.to_vec();
+    let mut evolution = NetworkEvolution {
+        node_values: vec![Vec::with_capacity(trajectory_conf.num_steps); nodes.len()],
+        sync_errors: Vec::with_capacity(trajectory_conf.num_steps),
+    };
+    let dt = ODE_STEP_SIZE / NUM_RK4_SUBSTEPS as Time;
+    for step in 0..(trajectory_conf.num_transient + trajectory_conf.num_steps) {
+        for substep in 0..NUM_RK4_SUBSTEPS {
+            let t = step as Time * ODE_STEP_SIZE + substep as Time * dt;
+            rk4_step(&mut nodes, t, dt, |t, nodes| {
+                network_velocities(system, conf, t, nodes)
+            });
+        }
+        if !nodes.iter().all(|node| node.is_valid()) {
+            break;
+        }
+        if step >= trajectory_conf.num_transient {
+            evolution.sync_errors.push(sync_error(&nodes));
+            nodes
+                .iter()
+                .zip(evolution.node_values.iter_mut())
+                .for_each(|(node, values)| values.push(node[conf.variable]));
+        }
+    }
+    evolution
+}
+
+/// Networks of all systems start from the same nodes, one sample of the initial distribution per node.
+pub fn continuous_networks<const D: usize, T>(
+    systems: &[T],
+    init_distr: &[InitialDistributionVariant],
+    conf: &NetworkConf,
+    trajectory_conf: &TrajectoryConf,
+) -> Result<Vec<NetworkEvolution>, Error>
+where
+    T: System<Time, SVector<ChaosFloat, D>> + Sync,
+    SVector<ChaosFloat, D>: FromStateVec + ValidStateCheck,
+{
+    check_dimensions(init_distr, D)?;
+    if conf.variable >= D {
+        bail!("The coupled variable must be one of the {D} state variables!");
+    }
+    let num_nodes = conf.matrix.num_nodes();
+    let initial_nodes: Vec<SVector<ChaosFloat, D>> =
+        ChaosData::<SVector<ChaosFloat, D>>::new(num_nodes, init_distr)
+            .data_filtered()
+            .into_iter()
+            .take(num_nodes)
+            .copied()
+            .collect();
+    if initial_nodes.len() < num_nodes {
+        bail!("The initial distribution must provide a state for each of the {num_nodes} nodes!");
+    }
+    Ok(evaluate_cells(systems, |system| {
+        evolve_network(system, conf, &initial_nodes, trajectory_conf)
+    }))
+}
+
+pub fn coupled_networks(
+    schema: TrajectorySchema,
+    conf: &NetworkConf,
+) -> Result<NetworkResult, Error> {
+    let TrajectorySchema {
+        init_distr,
+        diff_system_vec,
+        pars,
+        conf: trajectory_conf,
+        ..
+    } = schema;
+    let solver_vec = match diff_system_vec {
+        Some(solver_vec) => solver_vec,
+        None => bail!("Networks need a differential system as node dynamics!"),
+    };
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Networks are only available for state distributions!"),
+    };
+    let evolutions = solver_vec.networks(&init_distr, conf, &trajectory_conf)?;
+    Ok(NetworkResult { pars, evolutions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::functions::Lorenz;
+    #[test]
+    fn test_lorenz_network() -> Result<(), Error> {
+        // two Lorenz systems that start apart
+        let init_distr = vec![
+            InitialDistributionVariant::Linspace(Linspace {
+                low: 1.0,
+                high: 2.0,
+            }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+        ];
+        let trajectory_conf = TrajectoryConf {
+            num_transient: 300,
+            num_steps: 100,
+        };
+        let network = |matrix: &str, coupling: ChaosFloat| -> Result<NetworkEvolution, Error> {
+            let conf = NetworkConf {
+                matrix: CouplingMatrix::from_text(matrix)?,
+                coupling,
+                variable: 0,
+            };
+            let mut evolutions =
+                continuous_networks(&[Lorenz::default()], &init_distr, &conf, &trajectory_conf)?;
+            Ok(evolutions.remove(0))
+        };
+        let uncoupled = network("0 1\n1 0", 0.0)?;
+        assert_eq!(uncoupled.sync_errors.len(), 100);
+        assert_eq!(uncoupled.node_values.len(), 2);
+        assert!(uncoupled.mean_sync_error().expect("no escapes") > 1.0);
+        let mutual = network("0 1\n1 0", 10.0)?;
+        assert!(mutual.mean_sync_error().expect("no escapes") < 1e-6);
+        // the slave in row 2 follows the master in row 1
+        let master_slave = network("0 0\n1 0", 20.0)?;
+        assert!(master_slave.mean_sync_error().expect("no escapes") < 1e-6);
+        let conf = NetworkConf {
+            matrix: CouplingMatrix::from_text("0 1\n1 0")?,
+            coupling: 1.0,
+            variable: 3,
+        };
+        assert!(
+            continuous_networks(&[Lorenz::default()], &init_distr, &conf, &trajectory_conf)
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,482 @@
+use super::lyapunov::{perturbed_state, renormalize_twin, state_vec};
+use super::periodicity::{detect_period, period_from_maxima, MAX_PERIOD};
+use super::rotation::circle_cells;
+use super::trajectory::{check_dimensions, evaluate_cells};
+use super::zero_one::zero_one_test;
+use crate::chaos::{
+    data::*,
+    execution::{DiscreteMapVec, OdeSystemSolverVec},
+    functions::{DiscreteMap, OdeSolverTrait, ODE_STEP_SIZE},
+};
+use anyhow::{bail, Error};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+// number of ODE steps between two renormalizations of the twin trajectory
+const LYAPUNOV_INTERVAL_CONTINUOUS: usize = 50;
+// number of ODE steps between two values of the 0-1 test, oversampled flows look regular
+const ZERO_ONE_INTERVAL_CONTINUOUS: usize = 5;
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum ParameterPlaneSummary {
+    #[default]
+    Lyapunov,
+    Period,
+    Divergence,
+    RotationNumber,
+    ZeroOne,
+}
+
+impl From<ParameterPlaneSummary> for &'static str {
+    fn from(val: ParameterPlaneSummary) -> Self {
+        match val {
+            ParameterPlaneSummary::Lyapunov => "Lyapunov Exponent",
+            ParameterPlaneSummary::Period => "Period",
+            ParameterPlaneSummary::Divergence => "Divergence Fraction",
+            ParameterPlaneSummary::RotationNumber => "Rotation Number",
+            ParameterPlaneSummary::ZeroOne => "0-1 Test",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ParameterPlaneConf {
+    pub summary: ParameterPlaneSummary,
+    // state component of the observed time series of the 0-1 test and of ODE periods
+    pub component: usize,
+    pub num_transient: usize,
+    pub num_steps: usize,
+}
+
+impl Default for ParameterPlaneConf {
+    fn default() -> Self {
+        Self {
+            summary: Default::default(),
+            component: 0,
+            num_transient: 500,
+            num_steps: 500,
+        }
+    }
+}
+
+pub struct ParameterPlaneSchema {
+    pub num_samples: usize,
+    pub init_distr: InitialDistributionConfig,
+    pub discrete_map_vec: Option<DiscreteMapVec>,
+    pub diff_system_vec: Option<OdeSystemSolverVec>,
+    pub pars_x: (&'static str, Vec<f64>),
+    pub pars_y: (&'static str, Vec<f64>),
+    pub conf: ParameterPlaneConf,
+}
+
+pub struct ParameterPlaneResult {
+    pub pars_x: (&'static str, Vec<f64>),
+    pub pars_y: (&'static str, Vec<f64>),
+    pub summary: ParameterPlaneSummary,
+    // row-major with the y-parameter as outer index
+    pub values: Vec<Option<ChaosFloat>>,
+}
+
+pub fn parameter_plane(schema: ParameterPlaneSchema) -> Result<ParameterPlaneResult, Error> {
+    let ParameterPlaneSchema {
+        num_samples,
+        init_distr,
+        discrete_map_vec,
+        diff_system_vec,
+        pars_x,
+        pars_y,
+        conf,
+    } = schema;
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Parameter planes are only available for state distributions!"),
+    };
+    let values = if conf.summary == ParameterPlaneSummary::RotationNumber {
+        circle_cells(discrete_map_vec, num_samples, &init_distr, &conf)?
+    } else if let Some(map_vec) = discrete_map_vec {
+        map_vec.parameter_plane_cells(num_samples, &init_distr, &conf)?
+    } else if let Some(system_vec) = diff_system_vec {
+        system_vec.parameter_plane_cells(num_samples, &init_distr, &conf)?
+    } else {
+        bail!("Cannot compute a parameter plane without a chaotic function!")
+    };
+    if values.len() != pars_x.1.len() * pars_y.1.len() {
+        bail!("The number of configurations does not match the parameter grid!");
+    }
+    Ok(ParameterPlaneResult {
+        pars_x,
+        pars_y,
+        summary: conf.summary,
+        values,
+    })
+}
+
+fn aggregate_samples(
+    summary: ParameterPlaneSummary,
+    samples: Vec<Option<ChaosFloat>>,
+) -> Option<ChaosFloat> {
+    let num_samples = samples.len();
+    if num_samples == 0 {
+        return None;
+    }
+    let valid: Vec<ChaosFloat> = samples.into_iter().flatten().collect();
+    match summary {
+        ParameterPlaneSummary::Divergence => {
+            Some((num_samples - valid.len()) as ChaosFloat / num_samples as ChaosFloat)
+        }
+        ParameterPlaneSummary::Lyapunov
+        | ParameterPlaneSummary::RotationNumber
+        | ParameterPlaneSummary::ZeroOne => {
+            if valid.is_empty() {
+                None
+            } else {
+                Some(valid.iter().sum::<ChaosFloat>() / valid.len() as ChaosFloat)
+            }
+        }
+        ParameterPlaneSummary::Period => {
+            // most frequent period (0 marks aperiodic samples)
+            let mut counts = [0_usize; MAX_PERIOD + 1];
+            valid.iter().for_each(|p| counts[*p as usize] += 1);
+            counts
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .max_by_key(|(_, count)| **count)
+                .map(|(p, _)| p as ChaosFloat)
+        }
+    }
+}
+
+pub fn discrete_cells<V, D>(
+    maps: &[D],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &ParameterPlaneConf,
+) -> Result<Vec<Option<ChaosFloat>>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    D: DiscreteMap<State = V> + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    if conf.component >= num_dims {
+        bail!("The selected state component does not exist!");
+    }
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    Ok(evaluate_cells(maps, |map| {
+        let samples = init_data
+            .data_filtered()
+            .into_iter()
+            .map(|y0| discrete_sample(map, y0, num_dims, conf))
+            .collect();
+        aggregate_samples(conf.summary, samples)
+    }))
+}
+
+fn discrete_sample<V, D>(
+    map: &D,
+    y0: &V,
+    num_dims: usize,
+    conf: &ParameterPlaneConf,
+) -> Option<ChaosFloat>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone,
+    D: DiscreteMap<State = V>,
+{
+    let mut y = y0.clone();
+    let mut t: Time = 0.0;
+    for _ in 0..conf.num_transient {
+        map.execute(&mut y, &t);
+        t += 1.0;
+        if !y.is_valid() {
+            return None;
+        }
+    }
+    match conf.summary {
+        ParameterPlaneSummary::Divergence => {
+            for _ in 0..conf.num_steps {
+                map.execute(&mut y, &t);
+                t += 1.0;
+                if !y.is_valid() {
+                    return None;
+                }
+            }
+            Some(0.0)
+        }
+        ParameterPlaneSummary::Period => {
+            let mut trajectory = Vec::with_capacity(conf.num_steps);
+            for _ in 0..conf.num_steps {
+                map.execute(&mut y, &t);
+                t += 1.0;
+                if !y.is_valid() {
+                    return None;
+                }
+                trajectory.push(state_vec(&y, num_dims));
+            }
+            Some(detect_period(&trajectory, MAX_PERIOD).unwrap_or(0) as ChaosFloat)
+        }
+        ParameterPlaneSummary::Lyapunov => {
+            let mut twin = perturbed_state(&y, num_dims);
+            let mut log_stretch = 0.0;
+            for _ in 0..conf.num_steps {
+                map.execute(&mut y, &t);
+                map.execute(&mut twin, &t);
+                t += 1.0;
+                if !y.is_valid() || !twin.is_valid() {
+                    return None;
+                }
+                let (stretch, renormalized) =
+                    renormalize_twin(&state_vec(&y, num_dims), &state_vec(&twin, num_dims));
+                log_stretch += stretch;
+                twin = V::from(renormalized);
+            }
+            Some(log_stretch / conf.num_steps.max(1) as ChaosFloat)
+        }
+        ParameterPlaneSummary::ZeroOne => {
+            let mut series = Vec::with_capacity(conf.num_steps);
+            for _ in 0..conf.num_steps {
+                map.execute(&mut y, &t);
+                t += 1.0;
+                if !y.is_valid() {
+                    return None;
+                }
+                series.push(y.ind(conf.component));
+            }
+            zero_one_test(&series)
+        }
+        // only the lift of the Circle map has a rotation number
+        ParameterPlaneSummary::RotationNumber => None,
+    }
+}
+
+pub fn continuous_cells<V, O>(
+    solvers: &[O],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &ParameterPlaneConf,
+) -> Result<Vec<Option<ChaosFloat>>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    O: OdeSolverTrait<State = V> + Clone + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    if conf.component >= num_dims {
+        bail!("The selected state component does not exist!");
+    }
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    Ok(evaluate_cells(solvers, |solver| {
+        let samples = init_data
+            .data_filtered()
+            .into_iter()
+            .map(|y0| continuous_sample(solver, y0, num_dims, conf))
+            .collect();
+        aggregate_samples(conf.summary, samples)
+    }))
+}
+
+fn continuous_sample<V, O>(
+    solver: &O,
+    y0: &V,
+    num_dims: usize,
+    conf: &ParameterPlaneConf,
+) -> Option<ChaosFloat>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone,
+    O: OdeSolverTrait<State = V> + Clone,
+{
+    let mut solver = solver.clone();
+    let mut states = vec![Some(y0.clone())];
+    solver.initial_states(&mut states);
+    solver.execute(&mut states, conf.num_transient);
+    let y = states[0].clone()?;
+    match conf.summary {
+        ParameterPlaneSummary::Divergence => {
+            solver.execute(&mut states, conf.num_steps);
+            states[0].as_ref().map(|_| 0.0)
+        }
+        ParameterPlaneSummary::Period => {
+            let mut series = Vec::with_capacity(conf.num_steps);
+            for _ in 0..conf.num_steps {
+                solver.execute(&mut states, 1);
+                series.push(states[0].as_ref()?.ind(conf.component));
+            }
+            Some(period_from_maxima(&series, MAX_PERIOD).unwrap_or(0) as ChaosFloat)
+        }
+        ParameterPlaneSummary::Lyapunov => {
+            let twin = perturbed_state(&y, num_dims);
+            let mut states = vec![Some(y), Some(twin)];
+            solver.initial_states(&mut states);
+            let num_intervals = (conf.num_steps / LYAPUNOV_INTERVAL_CONTINUOUS).max(1);
+            let mut log_stretch = 0.0;
+            for _ in 0..num_intervals {
+                solver.execute(&mut states, LYAPUNOV_INTERVAL_CONTINUOUS);
+                let (stretch, renormalized) = renormalize_twin(
+                    &state_vec(states[0].as_ref()?, num_dims),
+                    &state_vec(states[1].as_ref()?, num_dims),
+                );
+                log_stretch += stretch;
+                states[1] = Some(V::from(renormalized));
+                solver.reinit_states(&mut states, vec![1]);
+            }
+            let total_time =
+                (num_intervals * LYAPUNOV_INTERVAL_CONTINUOUS) as ChaosFloat * ODE_STEP_SIZE;
+            Some(log_stretch / total_time)
+        }
+        ParameterPlaneSummary::ZeroOne => {
+            let num_values = conf.num_steps / ZERO_ONE_INTERVAL_CONTINUOUS;
+            let mut series = Vec::with_capacity(num_values);
+            for _ in 0..num_values {
+                solver.execute(&mut states, ZERO_ONE_INTERVAL_CONTINUOUS);
+                series.push(states[0].as_ref()?.ind(conf.component));
+            }
+            zero_one_test(&series)
+        }
+        ParameterPlaneSummary::RotationNumber => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::functions::*;
+    #[test]
+    fn test_parameter_plane_logistic() -> Result<(), Error> {
+        let r_values = vec![2.5, 3.2, 4.0];
+        let maps: Vec<_> = r_values
+            .iter()
+            .map(|r| SimpleDiscreteMap::new(Logistic { r: *r }))
+            .collect();
+        let schema = |summary| ParameterPlaneSchema {
+            num_samples: 3,
+            init_distr: InitialDistributionConfig::States(vec![InitialDistributionVariant::Fixed(
+                Fixed { value: 0.1 },
+            )]),
+            discrete_map_vec: Some(DiscreteMapVec::Logistic(maps.clone())),
+            diff_system_vec: None,
+            pars_x: ("r", r_values.clone()),
+            pars_y: ("none", vec![0.0]),
+            conf: ParameterPlaneConf {
+                summary,
+                num_transient: 200,
+                num_steps: 2000,
+                ..Default::default()
+            },
+        };
+        let periods = parameter_plane(schema(ParameterPlaneSummary::Period))?.values;
+        assert_eq!(periods, vec![Some(1.0), Some(2.0), Some(0.0)]);
+        let lyapunov = parameter_plane(schema(ParameterPlaneSummary::Lyapunov))?.values;
+        assert!(lyapunov[0].expect("Fixpoint is stable") < 0.0);
+        let chaotic_exponent = lyapunov[2].expect("r=4 stays in the unit interval");
+        assert!((chaotic_exponent - 2.0_f64.ln()).abs() < 0.1);
+        let zero_one = parameter_plane(schema(ParameterPlaneSummary::ZeroOne))?.values;
+        assert!(zero_one[0].expect("Fixpoint is regular") < 0.1);
+        assert!(zero_one[2].expect("r=4 is chaotic") > 0.9);
+        let divergence = parameter_plane(schema(ParameterPlaneSummary::Divergence))?.values;
+        assert_eq!(divergence, vec![Some(0.0); 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parameter_plane_henon() -> Result<(), Error> {
+        let (a_values, b_values) = (vec![0.2, 1.0, 1.4], vec![-0.3, 0.3]);
+        // row-major with b as outer index like the parameter plane of the execute panel
+        let maps: Vec<_> = b_values
+            .iter()
+            .flat_map(|b| a_values.iter().map(|a| Henon { a: *a, b: *b }))
+            .map(SimpleDiscreteMap::new)
+            .collect();
+        let schema = |summary, pars_y| ParameterPlaneSchema {
+            num_samples: 1,
+            init_distr: InitialDistributionConfig::States(vec![
+                InitialDistributionVariant::Fixed(Fixed { value: 0.0 }),
+                InitialDistributionVariant::Fixed(Fixed { value: 0.0 }),
+            ]),
+            discrete_map_vec: Some(DiscreteMapVec::Henon(maps.clone())),
+            diff_system_vec: None,
+            pars_x: ("a", a_values.clone()),
+            pars_y,
+            conf: ParameterPlaneConf {
+                summary,
+                num_transient: 200,
+                num_steps: 2000,
+                ..Default::default()
+            },
+        };
+        let result = parameter_plane(schema(
+            ParameterPlaneSummary::Period,
+            ("b", b_values.clone()),
+        ))?;
+        assert_eq!(result.values.len(), a_values.len() * b_values.len());
+        // the period doubling cascade is faster for b=0.3 than for b=-0.3
+        let periods = [1.0, 1.0, 2.0, 1.0, 4.0, 0.0].map(Some).to_vec();
+        assert_eq!(result.values, periods);
+        let lyapunov = parameter_plane(schema(
+            ParameterPlaneSummary::Lyapunov,
+            ("b", b_values.clone()),
+        ))?
+        .values;
+        assert!(lyapunov[0].expect("Fixpoint is stable") < 0.0);
+        assert!(lyapunov[5].expect("Hénon attractor is bounded") > 0.3);
+        // the 0-1 test of the second component
+        let zero_one_schema = |component| {
+            let mut schema = schema(ParameterPlaneSummary::ZeroOne, ("b", b_values.clone()));
+            schema.conf.component = component;
+            schema
+        };
+        let zero_one = parameter_plane(zero_one_schema(1))?.values;
+        assert!(zero_one[0].expect("Fixpoint is regular") < 0.1);
+        assert!(zero_one[5].expect("Hénon attractor is chaotic") > 0.9);
+        assert!(
+            parameter_plane(zero_one_schema(2)).is_err(),
+            "The Hénon map has two components!"
+        );
+        assert!(
+            parameter_plane(schema(ParameterPlaneSummary::Period, ("b", vec![0.3]))).is_err(),
+            "The grid must match the number of maps!"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parameter_plane_lorenz() -> Result<(), Error> {
+        let (rho_values, sigma_values) = (vec![10.0, 28.0], vec![10.0, -10.0]);
+        let solvers: Vec<_> = sigma_values
+            .iter()
+            .flat_map(|sigma| {
+                rho_values.iter().map(|rho| Lorenz {
+                    sigma: *sigma,
+                    rho: *rho,
+                    ..Default::default()
+                })
+            })
+            .map(OdeSolver::new)
+            .collect();
+        let schema =
+            |summary| ParameterPlaneSchema {
+                num_samples: 1,
+                init_distr: InitialDistributionConfig::States(vec![
+                InitialDistributionVariant::Fixed(Fixed { value: 1.0 });
+                3
+            ]),
+                discrete_map_vec: None,
+                diff_system_vec: Some(OdeSystemSolverVec::Lorenz(solvers.clone())),
+                pars_x: ("rho", rho_values.clone()),
+                pars_y: ("sigma", sigma_values.clone()),
+                conf: ParameterPlaneConf {
+                    summary,
+                    num_transient: 500,
+                    num_steps: 2000,
+                    ..Default::default()
+                },
+            };
+        // a negative sigma blows up in finite time
+        let divergence = parameter_plane(schema(ParameterPlaneSummary::Divergence))?.values;
+        assert_eq!(divergence, [0.0, 0.0, 1.0, 1.0].map(Some).to_vec());
+        let lyapunov = parameter_plane(schema(ParameterPlaneSummary::Lyapunov))?.values;
+        assert!(lyapunov[0].expect("Fixpoint is stable") < 0.0);
+        assert!(lyapunov[1].expect("Lorenz attractor is bounded") > 0.5);
+        assert_eq!(lyapunov[2..], [None, None]);
+        Ok(())
+    }
+}
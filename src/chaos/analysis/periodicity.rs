@@ -0,0 +1,118 @@
+use crate::chaos::data::ChaosFloat;
+
+pub const MAX_PERIOD: usize = 32;
+const PERIOD_TOLERANCE_DISCRETE: ChaosFloat = 1e-6;
+const PERIOD_TOLERANCE_CONTINUOUS: ChaosFloat = 1e-2;
+
+/// Smallest period p <= max_period such that the tail of the trajectory repeats after p steps.
+pub fn detect_period(trajectory: &[Vec<ChaosFloat>], max_period: usize) -> Option<usize> {
+    let num_points = trajectory.len();
+    let max_period = max_period.min(num_points / 2);
+    if max_period == 0 {
+        return None;
+    }
+    let tail_start = num_points - max_period;
+    (1..=max_period).find(|p| {
+        (tail_start..num_points).all(|t| {
+            trajectory[t]
+                .iter()
+                .zip(trajectory[t - p].iter())
+                .all(|(x, x_prev)| {
+                    (x - x_prev).abs() <= PERIOD_TOLERANCE_DISCRETE * (1.0 + x_prev.abs())
+                })
+        })
+    })
+}
+
+/// Maximum at the middle of three consecutive samples, refined by a parabola through them.
+pub fn parabolic_maximum(
+    y_prev: ChaosFloat,
+    y: ChaosFloat,
+    y_next: ChaosFloat,
+) -> Option<ChaosFloat> {
+    if y > y_prev && y >= y_next {
+        let curvature = y_prev - 2.0 * y + y_next;
+        if curvature.abs() < ChaosFloat::EPSILON {
+            Some(y)
+        } else {
+            let offset = 0.5 * (y_prev - y_next) / curvature;
+            Some(y - 0.25 * (y_prev - y_next) * offset)
+        }
+    } else {
+        None
+    }
+}
+
+/// Local maxima of a sampled series, refined by a parabola through the neighbouring samples.
+pub fn local_maxima(series: &[ChaosFloat]) -> Vec<ChaosFloat> {
+    series
+        .windows(3)
+        .filter_map(|w| parabolic_maximum(w[0], w[1], w[2]))
+        .collect()
+}
+
+/// Number of distinct local maxima of a flow component, i.e. the period of a limit cycle.
+/// Returns None for more than max_period distinct values (aperiodic oscillation).
+pub fn period_from_maxima(series: &[ChaosFloat], max_period: usize) -> Option<usize> {
+    let mut maxima = local_maxima(series);
+    if maxima.is_empty() {
+        return Some(1); // equilibrium
+    }
+    // only the latest maxima describe the attractor
+    let num_maxima = maxima.len();
+    let mut maxima = maxima.split_off(num_maxima.saturating_sub(4 * max_period));
+    let (min, max) = series.iter().fold(
+        (ChaosFloat::INFINITY, ChaosFloat::NEG_INFINITY),
+        |(min, max), x| (min.min(*x), max.max(*x)),
+    );
+    let tolerance = PERIOD_TOLERANCE_CONTINUOUS * (max - min).max(ChaosFloat::EPSILON);
+    maxima.sort_by(|a, b| a.total_cmp(b));
+    let num_distinct = 1 + maxima
+        .windows(2)
+        .filter(|w| w[1] - w[0] > tolerance)
+        .count();
+    if num_distinct <= max_period {
+        Some(num_distinct)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_detect_period() {
+        let period_2: Vec<Vec<f64>> = (0..100)
+            .map(|i| vec![if i % 2 == 0 { 0.3 } else { 0.8 }])
+            .collect();
+        assert_eq!(detect_period(&period_2, MAX_PERIOD), Some(2));
+        let fixpoint: Vec<Vec<f64>> = (0..100).map(|_| vec![0.5, 0.1]).collect();
+        assert_eq!(detect_period(&fixpoint, MAX_PERIOD), Some(1));
+        let mut x = 0.1;
+        let chaotic: Vec<Vec<f64>> = (0..200)
+            .map(|_| {
+                x = 4.0 * x * (1.0 - x);
+                vec![x]
+            })
+            .collect();
+        assert_eq!(detect_period(&chaotic, MAX_PERIOD), None);
+    }
+    #[test]
+    fn test_period_from_maxima() {
+        let dt = 0.1;
+        let sine: Vec<f64> = (0..1000).map(|i| (i as f64 * dt).sin()).collect();
+        assert_eq!(period_from_maxima(&sine, MAX_PERIOD), Some(1));
+        let maxima = local_maxima(&sine);
+        assert!(maxima.iter().all(|m| (m - 1.0).abs() < 1e-3));
+        let two_peaks: Vec<f64> = (0..2000)
+            .map(|i| {
+                let t = i as f64 * dt;
+                t.sin() + 0.5 * (0.5 * t).sin()
+            })
+            .collect();
+        assert_eq!(period_from_maxima(&two_peaks, MAX_PERIOD), Some(2));
+        let constant = vec![1.0; 100];
+        assert_eq!(period_from_maxima(&constant, MAX_PERIOD), Some(1));
+    }
+}
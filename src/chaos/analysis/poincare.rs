@@ -0,0 +1,149 @@
+use crate::chaos::data::{ChaosFloat, StateIndex};
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Mul, Sub};
+use strum_macros::EnumIter;
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum CrossingDirection {
+    #[default]
+    Increasing,
+    Decreasing,
+    Both,
+}
+
+impl From<CrossingDirection> for &'static str {
+    fn from(val: CrossingDirection) -> Self {
+        match val {
+            CrossingDirection::Increasing => "Increasing",
+            CrossingDirection::Decreasing => "Decreasing",
+            CrossingDirection::Both => "Both",
+        }
+    }
+}
+
+/// States that can be linearly interpolated between two consecutive solver outputs.
+pub trait SectionState:
+    Copy + StateIndex + Add<Output = Self> + Sub<Output = Self> + Mul<ChaosFloat, Output = Self>
+{
+}
+
+impl<V> SectionState for V where
+    V: Copy + StateIndex + Add<Output = V> + Sub<Output = V> + Mul<ChaosFloat, Output = V>
+{
+}
+
+// the largest state dimension of the differential systems
+pub const MAX_SECTION_DIMS: usize = 6;
+
+/// Hyperplane n·x = offset which is crossed in the given direction of the normal n.
+#[derive(PartialEq, Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct PoincareSection {
+    // components beyond the dimension of the system must be zero
+    pub normal: [ChaosFloat; MAX_SECTION_DIMS],
+    pub offset: ChaosFloat,
+    pub direction: CrossingDirection,
+}
+
+impl Default for PoincareSection {
+    fn default() -> Self {
+        Self::axis_aligned(2, 0.0, Default::default())
+    }
+}
+
+impl PoincareSection {
+    /// Hyperplane x_axis = offset.
+    pub fn axis_aligned(axis: usize, offset: ChaosFloat, direction: CrossingDirection) -> Self {
+        let mut normal = [0.0; MAX_SECTION_DIMS];
+        normal[axis.min(MAX_SECTION_DIMS - 1)] = 1.0;
+        Self {
+            normal,
+            offset,
+            direction,
+        }
+    }
+
+    /// The axis of an axis-aligned hyperplane.
+    pub fn axis(&self) -> Option<usize> {
+        let mut nonzero = self.normal.iter().enumerate().filter(|(_, n)| **n != 0.0);
+        match (nonzero.next(), nonzero.next()) {
+            (Some((axis, n)), None) if *n == 1.0 => Some(axis),
+            _ => None,
+        }
+    }
+
+    fn signed_distance<V: StateIndex>(&self, state: &V) -> ChaosFloat {
+        self.normal
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| **n != 0.0)
+            .map(|(i, n)| n * state.ind(i))
+            .sum::<ChaosFloat>()
+            - self.offset
+    }
+
+    fn crosses(&self, distance_prev: ChaosFloat, distance_next: ChaosFloat) -> bool {
+        let increasing = distance_prev < 0.0 && distance_next >= 0.0;
+        let decreasing = distance_prev > 0.0 && distance_next <= 0.0;
+        match self.direction {
+            CrossingDirection::Increasing => increasing,
+            CrossingDirection::Decreasing => decreasing,
+            CrossingDirection::Both => increasing || decreasing,
+        }
+    }
+
+    /// Point on the section between two consecutive states, interpolated linearly.
+    pub fn crossing<V: SectionState>(&self, prev: &V, next: &V) -> Option<V> {
+        let distance_prev = self.signed_distance(prev);
+        let distance_next = self.signed_distance(next);
+        if self.crosses(distance_prev, distance_next) {
+            let fraction = distance_prev / (distance_prev - distance_next);
+            Some(*prev + (*next - *prev) * fraction)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::data::State3;
+    #[test]
+    fn test_poincare_crossing() {
+        let section = PoincareSection::axis_aligned(2, 27.0, CrossingDirection::Increasing);
+        assert_eq!(section.axis(), Some(2));
+        let prev = State3::new(1.0, 2.0, 26.0);
+        let next = State3::new(3.0, 0.0, 30.0);
+        let point = section
+            .crossing(&prev, &next)
+            .expect("z increases through 27");
+        assert!((point[0] - 1.5).abs() < 1e-12);
+        assert!((point[1] - 1.5).abs() < 1e-12);
+        assert!((point[2] - 27.0).abs() < 1e-12);
+        assert_eq!(section.crossing(&next, &prev), None);
+        let section = PoincareSection {
+            direction: CrossingDirection::Both,
+            ..section
+        };
+        assert!(section.crossing(&next, &prev).is_some());
+        assert_eq!(section.crossing(&prev, &prev), None);
+        // the diagonal plane x + y = 2 with increasing x + y
+        let mut normal = [0.0; MAX_SECTION_DIMS];
+        normal[0] = 1.0;
+        normal[1] = 1.0;
+        let diagonal = PoincareSection {
+            normal,
+            offset: 2.0,
+            direction: CrossingDirection::Increasing,
+        };
+        assert_eq!(diagonal.axis(), None);
+        let prev = State3::new(0.0, 1.0, 5.0);
+        let next = State3::new(2.0, 2.0, 7.0);
+        let point = diagonal
+            .crossing(&prev, &next)
+            .expect("x + y increases through 2");
+        assert!((point[0] + point[1] - 2.0).abs() < 1e-12);
+        assert!((point[2] - (5.0 + 2.0 / 3.0)).abs() < 1e-12);
+        assert_eq!(diagonal.crossing(&next, &prev), None);
+    }
+}
@@ -0,0 +1,178 @@
+use super::dimension::max_extent;
+use super::lyapunov::{euclidean_distance, state_vec};
+use super::trajectory::{evaluate_cells, RecordedTrajectories};
+use crate::chaos::data::*;
+
+#[derive(Clone, Copy)]
+pub struct RecurrenceConf {
+    // recurrence radius relative to the largest extent of the trajectory
+    pub threshold: ChaosFloat,
+    // shortest diagonal and vertical line that is counted
+    pub min_line: usize,
+    // only every n-th recorded state is compared
+    pub stride: usize,
+}
+
+impl Default for RecurrenceConf {
+    fn default() -> Self {
+        Self {
+            threshold: 0.1,
+            min_line: 2,
+            stride: 1,
+        }
+    }
+}
+
+/// Recurrence quantification analysis without the line of identity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecurrenceQuantification {
+    pub recurrence_rate: ChaosFloat,
+    pub determinism: ChaosFloat,
+    pub laminarity: ChaosFloat,
+    pub mean_diagonal_length: ChaosFloat,
+}
+
+/// Thresholded distance matrix of a trajectory.
+pub struct RecurrenceMatrix {
+    size: usize,
+    // row-major
+    recurrent: Vec<bool>,
+}
+
+impl RecurrenceMatrix {
+    pub fn new<V: StateIndex>(states: &[V], num_dims: usize, radius: ChaosFloat) -> Self {
+        let points: Vec<Vec<ChaosFloat>> = states
+            .iter()
+            .map(|state| state_vec(state, num_dims))
+            .collect();
+        let rows: Vec<Vec<bool>> = evaluate_cells(&points, |p| {
+            points
+                .iter()
+                .map(|q| euclidean_distance(p, q) <= radius)
+                .collect()
+        });
+        Self {
+            size: points.len(),
+            recurrent: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_recurrent(&self, i: usize, j: usize) -> bool {
+        self.recurrent[i * self.size + j]
+    }
+
+    // lengths of the runs of recurrent points in a sequence
+    fn line_lengths(points: impl Iterator<Item = bool>, lengths: &mut Vec<usize>) {
+        let mut length = 0;
+        points.chain(std::iter::once(false)).for_each(|recurrent| {
+            if recurrent {
+                length += 1;
+            } else if length > 0 {
+                lengths.push(length);
+                length = 0;
+            }
+        });
+    }
+
+    pub fn quantification(&self, min_line: usize) -> RecurrenceQuantification {
+        let n = self.size;
+        // the matrix is symmetric, diagonals of the upper triangle suffice
+        let mut diagonals = Vec::new();
+        (1..n).for_each(|k| {
+            Self::line_lengths(
+                (0..(n - k)).map(|i| self.is_recurrent(i, i + k)),
+                &mut diagonals,
+            );
+        });
+        // vertical lines are interrupted by the line of identity
+        let mut verticals = Vec::new();
+        (0..n).for_each(|j| {
+            Self::line_lengths((0..j).map(|i| self.is_recurrent(i, j)), &mut verticals);
+            Self::line_lengths(
+                ((j + 1)..n).map(|i| self.is_recurrent(i, j)),
+                &mut verticals,
+            );
+        });
+        let num_upper: usize = diagonals.iter().sum();
+        let num_recurrent: usize = verticals.iter().sum();
+        let ratio = |numerator: usize, denominator: usize| {
+            if denominator > 0 {
+                numerator as ChaosFloat / denominator as ChaosFloat
+            } else {
+                0.0
+            }
+        };
+        let long_diagonals: Vec<usize> = diagonals.into_iter().filter(|l| *l >= min_line).collect();
+        let num_in_diagonals: usize = long_diagonals.iter().sum();
+        let num_in_verticals: usize = verticals.iter().filter(|v| **v >= min_line).sum();
+        RecurrenceQuantification {
+            recurrence_rate: ratio(num_recurrent, n * n.saturating_sub(1)),
+            determinism: ratio(num_in_diagonals, num_upper),
+            laminarity: ratio(num_in_verticals, num_recurrent),
+            mean_diagonal_length: ratio(num_in_diagonals, long_diagonals.len()),
+        }
+    }
+}
+
+pub struct RecurrenceResult {
+    pub pars: (&'static str, Vec<f64>),
+    // time between two compared states
+    pub sample_time: Time,
+    // first complete trajectory of every data set
+    pub matrices: Vec<Option<RecurrenceMatrix>>,
+    pub measures: Vec<Option<RecurrenceQuantification>>,
+}
+
+pub fn recurrence_analysis(
+    recorded: RecordedTrajectories,
+    conf: &RecurrenceConf,
+) -> RecurrenceResult {
+    let stride = conf.stride.max(1);
+    let matrices: Vec<Option<RecurrenceMatrix>> = (0..recorded.trajectories.len())
+        .map(|index| {
+            let trajectory = recorded.complete_trajectories(index).into_iter().next()?;
+            let states: Vec<InitState> = trajectory.iter().step_by(stride).cloned().collect();
+            let radius = conf.threshold * max_extent(&states);
+            Some(RecurrenceMatrix::new(&states, recorded.num_dims, radius))
+        })
+        .collect();
+    let measures = matrices
+        .iter()
+        .map(|matrix| {
+            matrix
+                .as_ref()
+                .map(|matrix| matrix.quantification(conf.min_line))
+        })
+        .collect();
+    RecurrenceResult {
+        pars: recorded.pars,
+        sample_time: recorded.sample_time * stride as Time,
+        matrices,
+        measures,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_recurrence_quantification() {
+        // period two orbit recurs after every second step
+        let orbit: Vec<InitState> = (0..10).map(|i| vec![(i % 2) as ChaosFloat]).collect();
+        let rqa = RecurrenceMatrix::new(&orbit, 1, 0.1).quantification(2);
+        assert!((rqa.recurrence_rate - 40.0 / 90.0).abs() < 1e-12);
+        assert_eq!(rqa.determinism, 1.0);
+        assert_eq!(rqa.laminarity, 0.0);
+        // diagonals of length 8, 6, 4 and 2
+        assert_eq!(rqa.mean_diagonal_length, 5.0);
+        let fixpoint: Vec<InitState> = vec![vec![0.5, 0.5]; 10];
+        let rqa = RecurrenceMatrix::new(&fixpoint, 2, 0.1).quantification(2);
+        assert_eq!(rqa.recurrence_rate, 1.0);
+        // single points next to the line of identity are no vertical lines
+        assert!((rqa.laminarity - 88.0 / 90.0).abs() < 1e-12);
+    }
+}
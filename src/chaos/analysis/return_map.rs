@@ -0,0 +1,89 @@
+use super::periodicity::parabolic_maximum;
+use crate::chaos::data::{ChaosFloat, State2, StateIndex};
+
+/// Events of a single trajectory, detected inside the step loop of the solver.
+#[derive(PartialEq, Default, Clone, Debug)]
+pub struct EventHistory {
+    // value of the component one solver step before the current state
+    previous: Option<ChaosFloat>,
+    last_event: Option<ChaosFloat>,
+}
+
+impl EventHistory {
+    fn next_maximum(&mut self, value: ChaosFloat, next_value: ChaosFloat) -> Option<ChaosFloat> {
+        let maximum = self
+            .previous
+            .and_then(|previous| parabolic_maximum(previous, value, next_value));
+        self.previous = Some(value);
+        maximum
+    }
+
+    fn add_event(&mut self, event: ChaosFloat) -> Option<State2> {
+        let pair = self
+            .last_event
+            .map(|last_event| State2::new(last_event, event));
+        self.last_event = Some(event);
+        pair
+    }
+}
+
+/// Successive local maxima (or section crossings) of a state component as pairs (x_n, x_n+1).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ReturnMap {
+    pub component: usize,
+    pub from_section: bool,
+}
+
+impl ReturnMap {
+    /// Pair of successive events after one solver step from prev to next.
+    /// The crossing is the section point between both states, if any.
+    pub fn next_pair<V: StateIndex>(
+        &self,
+        history: &mut EventHistory,
+        (prev, next): (&V, &V),
+        crossing: Option<&V>,
+    ) -> Option<State2> {
+        let event = if self.from_section {
+            crossing.map(|point| point.ind(self.component))
+        } else {
+            history.next_maximum(prev.ind(self.component), next.ind(self.component))
+        }?;
+        history.add_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_next_maximum_pairs() {
+        let return_map = ReturnMap {
+            component: 0,
+            from_section: false,
+        };
+        let mut history = EventHistory::default();
+        let series: Vec<State2> = [0.0, 1.0, 0.0, 2.0, 0.0, 3.0, 0.0]
+            .iter()
+            .map(|x| State2::new(*x, 0.0))
+            .collect();
+        let pairs: Vec<State2> = series
+            .windows(2)
+            .filter_map(|w| return_map.next_pair(&mut history, (&w[0], &w[1]), None))
+            .collect();
+        assert_eq!(pairs, vec![State2::new(1.0, 2.0), State2::new(2.0, 3.0)]);
+        let return_map = ReturnMap {
+            from_section: true,
+            ..return_map
+        };
+        let mut history = EventHistory::default();
+        let crossings: Vec<State2> = [1.0, 2.0, 4.0]
+            .iter()
+            .map(|x| State2::new(*x, 0.0))
+            .filter_map(|point| return_map.next_pair(&mut history, (&point, &point), Some(&point)))
+            .collect();
+        assert_eq!(
+            crossings,
+            vec![State2::new(1.0, 2.0), State2::new(2.0, 4.0)]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use super::parameter_plane::ParameterPlaneConf;
+use super::periodicity::{detect_period, MAX_PERIOD};
+use super::trajectory::{check_dimensions, evaluate_cells, TrajectoryConf, TrajectorySchema};
+use crate::chaos::{
+    data::*,
+    execution::DiscreteMapVec,
+    functions::{Circle, SimpleDiscreteMap},
+};
+use anyhow::{bail, Error};
+use std::f64::consts::PI;
+
+pub struct RotationSample {
+    // mean advance of the lifted phase per iteration
+    pub rotation_number: ChaosFloat,
+    // p/q of a mode-locked orbit with period q
+    pub locking: Option<(i64, usize)>,
+}
+
+fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+pub fn circle_rotation(
+    circle: &Circle,
+    theta: ChaosFloat,
+    num_transient: usize,
+    num_steps: usize,
+) -> RotationSample {
+    let mut theta = theta;
+    for _ in 0..num_transient {
+        theta = circle.lift(theta).rem_euclid(1.0);
+    }
+    let start = theta;
+    let mut phases = Vec::with_capacity(num_steps);
+    let mut lifted = Vec::with_capacity(num_steps);
+    for _ in 0..num_steps {
+        theta = circle.lift(theta);
+        lifted.push(theta);
+        // points on the unit circle avoid jumps of phases close to zero
+        let angle = 2.0 * PI * theta;
+        phases.push(vec![angle.cos(), angle.sin()]);
+    }
+    let rotation_number = (theta - start) / num_steps.max(1) as ChaosFloat;
+    // a periodic phase advances by an integer number of turns within one period
+    let locking = detect_period(&phases, MAX_PERIOD).map(|q| {
+        let last = lifted.len() - 1;
+        let p = (lifted[last] - lifted[last - q]).round() as i64;
+        let divisor = greatest_common_divisor(p.unsigned_abs(), q as u64).max(1);
+        (p / divisor as i64, q / divisor as usize)
+    });
+    RotationSample {
+        rotation_number,
+        locking,
+    }
+}
+
+// the phase of samples outside of the unit interval is their fractional part
+fn circle_samples(
+    maps: &[SimpleDiscreteMap<Circle>],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_transient: usize,
+    num_steps: usize,
+) -> Result<Vec<Vec<RotationSample>>, Error> {
+    check_dimensions(init_distr, 1)?;
+    let init_data = ChaosData::<State1>::new(num_samples, init_distr);
+    Ok(evaluate_cells(maps, |map| {
+        init_data
+            .data_filtered()
+            .into_iter()
+            .map(|theta| circle_rotation(map.conf(), theta[0], num_transient, num_steps))
+            .collect()
+    }))
+}
+
+fn circle_maps(
+    discrete_map_vec: Option<DiscreteMapVec>,
+) -> Result<Vec<SimpleDiscreteMap<Circle>>, Error> {
+    match discrete_map_vec {
+        Some(DiscreteMapVec::Circle(maps)) => Ok(maps),
+        _ => bail!("Rotation numbers are only computed for the Circle map!"),
+    }
+}
+
+fn state_distributions(
+    init_distr: InitialDistributionConfig,
+) -> Result<Vec<InitialDistributionVariant>, Error> {
+    match init_distr {
+        InitialDistributionConfig::States(init_distr) => Ok(init_distr),
+        _ => bail!("Rotation numbers are only available for state distributions!"),
+    }
+}
+
+// all samples must lock on the same rational rotation number
+fn common_locking(samples: &[RotationSample]) -> Option<(i64, usize)> {
+    let locking = samples.first()?.locking?;
+    samples
+        .iter()
+        .all(|sample| sample.locking == Some(locking))
+        .then_some(locking)
+}
+
+/// Rational rotation numbers p/q of the parameter grid, cells without mode locking are None.
+pub fn circle_cells(
+    discrete_map_vec: Option<DiscreteMapVec>,
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    conf: &ParameterPlaneConf,
+) -> Result<Vec<Option<ChaosFloat>>, Error> {
+    let maps = circle_maps(discrete_map_vec)?;
+    let samples = circle_samples(
+        &maps,
+        num_samples,
+        init_distr,
+        conf.num_transient,
+        conf.num_steps,
+    )?;
+    Ok(samples
+        .iter()
+        .map(|samples| common_locking(samples).map(|(p, q)| p as ChaosFloat / q as ChaosFloat))
+        .collect())
+}
+
+pub struct RotationCurve {
+    pub pars: (&'static str, Vec<f64>),
+    // mean over the samples per circle map
+    pub rotation_numbers: Vec<Option<ChaosFloat>>,
+    // spread of the rotation numbers of the samples, zero if unique
+    pub spreads: Vec<Option<ChaosFloat>>,
+    pub lockings: Vec<Option<(i64, usize)>>,
+}
+
+pub fn rotation_curve(schema: TrajectorySchema) -> Result<RotationCurve, Error> {
+    let TrajectorySchema {
+        num_samples,
+        init_distr,
+        discrete_map_vec,
+        pars,
+        conf: TrajectoryConf {
+            num_transient,
+            num_steps,
+        },
+        ..
+    } = schema;
+    let maps = circle_maps(discrete_map_vec)?;
+    let init_distr = state_distributions(init_distr)?;
+    let samples = circle_samples(&maps, num_samples, &init_distr, num_transient, num_steps)?;
+    let rotation_numbers = samples
+        .iter()
+        .map(|samples| {
+            if samples.is_empty() {
+                None
+            } else {
+                let sum: ChaosFloat = samples.iter().map(|s| s.rotation_number).sum();
+                Some(sum / samples.len() as ChaosFloat)
+            }
+        })
+        .collect();
+    let spreads = samples
+        .iter()
+        .map(|samples| {
+            let numbers = samples.iter().map(|s| s.rotation_number);
+            let min = numbers.clone().reduce(ChaosFloat::min)?;
+            let max = numbers.reduce(ChaosFloat::max)?;
+            Some(max - min)
+        })
+        .collect();
+    let lockings = samples
+        .iter()
+        .map(|samples| common_locking(samples))
+        .collect();
+    Ok(RotationCurve {
+        pars,
+        rotation_numbers,
+        spreads,
+        lockings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_circle_rotation() {
+        // a rigid rotation keeps its irrational rotation number
+        let golden = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let rotation = circle_rotation(
+            &Circle {
+                omega: golden,
+                k: 0.0,
+            },
+            0.1,
+            0,
+            1000,
+        );
+        assert!((rotation.rotation_number - golden).abs() < 1e-9);
+        assert_eq!(rotation.locking, None);
+        // the critical circle map locks inside the 1/2 tongue
+        let rotation = circle_rotation(&Circle { omega: 0.5, k: 1.0 }, 0.1, 500, 1000);
+        assert!((rotation.rotation_number - 0.5).abs() < 1e-2);
+        assert_eq!(rotation.locking, Some((1, 2)));
+        // the lift keeps full turns of large frequencies
+        let rotation = circle_rotation(
+            &Circle {
+                omega: 2.0,
+                k: 0.5 * PI,
+            },
+            0.1,
+            500,
+            1000,
+        );
+        assert!((rotation.rotation_number - 2.0).abs() < 1e-2);
+        assert_eq!(rotation.locking, Some((2, 1)));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::chaos::data::ChaosFloat;
+
+// fits with a smaller coefficient of determination are no scaling range
+const MIN_R_SQUARED: ChaosFloat = 0.99;
+// largest relative deviation of a local slope from the fitted slope within a scaling range
+const MAX_SLOPE_DEVIATION: ChaosFloat = 0.15;
+// two-sided 95% quantiles of the t-distribution for 1 to 30 degrees of freedom
+const T_QUANTILES_95: [ChaosFloat; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+
+/// Least squares line y = slope * x + intercept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearFit {
+    pub slope: ChaosFloat,
+    pub intercept: ChaosFloat,
+    pub slope_std_error: ChaosFloat,
+    pub r_squared: ChaosFloat,
+    pub num_points: usize,
+}
+
+impl LinearFit {
+    /// Half width of the 95% confidence interval of the slope.
+    pub fn slope_confidence_95(&self) -> ChaosFloat {
+        let degrees_of_freedom = self.num_points.saturating_sub(2);
+        let t = match degrees_of_freedom {
+            0 => return ChaosFloat::INFINITY,
+            1..=30 => T_QUANTILES_95[degrees_of_freedom - 1],
+            _ => 1.96,
+        };
+        t * self.slope_std_error
+    }
+
+    pub fn value(&self, x: ChaosFloat) -> ChaosFloat {
+        self.slope * x + self.intercept
+    }
+}
+
+pub fn linear_fit(xs: &[ChaosFloat], ys: &[ChaosFloat]) -> Option<LinearFit> {
+    let num_points = xs.len().min(ys.len());
+    if num_points < 2 {
+        return None;
+    }
+    let n = num_points as ChaosFloat;
+    let x_mean = xs[..num_points].iter().sum::<ChaosFloat>() / n;
+    let y_mean = ys[..num_points].iter().sum::<ChaosFloat>() / n;
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    xs.iter().zip(ys.iter()).for_each(|(x, y)| {
+        let (dx, dy) = (x - x_mean, y - y_mean);
+        sxx += dx * dx;
+        sxy += dx * dy;
+        syy += dy * dy;
+    });
+    if sxx <= 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    let intercept = y_mean - slope * x_mean;
+    let residual = (syy - slope * sxy).max(0.0);
+    let r_squared = if syy > 0.0 { 1.0 - residual / syy } else { 1.0 };
+    let slope_std_error = if num_points > 2 {
+        (residual / (n - 2.0) / sxx).sqrt()
+    } else {
+        0.0
+    };
+    Some(LinearFit {
+        slope,
+        intercept,
+        slope_std_error,
+        r_squared,
+        num_points,
+    })
+}
+
+fn has_constant_slope(xs: &[ChaosFloat], ys: &[ChaosFloat], fit: &LinearFit) -> bool {
+    let tolerance = MAX_SLOPE_DEVIATION * fit.slope.abs();
+    xs.windows(2).zip(ys.windows(2)).all(|(x, y)| {
+        let dx = x[1] - x[0];
+        dx == 0.0 || ((y[1] - y[0]) / dx - fit.slope).abs() <= tolerance
+    })
+}
+
+/// Longest window of consecutive points with a nearly constant local slope,
+/// returns its index range and fit. Falls back to a fit over all points.
+pub fn fit_scaling_range(
+    xs: &[ChaosFloat],
+    ys: &[ChaosFloat],
+    min_points: usize,
+) -> Option<((usize, usize), LinearFit)> {
+    let num_points = xs.len().min(ys.len());
+    let min_points = min_points.max(2);
+    let mut best: Option<((usize, usize), LinearFit)> = None;
+    for len in (min_points..=num_points).rev() {
+        for start in 0..=(num_points - len) {
+            let end = start + len;
+            if let Some(fit) = linear_fit(&xs[start..end], &ys[start..end]) {
+                let is_better = best.map_or(true, |(_, best_fit)| {
+                    fit.slope_std_error < best_fit.slope_std_error
+                });
+                if fit.r_squared >= MIN_R_SQUARED
+                    && is_better
+                    && has_constant_slope(&xs[start..end], &ys[start..end], &fit)
+                {
+                    best = Some(((start, end), fit));
+                }
+            }
+        }
+        if best.is_some() {
+            break; // longest windows first
+        }
+    }
+    best.or_else(|| {
+        linear_fit(&xs[..num_points], &ys[..num_points]).map(|fit| ((0, num_points), fit))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_scaling_range() {
+        let xs: Vec<ChaosFloat> = (0..20).map(|i| i as ChaosFloat * 0.25).collect();
+        // slope 2 in the middle, saturated at both ends
+        let ys: Vec<ChaosFloat> = xs.iter().map(|x| 2.0 * x.clamp(1.0, 4.0)).collect();
+        let ((start, end), fit) = fit_scaling_range(&xs, &ys, 4).expect("enough points");
+        assert_eq!((start, end), (4, 17));
+        assert!((fit.slope - 2.0).abs() < 1e-12);
+        assert!(fit.slope_confidence_95() < 1e-6);
+        let exact = linear_fit(&[0.0, 1.0, 2.0], &[1.0, 3.0, 5.0]).expect("three points");
+        assert!((exact.intercept - 1.0).abs() < 1e-12);
+        assert!((exact.r_squared - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,218 @@
+use super::trajectory::RecordedTrajectories;
+use crate::chaos::data::{ChaosFloat, Complex, Time};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use strum_macros::EnumIter;
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum SpectralWindow {
+    Rectangular,
+    #[default]
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl From<SpectralWindow> for &'static str {
+    fn from(val: SpectralWindow) -> Self {
+        match val {
+            SpectralWindow::Rectangular => "Rectangular",
+            SpectralWindow::Hann => "Hann",
+            SpectralWindow::Hamming => "Hamming",
+            SpectralWindow::Blackman => "Blackman",
+        }
+    }
+}
+
+impl SpectralWindow {
+    fn weights(&self, num_values: usize) -> Vec<ChaosFloat> {
+        let denominator = num_values.saturating_sub(1).max(1) as ChaosFloat;
+        (0..num_values)
+            .map(|i| {
+                let phase = 2.0 * PI * i as ChaosFloat / denominator;
+                match self {
+                    SpectralWindow::Rectangular => 1.0,
+                    SpectralWindow::Hann => 0.5 - 0.5 * phase.cos(),
+                    SpectralWindow::Hamming => 0.54 - 0.46 * phase.cos(),
+                    SpectralWindow::Blackman => {
+                        0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos()
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// One-sided power spectral density without the DC component.
+#[derive(Clone, Debug, Default)]
+pub struct PowerSpectrum {
+    pub frequencies: Vec<ChaosFloat>,
+    pub densities: Vec<ChaosFloat>,
+}
+
+impl PowerSpectrum {
+    /// Frequency with the highest power.
+    pub fn dominant_frequency(&self) -> Option<ChaosFloat> {
+        self.frequencies
+            .iter()
+            .zip(self.densities.iter())
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(f, _)| *f)
+    }
+}
+
+pub struct SpectrumResult {
+    pub pars: (&'static str, Vec<f64>),
+    pub component: usize,
+    // None if every sample of the configuration escaped
+    pub spectra: Vec<Option<PowerSpectrum>>,
+}
+
+/// In-place radix-2 Cooley-Tukey FFT, the length must be a power of two.
+pub fn fft(values: &mut [Complex]) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as ChaosFloat;
+        let root = Complex::new(angle.cos(), angle.sin());
+        for chunk in values.chunks_mut(len) {
+            let mut twiddle = Complex::new(1.0, 0.0);
+            let (lower, upper) = chunk.split_at_mut(len / 2);
+            for (a, b) in lower.iter_mut().zip(upper.iter_mut()) {
+                let t = *b * twiddle;
+                *b = *a - t;
+                *a += t;
+                twiddle *= root;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+pub fn power_spectral_density(
+    series: &[ChaosFloat],
+    sample_time: Time,
+    window: SpectralWindow,
+) -> PowerSpectrum {
+    let num_values = series.len();
+    if num_values < 2 {
+        return Default::default();
+    }
+    let mean = series.iter().sum::<ChaosFloat>() / num_values as ChaosFloat;
+    let weights = window.weights(num_values);
+    let weight_power: ChaosFloat = weights.iter().map(|w| w * w).sum();
+    let num_fft = num_values.next_power_of_two(); // zero padding
+    let mut values = vec![Complex::new(0.0, 0.0); num_fft];
+    series
+        .iter()
+        .zip(weights.iter())
+        .zip(values.iter_mut())
+        .for_each(|((x, w), value)| *value = Complex::new((x - mean) * w, 0.0));
+    fft(&mut values);
+    let scale = sample_time / weight_power;
+    let frequency_step = 1.0 / (num_fft as ChaosFloat * sample_time);
+    let nyquist = num_fft / 2;
+    let (frequencies, densities) = (1..=nyquist)
+        .map(|k| {
+            // energy of negative frequencies is added except for the Nyquist bin
+            let factor = if k == nyquist { 1.0 } else { 2.0 };
+            (
+                k as ChaosFloat * frequency_step,
+                factor * scale * values[k].norm_sqr(),
+            )
+        })
+        .unzip();
+    PowerSpectrum {
+        frequencies,
+        densities,
+    }
+}
+
+/// Spectra of one state component averaged over all samples that did not escape.
+pub fn power_spectra(
+    recorded: &RecordedTrajectories,
+    component: usize,
+    window: SpectralWindow,
+) -> SpectrumResult {
+    let spectra = (0..recorded.trajectories.len())
+        .map(|index| {
+            let trajectories = recorded.complete_trajectories(index);
+            let mut spectra = trajectories.iter().map(|trajectory| {
+                let series: Vec<ChaosFloat> =
+                    trajectory.iter().map(|state| state[component]).collect();
+                power_spectral_density(&series, recorded.sample_time, window)
+            });
+            let mut averaged = spectra.next()?;
+            let mut num_spectra = 1;
+            spectra.for_each(|spectrum| {
+                averaged
+                    .densities
+                    .iter_mut()
+                    .zip(spectrum.densities)
+                    .for_each(|(sum, density)| *sum += density);
+                num_spectra += 1;
+            });
+            averaged
+                .densities
+                .iter_mut()
+                .for_each(|density| *density /= num_spectra as ChaosFloat);
+            Some(averaged)
+        })
+        .collect();
+    SpectrumResult {
+        pars: recorded.pars.clone(),
+        component,
+        spectra,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_fft_impulse_and_cosine() {
+        let mut impulse = vec![Complex::new(0.0, 0.0); 8];
+        impulse[0] = Complex::new(1.0, 0.0);
+        fft(&mut impulse);
+        assert!(impulse
+            .iter()
+            .all(|v| (*v - Complex::new(1.0, 0.0)).norm() < 1e-12));
+        let mut cosine: Vec<Complex> = (0..8)
+            .map(|i| Complex::new((2.0 * PI * i as ChaosFloat / 8.0).cos(), 0.0))
+            .collect();
+        fft(&mut cosine);
+        assert!((cosine[1].re - 4.0).abs() < 1e-12);
+        assert!((cosine[7].re - 4.0).abs() < 1e-12);
+        assert!(cosine[2].norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_sine_peak() {
+        let sample_time = 0.1;
+        let frequency = 1.25;
+        let series: Vec<ChaosFloat> = (0..1024)
+            .map(|i| (2.0 * PI * frequency * i as ChaosFloat * sample_time).sin())
+            .collect();
+        for window in [SpectralWindow::Rectangular, SpectralWindow::Hann] {
+            let spectrum = power_spectral_density(&series, sample_time, window);
+            assert_eq!(spectrum.frequencies.len(), 512);
+            let peak = spectrum.dominant_frequency().expect("non-empty spectrum");
+            assert!((peak - frequency).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use super::lyapunov::state_vec;
+use crate::chaos::{
+    data::*,
+    execution::{DiscreteMapVec, OdeSystemSolverVec},
+    fractal::FractalData,
+    functions::{DiscreteMap, OdeSolverTrait, ODE_STEP_SIZE},
+};
+use anyhow::{bail, Error};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+
+// visited states of a single sample, ends early if the sample escaped
+pub type Trajectory = Vec<Vec<ChaosFloat>>;
+
+#[derive(Clone, Copy)]
+pub struct TrajectoryConf {
+    pub num_transient: usize,
+    pub num_steps: usize,
+}
+
+impl Default for TrajectoryConf {
+    fn default() -> Self {
+        Self {
+            num_transient: 500,
+            num_steps: 2048,
+        }
+    }
+}
+
+pub struct TrajectorySchema {
+    pub num_samples: usize,
+    pub init_distr: InitialDistributionConfig,
+    pub discrete_map_vec: Option<DiscreteMapVec>,
+    pub diff_system_vec: Option<OdeSystemSolverVec>,
+    pub pars: (&'static str, Vec<f64>),
+    pub conf: TrajectoryConf,
+    // measured states that replace the chaotic function
+    pub imported: Option<ImportedSeries>,
+}
+
+pub struct RecordedTrajectories {
+    pub pars: (&'static str, Vec<f64>),
+    pub num_dims: usize,
+    // time between two recorded states
+    pub sample_time: Time,
+    // one entry per discrete map or differential system
+    pub trajectories: Vec<Vec<Trajectory>>,
+}
+
+impl RecordedTrajectories {
+    /// Trajectories that did not escape during the recording.
+    pub fn complete_trajectories(&self, index: usize) -> Vec<&Trajectory> {
+        let num_steps = self
+            .trajectories
+            .iter()
+            .flatten()
+            .map(|trajectory| trajectory.len())
+            .max()
+            .unwrap_or_default();
+        self.trajectories[index]
+            .iter()
+            .filter(|trajectory| trajectory.len() == num_steps)
+            .collect()
+    }
+}
+
+// the imported series is a single trajectory, cut like a recorded one
+fn imported_trajectories(series: &ImportedSeries, conf: &TrajectoryConf) -> RecordedTrajectories {
+    let trajectory = series
+        .states()
+        .iter()
+        .skip(conf.num_transient)
+        .take(conf.num_steps)
+        .cloned()
+        .collect();
+    RecordedTrajectories {
+        pars: Default::default(),
+        num_dims: series.num_dims(),
+        sample_time: series.sample_time(),
+        trajectories: vec![vec![trajectory]],
+    }
+}
+
+pub fn record_trajectories(schema: TrajectorySchema) -> Result<RecordedTrajectories, Error> {
+    let TrajectorySchema {
+        num_samples,
+        init_distr,
+        discrete_map_vec,
+        diff_system_vec,
+        pars,
+        conf,
+        imported,
+    } = schema;
+    if let Some(series) = imported {
+        return Ok(imported_trajectories(&series, &conf));
+    }
+    let init_distr = match init_distr {
+        InitialDistributionConfig::States(init_distr) => init_distr,
+        _ => bail!("Trajectories are only recorded for state distributions!"),
+    };
+    let (trajectories, sample_time) = if let Some(map_vec) = discrete_map_vec {
+        (map_vec.trajectories(num_samples, &init_distr, &conf)?, 1.0)
+    } else if let Some(system_vec) = diff_system_vec {
+        (
+            system_vec.trajectories(num_samples, &init_distr, &conf)?,
+            ODE_STEP_SIZE,
+        )
+    } else {
+        bail!("Cannot record trajectories without a chaotic function!")
+    };
+    Ok(RecordedTrajectories {
+        pars,
+        num_dims: init_distr.len(),
+        sample_time,
+        trajectories,
+    })
+}
+
+fn filtered_points<V: StateIndex>(data: &ChaosData<V>, num_dims: usize) -> Vec<Vec<ChaosFloat>> {
+    data.data_filtered()
+        .into_iter()
+        .map(|state| state_vec(state, num_dims))
+        .collect()
+}
+
+/// Valid states of every data set (one per parameter) as point clouds.
+pub fn ensemble_points(data: &ChaosDataVec<'_>) -> Result<Vec<Vec<Vec<ChaosFloat>>>, Error> {
+    let points = match data {
+        ChaosDataVec::State1(data_vec) => data_vec.iter().map(|d| filtered_points(*d, 1)).collect(),
+        ChaosDataVec::State2(data_vec) => data_vec.iter().map(|d| filtered_points(*d, 2)).collect(),
+        ChaosDataVec::State3(data_vec) => data_vec.iter().map(|d| filtered_points(*d, 3)).collect(),
+        ChaosDataVec::State4(data_vec) => data_vec.iter().map(|d| filtered_points(*d, 4)).collect(),
+        _ => bail!("Point clouds are only available for state data!"),
+    };
+    Ok(points)
+}
+
+fn fractal_points<E>(
+    data: &ChaosData<FractalData<E>>,
+    num_dims: usize,
+) -> (Vec<Vec<ChaosFloat>>, Vec<bool>)
+where
+    FractalData<E>: StateIndex,
+{
+    data.data_filtered()
+        .into_iter()
+        .map(|fractal| (state_vec(fractal, num_dims), fractal.last()))
+        .unzip()
+}
+
+/// Start values of every fractal data set with the samples that stayed bounded
+/// for all iterations, the others escaped or are still iterated.
+pub fn fractal_membership(
+    data: &ChaosDataVec<'_>,
+) -> Result<Vec<(Vec<Vec<ChaosFloat>>, Vec<bool>)>, Error> {
+    let membership = match data {
+        ChaosDataVec::FractalComplex(data_vec) => {
+            data_vec.iter().map(|d| fractal_points(*d, 2)).collect()
+        }
+        ChaosDataVec::FractalDual(data_vec) => {
+            data_vec.iter().map(|d| fractal_points(*d, 2)).collect()
+        }
+        ChaosDataVec::FractalPerplex(data_vec) => {
+            data_vec.iter().map(|d| fractal_points(*d, 2)).collect()
+        }
+        ChaosDataVec::FractalQuaternion(data_vec) => {
+            data_vec.iter().map(|d| fractal_points(*d, 4)).collect()
+        }
+        _ => bail!("Set membership is only available for fractal data!"),
+    };
+    Ok(membership)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) fn evaluate_cells<M: Sync, R: Send>(
+    cells: &[M],
+    evaluate: impl Fn(&M) -> R + Sync,
+) -> Vec<R> {
+    let num_threads = num_cpus::get();
+    let chunk_size = cells.len().div_ceil(num_threads).max(1);
+    let evaluate = &evaluate;
+    thread::scope(|s| {
+        let handles: Vec<_> = cells
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(evaluate).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Analysis thread panicked!"))
+            .collect()
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(super) fn evaluate_cells<M: Sync, R: Send>(
+    cells: &[M],
+    evaluate: impl Fn(&M) -> R + Sync,
+) -> Vec<R> {
+    cells.iter().map(evaluate).collect()
+}
+
+pub(super) fn check_dimensions(
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+) -> Result<(), Error> {
+    if init_distr.len() != num_dims {
+        bail!(
+            "The chaotic function needs {num_dims} dimensions but {} initial distributions are set!",
+            init_distr.len()
+        );
+    }
+    Ok(())
+}
+
+pub fn discrete_trajectories<V, D>(
+    maps: &[D],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &TrajectoryConf,
+) -> Result<Vec<Vec<Trajectory>>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    D: DiscreteMap<State = V> + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    Ok(evaluate_cells(maps, |map| {
+        init_data
+            .data_filtered()
+            .into_iter()
+            .map(|y0| {
+                let mut y = y0.clone();
+                let mut t: Time = 0.0;
+                let mut trajectory = Vec::with_capacity(conf.num_steps);
+                for step in 0..(conf.num_transient + conf.num_steps) {
+                    map.execute(&mut y, &t);
+                    t += 1.0;
+                    if !y.is_valid() {
+                        break;
+                    }
+                    if step >= conf.num_transient {
+                        trajectory.push(state_vec(&y, num_dims));
+                    }
+                }
+                trajectory
+            })
+            .collect()
+    }))
+}
+
+pub fn continuous_trajectories<V, O>(
+    solvers: &[O],
+    num_samples: usize,
+    init_distr: &[InitialDistributionVariant],
+    num_dims: usize,
+    conf: &TrajectoryConf,
+) -> Result<Vec<Vec<Trajectory>>, Error>
+where
+    V: StateIndex + FromStateVec + ValidStateCheck + Clone + Sync,
+    O: OdeSolverTrait<State = V> + Clone + Sync,
+{
+    check_dimensions(init_distr, num_dims)?;
+    let init_data = ChaosData::<V>::new(num_samples, init_distr);
+    Ok(evaluate_cells(solvers, |solver| {
+        let mut solver = solver.clone();
+        let mut states = init_data.data().clone();
+        solver.initial_states(&mut states);
+        solver.execute(&mut states, conf.num_transient);
+        let mut trajectories = vec![Vec::with_capacity(conf.num_steps); states.len()];
+        for _ in 0..conf.num_steps {
+            solver.execute(&mut states, 1);
+            states
+                .iter()
+                .zip(trajectories.iter_mut())
+                .for_each(|(state, trajectory)| {
+                    if let Some(y) = state {
+                        // escaped samples are None from here on
+                        trajectory.push(state_vec(y, num_dims));
+                    }
+                });
+        }
+        trajectories
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::functions::*;
+    #[test]
+    fn test_record_trajectories() -> Result<(), Error> {
+        let schema = TrajectorySchema {
+            num_samples: 2,
+            init_distr: InitialDistributionConfig::States(vec![InitialDistributionVariant::Fixed(
+                Fixed { value: 0.1 },
+            )]),
+            discrete_map_vec: Some(DiscreteMapVec::Logistic(vec![
+                SimpleDiscreteMap::new(Logistic { r: 2.5 }),
+                SimpleDiscreteMap::new(Logistic { r: 3.2 }),
+            ])),
+            diff_system_vec: None,
+            pars: ("r", vec![2.5, 3.2]),
+            conf: TrajectoryConf {
+                num_transient: 100,
+                num_steps: 10,
+            },
+            imported: None,
+        };
+        let recorded = record_trajectories(schema)?;
+        assert_eq!(recorded.num_dims, 1);
+        assert_eq!(recorded.trajectories.len(), 2);
+        assert_eq!(recorded.complete_trajectories(0).len(), 2);
+        let fixpoint = recorded.trajectories[0][0].last().expect("10 steps")[0];
+        assert!(
+            (fixpoint - 0.6).abs() < 1e-6,
+            "Fixpoint of r=2.5 is 1 - 1/r"
+        );
+        let schema = TrajectorySchema {
+            num_samples: 1,
+            init_distr: Default::default(),
+            discrete_map_vec: None,
+            diff_system_vec: None,
+            pars: Default::default(),
+            conf: TrajectoryConf {
+                num_transient: 1,
+                num_steps: 2,
+            },
+            imported: Some(ImportedSeries::from_csv(
+                "t,x\n0.0,1.0\n0.5,2.0\n1.0,3.0\n1.5,4.0",
+            )?),
+        };
+        let recorded = record_trajectories(schema)?;
+        assert_eq!(recorded.sample_time, 0.5);
+        assert_eq!(
+            recorded.trajectories,
+            vec![vec![vec![vec![2.0], vec![3.0]]]]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::chaos::data::ChaosFloat;
+use std::f64::consts::PI;
+
+// number of frequencies c whose median correlation gives K
+const NUM_FREQUENCIES: usize = 32;
+// shorter series do not give enough displacements for a correlation
+const MIN_SERIES_LENGTH: usize = 50;
+const VARIANCE_TOLERANCE: ChaosFloat = 1e-10;
+
+// quasi-random frequencies in (π/5, 4π/5) avoid resonances with the observable
+fn frequencies() -> impl Iterator<Item = ChaosFloat> {
+    let golden = (5.0_f64.sqrt() - 1.0) / 2.0;
+    (1..=NUM_FREQUENCIES)
+        .map(move |j| PI / 5.0 + 3.0 * PI / 5.0 * (j as ChaosFloat * golden).fract())
+}
+
+fn correlation(xs: &[ChaosFloat], ys: &[ChaosFloat]) -> ChaosFloat {
+    let n = xs.len() as ChaosFloat;
+    let mean_x = xs.iter().sum::<ChaosFloat>() / n;
+    let mean_y = ys.iter().sum::<ChaosFloat>() / n;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    xs.iter().zip(ys.iter()).for_each(|(x, y)| {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    });
+    if variance_x * variance_y > 0.0 {
+        covariance / (variance_x * variance_y).sqrt()
+    } else {
+        0.0 // a constant displacement does not grow
+    }
+}
+
+/// Gottwald-Melbourne 0-1 test of an observable, K is close to 0 for regular and 1 for chaotic dynamics.
+pub fn zero_one_test(series: &[ChaosFloat]) -> Option<ChaosFloat> {
+    let num_points = series.len();
+    if num_points < MIN_SERIES_LENGTH || series.iter().any(|x| !x.is_finite()) {
+        return None;
+    }
+    // the mean is removed, which cancels the oscillating term of the displacement
+    let mean = series.iter().sum::<ChaosFloat>() / num_points as ChaosFloat;
+    let phi: Vec<ChaosFloat> = series.iter().map(|x| x - mean).collect();
+    let variance = phi.iter().map(|x| x * x).sum::<ChaosFloat>() / num_points as ChaosFloat;
+    if variance.sqrt() <= VARIANCE_TOLERANCE * mean.abs().max(1.0) {
+        return Some(0.0); // rounding errors of a converged fixpoint are no chaos
+    }
+    let n_cut = num_points / 10;
+    let num_terms = num_points - n_cut;
+    let lags: Vec<ChaosFloat> = (1..=n_cut).map(|n| n as ChaosFloat).collect();
+    let mut correlations: Vec<ChaosFloat> = frequencies()
+        .map(|c| {
+            // translation variables of the observable
+            let (mut p, mut q) = (0.0, 0.0);
+            let (ps, qs): (Vec<ChaosFloat>, Vec<ChaosFloat>) = phi
+                .iter()
+                .enumerate()
+                .map(|(j, x)| {
+                    let angle = (j + 1) as ChaosFloat * c;
+                    p += x * angle.cos();
+                    q += x * angle.sin();
+                    (p, q)
+                })
+                .unzip();
+            let mean_square_displacements: Vec<ChaosFloat> = (1..=n_cut)
+                .map(|n| {
+                    (0..num_terms)
+                        .map(|j| (ps[j + n] - ps[j]).powi(2) + (qs[j + n] - qs[j]).powi(2))
+                        .sum::<ChaosFloat>()
+                        / num_terms as ChaosFloat
+                })
+                .collect();
+            correlation(&lags, &mean_square_displacements)
+        })
+        .collect();
+    correlations.sort_by(|a, b| a.total_cmp(b));
+    let middle = correlations.len() / 2;
+    Some((correlations[middle - 1] + correlations[middle]) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_zero_one_logistic() {
+        let logistic = |r: ChaosFloat| {
+            let mut x = 0.1234;
+            (0..1100)
+                .map(|_| {
+                    x = r * x * (1.0 - x);
+                    x
+                })
+                .skip(100)
+                .collect::<Vec<_>>()
+        };
+        let chaotic = zero_one_test(&logistic(4.0)).expect("long series");
+        assert!(chaotic > 0.9, "K is {chaotic}");
+        let periodic = zero_one_test(&logistic(3.5)).expect("long series");
+        assert!(periodic.abs() < 0.1, "K is {periodic}");
+        let fixpoint = zero_one_test(&logistic(2.5)).expect("long series");
+        assert!(fixpoint.abs() < 0.1, "K is {fixpoint}");
+        assert_eq!(zero_one_test(&[0.5; 10]), None);
+    }
+}
@@ -1,9 +1,13 @@
 mod chaos_data;
 mod chaos_states;
+mod coupling_matrix;
 mod data_variants;
+mod imported_series;
 #[allow(clippy::derivable_impls)]
 mod initial_distribution;
 pub use self::chaos_data::*;
 pub use self::chaos_states::*;
+pub use self::coupling_matrix::*;
 pub use self::data_variants::*;
+pub use self::imported_series::*;
 pub use self::initial_distribution::*;
@@ -8,6 +8,12 @@ pub struct ChaosData<V> {
     data: Vec<Option<V>>,
 }
 
+impl<V> Default for ChaosData<V> {
+    fn default() -> Self {
+        Self { data: Vec::new() }
+    }
+}
+
 impl<V> ChaosData<V> {
     pub fn total_num_points(&self) -> usize {
         self.data.len()
@@ -50,13 +50,13 @@ pub const NUM_DIMS_PARTICLEXY: usize = 8;
 pub const DIMS_INIT_PARTICLEXYZ: usize = 9;
 pub const NUM_DIMS_PARTICLEXYZ: usize = 10;
 pub const DIMS_INIT_FRACTALCOMPLEX: usize = 2;
-pub const NUM_DIMS_FRACTALCOMPLEX: usize = 5;
+pub const NUM_DIMS_FRACTALCOMPLEX: usize = 6;
 pub const DIMS_INIT_FRACTALDUAL: usize = 2;
 pub const DIMS_INIT_FRACTALPERPLEX: usize = 2;
-pub const NUM_DIMS_FRACTALDUAL: usize = 5;
-pub const NUM_DIMS_FRACTALPERPLEX: usize = 5;
+pub const NUM_DIMS_FRACTALDUAL: usize = 6;
+pub const NUM_DIMS_FRACTALPERPLEX: usize = 6;
 pub const DIMS_INIT_FRACTALQUATERNION: usize = 4;
-pub const NUM_DIMS_FRACTALQUATERNION: usize = 9;
+pub const NUM_DIMS_FRACTALQUATERNION: usize = 10;
 impl DistributionDimensions {
     pub fn number_of_dimensions(&self) -> usize {
         match self {
@@ -68,11 +68,11 @@ impl DistributionDimensions {
             }
             DistributionDimensions::Particle(n) => *n * 2 + 4, // cartesian coordinates for pos and vel + parity, mass and charge + radius
             DistributionDimensions::Fractal(d) => match d {
-                FractalDimensions::Complex => 5,
-                FractalDimensions::Dual => 5,
-                FractalDimensions::Perplex => 5,
-                FractalDimensions::Quaternion => 9,
-            }, // *n * 2 + 1 - coordinates for c and z as well as the iteration count
+                FractalDimensions::Complex => NUM_DIMS_FRACTALCOMPLEX,
+                FractalDimensions::Dual => NUM_DIMS_FRACTALDUAL,
+                FractalDimensions::Perplex => NUM_DIMS_FRACTALPERPLEX,
+                FractalDimensions::Quaternion => NUM_DIMS_FRACTALQUATERNION,
+            }, // *n * 2 + 2 - coordinates for c and z as well as the iteration count and the root
         }
     }
     pub fn num_init_dimensions(&self) -> usize {
@@ -111,6 +111,12 @@ impl ValidStateCheck for State1 {
         is_valid_number(&self[0])
     }
 }
+// recorded states of the analysis
+impl StateIndex for InitState {
+    fn ind(&self, i: usize) -> ChaosFloat {
+        self[i]
+    }
+}
 
 macro_rules! impl_state_traits_and_dims {
     ($($variant:expr),*) => {
@@ -0,0 +1,72 @@
+use super::chaos_states::ChaosFloat;
+use anyhow::{bail, Error};
+
+const DELIMITERS: [char; 3] = [',', ';', '\t'];
+
+/// Weighted adjacency of a network, row i holds the couplings from all nodes into node i.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CouplingMatrix {
+    weights: Vec<Vec<ChaosFloat>>,
+}
+
+impl CouplingMatrix {
+    /// Parses one row per line, entries are separated by commas, semicolons, tabs or spaces.
+    pub fn from_text(text: &str) -> Result<Self, Error> {
+        let mut weights = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut row = Vec::new();
+            for field in line
+                .split(|c: char| DELIMITERS.contains(&c) || c.is_whitespace())
+                .filter(|field| !field.is_empty())
+            {
+                match field.parse::<ChaosFloat>() {
+                    Ok(weight) if weight.is_finite() => row.push(weight),
+                    _ => bail!("Row {} contains the invalid weight {field}!", index + 1),
+                };
+            }
+            weights.push(row);
+        }
+        let num_nodes = weights.len();
+        if num_nodes == 0 {
+            bail!("The coupling matrix is empty!");
+        }
+        if weights.iter().any(|row| row.len() != num_nodes) {
+            bail!("The coupling matrix must be square with {num_nodes} entries per row!");
+        }
+        Ok(Self { weights })
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Coupling from node `from` into node `to`, nodes outside the matrix are uncoupled.
+    pub fn weight(&self, to: usize, from: usize) -> ChaosFloat {
+        self.weights
+            .get(to)
+            .and_then(|row| row.get(from))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_coupling_matrix_from_text() -> Result<(), Error> {
+        let matrix = CouplingMatrix::from_text("# ring\n0 1 1\n1, 0, 1\n\n1;1;0.5\n")?;
+        assert_eq!(matrix.num_nodes(), 3);
+        assert_eq!(matrix.weight(2, 2), 0.5);
+        assert_eq!(matrix.weight(0, 1), 1.0);
+        assert_eq!(matrix.weight(0, 3), 0.0);
+        assert!(CouplingMatrix::from_text("").is_err());
+        assert!(CouplingMatrix::from_text("0 1\n1").is_err());
+        assert!(CouplingMatrix::from_text("0 x\n1 0").is_err());
+        Ok(())
+    }
+}
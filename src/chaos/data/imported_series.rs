@@ -0,0 +1,178 @@
+use super::chaos_data::ChaosData;
+use super::chaos_states::*;
+use super::data_variants::ChaosDataVariant;
+use anyhow::{bail, Error};
+
+// the state variants of the chaotic functions have at most four components
+pub const MAX_IMPORTED_DIMS: usize = 4;
+// header names of the optional time column, compared in lower case
+const TIME_LABELS: [&str; 2] = ["t", "time"];
+const DELIMITERS: [char; 3] = [',', ';', '\t'];
+
+/// Measured states of a single trajectory, e.g. lab data read from comma separated values.
+#[derive(Clone, Default)]
+pub struct ImportedSeries {
+    labels: Vec<String>,
+    times: Option<Vec<Time>>,
+    states: Vec<InitState>,
+}
+
+fn split_row(line: &str, delimiter: char) -> Vec<&str> {
+    line.split(delimiter).map(str::trim).collect()
+}
+
+impl ImportedSeries {
+    /// Parses one column per state component, a header row and a time column named t or time are optional.
+    pub fn from_csv(text: &str) -> Result<Self, Error> {
+        // empty lines and comments starting with # are skipped, rows are numbered as in the file
+        let mut rows = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+        let (first_row, first_line) = match rows.next() {
+            Some(first) => first,
+            None => bail!("The imported data is empty!"),
+        };
+        let delimiter = DELIMITERS
+            .into_iter()
+            .find(|delimiter| first_line.contains(*delimiter))
+            .unwrap_or(DELIMITERS[0]);
+        let first_fields = split_row(first_line, delimiter);
+        let num_columns = first_fields.len();
+        let has_header = first_fields
+            .iter()
+            .any(|field| field.parse::<ChaosFloat>().is_err());
+        let labels: Vec<String> = if has_header {
+            first_fields.iter().map(|field| field.to_string()).collect()
+        } else {
+            (1..=num_columns).map(|i| format!("State{i}")).collect()
+        };
+        let time_column = labels
+            .iter()
+            .position(|label| TIME_LABELS.contains(&label.to_lowercase().as_str()));
+        let num_dims = num_columns - time_column.map_or(0, |_| 1);
+        if num_dims == 0 || num_dims > MAX_IMPORTED_DIMS {
+            bail!("Import between 1 and {MAX_IMPORTED_DIMS} state columns, found {num_dims}!");
+        }
+        let data_rows = (!has_header)
+            .then_some((first_row, first_line))
+            .into_iter()
+            .chain(rows);
+        let mut times = Vec::new();
+        let mut states = Vec::new();
+        for (row, line) in data_rows {
+            let fields = split_row(line, delimiter);
+            if fields.len() != num_columns {
+                bail!(
+                    "Row {row} has {} instead of {num_columns} values!",
+                    fields.len()
+                );
+            }
+            let mut state = Vec::with_capacity(num_dims);
+            for (column, field) in fields.into_iter().enumerate() {
+                let value = match field.parse::<ChaosFloat>() {
+                    Ok(value) if value.is_finite() => value,
+                    _ => bail!("Row {row} contains the invalid value {field}!"),
+                };
+                if Some(column) == time_column {
+                    times.push(value);
+                } else {
+                    state.push(value);
+                }
+            }
+            states.push(state);
+        }
+        if states.is_empty() {
+            bail!("The imported data has no states!");
+        }
+        if times.windows(2).any(|pair| pair[1] <= pair[0]) {
+            bail!("The time column must be strictly increasing!");
+        }
+        Ok(Self {
+            labels: labels
+                .into_iter()
+                .enumerate()
+                .filter_map(|(column, label)| (Some(column) != time_column).then_some(label))
+                .collect(),
+            times: time_column.map(|_| times),
+            states,
+        })
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    pub fn num_dims(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn states(&self) -> &[InitState] {
+        &self.states
+    }
+
+    /// Mean time between two states, one step per row without a time column.
+    pub fn sample_time(&self) -> Time {
+        match &self.times {
+            Some(times) if times.len() > 1 => {
+                (times[times.len() - 1] - times[0]) / (times.len() - 1) as Time
+            }
+            _ => 1.0,
+        }
+    }
+
+    fn single_state<V: FromStateVec>(state: &InitState) -> ChaosData<V> {
+        let mut data = ChaosData::default();
+        *data.data_mut() = vec![Some(V::from(state.clone()))];
+        data
+    }
+
+    /// The state at `index` as chaos data with a single sample.
+    pub fn chaos_data(&self, index: usize) -> Option<ChaosDataVariant> {
+        let state = self.states.get(index)?;
+        match state.len() {
+            1 => Some(ChaosDataVariant::State1(Self::single_state(state))),
+            2 => Some(ChaosDataVariant::State2(Self::single_state(state))),
+            3 => Some(ChaosDataVariant::State3(Self::single_state(state))),
+            4 => Some(ChaosDataVariant::State4(Self::single_state(state))),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_imported_series_from_csv() -> Result<(), Error> {
+        let csv = "# lab measurement\nTime;x;y\n0.0;1.0;2.0\n\n0.5;1.5;2.5\n1.0;2.0;3.0\n";
+        let series = ImportedSeries::from_csv(csv)?;
+        assert_eq!(series.labels(), &["x", "y"]);
+        assert_eq!(series.num_dims(), 2);
+        assert_eq!(series.num_states(), 3);
+        assert_eq!(series.states()[1], vec![1.5, 2.5]);
+        assert!((series.sample_time() - 0.5).abs() < 1e-12);
+        match series.chaos_data(2) {
+            Some(ChaosDataVariant::State2(data)) => {
+                assert_eq!(data.data(), &[Some(State2::new(2.0, 3.0))]);
+            }
+            _ => bail!("Two columns without time are 2D states!"),
+        }
+        assert!(series.chaos_data(3).is_none());
+        // without a header every column is a state component
+        let series = ImportedSeries::from_csv("0.1\n0.2\n0.3")?;
+        assert_eq!(series.labels(), &["State1"]);
+        assert_eq!(series.sample_time(), 1.0);
+        assert!(ImportedSeries::from_csv("").is_err());
+        assert!(ImportedSeries::from_csv("x,y\n1.0,2.0\n3.0").is_err());
+        assert!(ImportedSeries::from_csv("x,y\n1.0,abc").is_err());
+        assert!(ImportedSeries::from_csv("a,b,c,d,e\n1,2,3,4,5").is_err());
+        assert!(ImportedSeries::from_csv("t,x\n1.0,2.0\n0.5,3.0").is_err());
+        Ok(())
+    }
+}
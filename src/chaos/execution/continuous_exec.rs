@@ -1,7 +1,12 @@
 use crate::chaos::{
-    data::{ChaosData, FromStateVec, InitialDistributionVariant, Time, ValidStateCheck},
-    functions::OdeSolverTrait,
+    analysis::{PoincareSection, ReturnMap, SectionState},
+    data::{
+        ChaosData, ChaosFloat, FromStateVec, InitialDistributionVariant, State2, Time,
+        ValidStateCheck,
+    },
+    functions::{Hamiltonian, IntegrationScheme, Integrator, OdeSolver, OdeSolverTrait},
 };
+use ode_solvers::System;
 
 pub struct ContinuousVecExecutor<V, O>
 where
@@ -61,4 +66,63 @@ where
     pub fn get_chaos_data_refs(&self) -> Vec<&ChaosData<V>> {
         self.pairs.iter().map(|(data, _)| data).collect()
     }
+
+    pub fn set_poincare_section(&mut self, section: Option<PoincareSection>) {
+        self.pairs
+            .iter_mut()
+            .for_each(|(_, ode_solver)| ode_solver.set_poincare_section(section));
+    }
+
+    pub fn set_integration_scheme(&mut self, scheme: IntegrationScheme) {
+        self.pairs
+            .iter_mut()
+            .for_each(|(_, ode_solver)| ode_solver.set_integration_scheme(scheme));
+    }
+
+    pub fn get_section_data_refs(&self) -> Option<Vec<&ChaosData<V>>> {
+        self.pairs
+            .iter()
+            .map(|(_, ode_solver)| ode_solver.section_data())
+            .collect()
+    }
+
+    pub fn set_return_map(&mut self, return_map: Option<ReturnMap>) {
+        self.pairs
+            .iter_mut()
+            .for_each(|(_, ode_solver)| ode_solver.set_return_map(return_map));
+    }
+
+    pub fn get_return_map_data_refs(&self) -> Option<Vec<&ChaosData<State2>>> {
+        self.pairs
+            .iter()
+            .map(|(_, ode_solver)| ode_solver.return_map_data())
+            .collect()
+    }
+}
+
+impl<V, T> ContinuousVecExecutor<V, OdeSolver<V, T>>
+where
+    V: FromStateVec + ValidStateCheck + SectionState,
+    T: Clone + System<Time, V> + Integrator<Input = V, Output = V> + Hamiltonian<State = V>,
+{
+    pub fn energies(&self) -> Vec<ChaosFloat> {
+        self.pairs
+            .iter()
+            .flat_map(|(data, ode_solver)| {
+                data.data_filtered()
+                    .into_iter()
+                    .map(|state| ode_solver.system().energy(state))
+            })
+            .collect()
+    }
+
+    /// Moves all states onto the given energy surface and restarts their integration.
+    pub fn set_energy(&mut self, energy: ChaosFloat) {
+        self.pairs.iter_mut().for_each(|(data, ode_solver)| {
+            data.data_mut().iter_mut().for_each(|state| {
+                *state = state.and_then(|v| ode_solver.system().with_energy(&v, energy));
+            });
+            ode_solver.initial_states(data.data_mut());
+        });
+    }
 }
@@ -1,9 +1,11 @@
 use anyhow::{bail, Error};
 
 use crate::chaos::{
+    analysis::{PoincareSection, ReturnMap},
     data::*,
     execution::executor_variants::*,
     execution::{continuous_exec::ContinuousVecExecutor, discrete_exec::DiscreteVecExecutor},
+    functions::IntegrationScheme,
 };
 
 macro_rules! try_init_from_chaos_data {
@@ -39,9 +41,17 @@ macro_rules! try_init_from_chaos_data {
                         KaplanYorke,
                         Rulkov,
                         Zaslavskii,
-                        ReverseProbability
+                        ReverseProbability,
+                        Lozi,
+                        PeterDeJong,
+                        Hopalong,
+                        GumowskiMira,
+                        Bedhead,
+                        Svensson,
+                        DiscreteLorenz,
+                        DelayedLogistic
                     ],
-                    [Brusselator, VanDerPol, QuadrupTwoOrbit]
+                    [Brusselator, VanDerPol, QuadrupTwoOrbit, Kuramoto]
                 );
             }
             $data_variant::State3(initial_chaos_data) => {
@@ -49,7 +59,15 @@ macro_rules! try_init_from_chaos_data {
                     $self,
                     $constructor,
                     initial_chaos_data,
-                    [Shah, Memristive],
+                    [
+                        Shah,
+                        Memristive,
+                        BarnsleyFern,
+                        SierpinskiTriangle,
+                        SierpinskiCarpet,
+                        HeighwayDragon,
+                        AffineIfs
+                    ],
                     [
                         Lorenz,
                         Rossler,
@@ -62,7 +80,34 @@ macro_rules! try_init_from_chaos_data {
                         Halvorsen,
                         ThreeSpeciesLotkaVolterra,
                         Rikitake,
-                        HindmarshRose
+                        HindmarshRose,
+                        Thomas,
+                        Dadras,
+                        Lu,
+                        SprottA,
+                        SprottB,
+                        SprottC,
+                        SprottD,
+                        SprottE,
+                        SprottF,
+                        SprottG,
+                        SprottH,
+                        SprottI,
+                        SprottJ,
+                        SprottK,
+                        SprottL,
+                        SprottM,
+                        SprottN,
+                        SprottO,
+                        SprottP,
+                        SprottQ,
+                        SprottR,
+                        SprottS,
+                        NoseHoover,
+                        Lorenz84,
+                        Rucklidge,
+                        Arneodo,
+                        FourWing
                     ]
                 );
             }
@@ -72,7 +117,13 @@ macro_rules! try_init_from_chaos_data {
                     $constructor,
                     initial_chaos_data,
                     [Sfsimm],
-                    [Ababneh, WeiWang]
+                    [
+                        HenonHeiles,
+                        DoublePendulum,
+                        RestrictedThreeBody,
+                        Ababneh,
+                        WeiWang
+                    ]
                 );
             }
             $data_variant::ParticleXY(initial_chaos_data) => {
@@ -92,15 +143,25 @@ macro_rules! try_init_from_chaos_data {
                         MandelbrotSinusComplex,
                         MandelbrotSinhComplex,
                         MandelbrotZubietaComplex,
+                        MandelbrotBurningShipComplex,
+                        MandelbrotTricornComplex,
+                        MandelbrotCelticComplex,
+                        MandelbrotPhoenixComplex,
                         MandelbrotPicardComplex,
                         MandelbrotBiomorphComplex,
+                        MandelbrotNewtonComplex,
                         JuliaPowerComplex,
                         JuliaTranscendentalComplex,
                         JuliaSinusComplex,
                         JuliaSinhComplex,
                         JuliaZubietaComplex,
+                        JuliaBurningShipComplex,
+                        JuliaTricornComplex,
+                        JuliaCelticComplex,
+                        JuliaPhoenixComplex,
                         JuliaPicardComplex,
-                        JuliaBiomorphComplex
+                        JuliaBiomorphComplex,
+                        JuliaNewtonComplex
                     ],
                     []
                 );
@@ -116,15 +177,25 @@ macro_rules! try_init_from_chaos_data {
                         MandelbrotSinusDual,
                         MandelbrotSinhDual,
                         MandelbrotZubietaDual,
+                        MandelbrotBurningShipDual,
+                        MandelbrotTricornDual,
+                        MandelbrotCelticDual,
+                        MandelbrotPhoenixDual,
                         MandelbrotPicardDual,
                         MandelbrotBiomorphDual,
+                        MandelbrotNewtonDual,
                         JuliaPowerDual,
                         JuliaTranscendentalDual,
                         JuliaSinusDual,
                         JuliaSinhDual,
                         JuliaZubietaDual,
+                        JuliaBurningShipDual,
+                        JuliaTricornDual,
+                        JuliaCelticDual,
+                        JuliaPhoenixDual,
                         JuliaPicardDual,
-                        JuliaBiomorphDual
+                        JuliaBiomorphDual,
+                        JuliaNewtonDual
                     ],
                     []
                 );
@@ -140,15 +211,25 @@ macro_rules! try_init_from_chaos_data {
                         MandelbrotSinusPerplex,
                         MandelbrotSinhPerplex,
                         MandelbrotZubietaPerplex,
+                        MandelbrotBurningShipPerplex,
+                        MandelbrotTricornPerplex,
+                        MandelbrotCelticPerplex,
+                        MandelbrotPhoenixPerplex,
                         MandelbrotPicardPerplex,
                         MandelbrotBiomorphPerplex,
+                        MandelbrotNewtonPerplex,
                         JuliaPowerPerplex,
                         JuliaTranscendentalPerplex,
                         JuliaSinusPerplex,
                         JuliaSinhPerplex,
                         JuliaZubietaPerplex,
+                        JuliaBurningShipPerplex,
+                        JuliaTricornPerplex,
+                        JuliaCelticPerplex,
+                        JuliaPhoenixPerplex,
                         JuliaPicardPerplex,
-                        JuliaBiomorphPerplex
+                        JuliaBiomorphPerplex,
+                        JuliaNewtonPerplex
                     ],
                     []
                 );
@@ -164,15 +245,25 @@ macro_rules! try_init_from_chaos_data {
                         MandelbrotSinusQuaternion,
                         MandelbrotSinhQuaternion,
                         MandelbrotZubietaQuaternion,
+                        MandelbrotBurningShipQuaternion,
+                        MandelbrotTricornQuaternion,
+                        MandelbrotCelticQuaternion,
+                        MandelbrotPhoenixQuaternion,
                         MandelbrotPicardQuaternion,
                         MandelbrotBiomorphQuaternion,
+                        MandelbrotNewtonQuaternion,
                         JuliaPowerQuaternion,
                         JuliaTranscendentalQuaternion,
                         JuliaSinusQuaternion,
                         JuliaSinhQuaternion,
                         JuliaZubietaQuaternion,
+                        JuliaBurningShipQuaternion,
+                        JuliaTricornQuaternion,
+                        JuliaCelticQuaternion,
+                        JuliaPhoenixQuaternion,
                         JuliaPicardQuaternion,
-                        JuliaBiomorphQuaternion
+                        JuliaBiomorphQuaternion,
+                        JuliaNewtonQuaternion
                     ],
                     []
                 );
@@ -198,12 +289,38 @@ macro_rules! map_executor_variant {
         }
     };
 }
+// read-only source that replays imported states, one state per execution
+struct ImportedSource {
+    series: ImportedSeries,
+    index: usize,
+    data: ChaosDataVariant,
+}
+
+impl ImportedSource {
+    fn replay(&mut self, num_executions: usize) -> Result<(), Error> {
+        let last_index = self.series.num_states() - 1;
+        if self.index == last_index {
+            bail!("All imported states have been replayed.");
+        }
+        let index = (self.index + num_executions).min(last_index);
+        if let Some(data) = self.series.chaos_data(index) {
+            self.index = index;
+            self.data = data;
+        }
+        Ok(())
+    }
+}
+
 pub struct ChaosExecutionController {
     initial_chaos_data: Option<ChaosDataVariant>,
+    imported: Option<ImportedSource>,
     discrete_map_vec: Option<DiscreteMapVec>,
     ode_solver_vec: Option<OdeSystemSolverVec>,
     executor: Option<ExecutorVariant>,
     initial_distributions: InitialDistributionConfig,
+    poincare_section: Option<PoincareSection>,
+    return_map: Option<ReturnMap>,
+    integration_scheme: IntegrationScheme,
     time: Time,
 }
 
@@ -211,10 +328,14 @@ impl Default for ChaosExecutionController {
     fn default() -> Self {
         Self {
             initial_chaos_data: None,
+            imported: None,
             discrete_map_vec: None,
             ode_solver_vec: None,
             executor: None,
             initial_distributions: Default::default(),
+            poincare_section: None,
+            return_map: None,
+            integration_scheme: Default::default(),
             time: 0.0,
         }
     }
@@ -248,6 +369,11 @@ impl ChaosExecutionController {
                 bail!("Dimension mismatch between data and discrete map / ode-system. Removing the chaotic function!");
             }
         }
+        if let Some(executor) = &mut self.executor {
+            executor.set_poincare_section(self.poincare_section);
+            executor.set_return_map(self.return_map);
+            executor.set_integration_scheme(self.integration_scheme);
+        }
         Ok(())
     }
     pub fn generate_initial_chaos_data(
@@ -255,6 +381,7 @@ impl ChaosExecutionController {
         num_samples: usize,
         init_distr: InitialDistributionConfig,
     ) -> Result<(), Error> {
+        self.imported = None;
         self.initial_chaos_data = Some(ChaosDataVariant::generate_initial_chaos_data(
             num_samples,
             &init_distr,
@@ -264,11 +391,40 @@ impl ChaosExecutionController {
         Ok(())
     }
 
+    pub fn import_series(&mut self, series: ImportedSeries) -> Result<(), Error> {
+        let data = match series.chaos_data(0) {
+            Some(data) => data,
+            None => bail!("The imported series has no states!"),
+        };
+        self.initial_chaos_data = None;
+        self.discrete_map_vec = None;
+        self.ode_solver_vec = None;
+        self.executor = None;
+        self.time = 0.0;
+        self.imported = Some(ImportedSource {
+            series,
+            index: 0,
+            data,
+        });
+        Ok(())
+    }
+
+    fn check_not_imported(&self) -> Result<(), Error> {
+        if self.imported.is_some() {
+            bail!("Imported data is read-only: Generate initial data to run a chaotic function!");
+        }
+        Ok(())
+    }
+
     pub fn dimensionality(&self) -> DistributionDimensions {
-        self.initial_distributions.dimensionality()
+        match &self.imported {
+            Some(imported) => DistributionDimensions::State(imported.series.num_dims()),
+            None => self.initial_distributions.dimensionality(),
+        }
     }
 
     pub fn set_discrete_mappers(&mut self, maps: DiscreteMapVec) -> Result<(), Error> {
+        self.check_not_imported()?;
         self.discrete_map_vec = Some(maps);
         self.ode_solver_vec = None;
         self.try_init_executor()?;
@@ -279,6 +435,7 @@ impl ChaosExecutionController {
         &mut self,
         diff_solvers: OdeSystemSolverVec,
     ) -> Result<(), Error> {
+        self.check_not_imported()?;
         self.discrete_map_vec = None;
         self.ode_solver_vec = Some(diff_solvers);
         self.try_init_executor()?;
@@ -290,16 +447,81 @@ impl ChaosExecutionController {
             Ok(executor.get_chaos_data_vec())
         } else if let Some(chaos_data_variant) = &self.initial_chaos_data {
             Ok(ChaosDataVec::from_chaos_data(chaos_data_variant))
+        } else if let Some(imported) = &self.imported {
+            Ok(ChaosDataVec::from_chaos_data(&imported.data))
         } else {
             bail!("No data available for plotting: Executor and initial data are not set.")
         }
     }
 
+    pub fn set_poincare_section(&mut self, section: Option<PoincareSection>) {
+        if self.poincare_section != section {
+            self.poincare_section = section;
+            if let Some(executor) = &mut self.executor {
+                executor.set_poincare_section(section);
+            }
+        }
+    }
+
+    pub fn set_return_map(&mut self, return_map: Option<ReturnMap>) {
+        if self.return_map != return_map {
+            self.return_map = return_map;
+            if let Some(executor) = &mut self.executor {
+                executor.set_return_map(return_map);
+            }
+        }
+    }
+
+    pub fn set_integration_scheme(&mut self, scheme: IntegrationScheme) {
+        if self.integration_scheme != scheme {
+            self.integration_scheme = scheme;
+            if let Some(executor) = &mut self.executor {
+                executor.set_integration_scheme(scheme);
+            }
+        }
+    }
+
+    pub fn energies(&self) -> Result<(&'static str, Vec<ChaosFloat>), Error> {
+        match &self.executor {
+            Some(executor) => executor.energies(),
+            None => bail!("Executor is not set: No energies available."),
+        }
+    }
+
+    pub fn set_energy(&mut self, energy: ChaosFloat) -> Result<(), Error> {
+        match &mut self.executor {
+            Some(executor) => executor.set_energy(energy),
+            None => bail!("Executor is not set: Cannot fix the energy."),
+        }
+    }
+
+    pub fn get_section_data(&self) -> Result<ChaosDataVec<'_>, Error> {
+        match &self.executor {
+            Some(executor) => match executor.get_section_data_vec() {
+                Some(section_data) => Ok(section_data),
+                None => bail!("No Poincaré section is set for the differential system."),
+            },
+            None => bail!("Executor is not set: No Poincaré section available."),
+        }
+    }
+
+    pub fn get_return_map_data(&self) -> Result<ChaosDataVec<'_>, Error> {
+        match &self.executor {
+            Some(executor) => match executor.get_return_map_data_vec() {
+                Some(return_map_data) => Ok(return_map_data),
+                None => bail!("Return maps are only available for differential systems."),
+            },
+            None => bail!("Executor is not set: No return map available."),
+        }
+    }
+
     pub fn execute(&mut self, num_executions: usize) -> Result<(), Error> {
         if let Some(executor_variant) = &mut self.executor {
             executor_variant.execute_vec(num_executions, &self.time);
             self.time += num_executions as Time;
             Ok(())
+        } else if let Some(imported) = &mut self.imported {
+            imported.replay(num_executions)
         } else {
             bail!("Executor is not set: Cannot execute chaotic functions.");
         }
@@ -332,7 +554,7 @@ impl ChaosExecutionController {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::chaos::functions::*;
+    use crate::chaos::{analysis::CrossingDirection, functions::*};
     #[test]
     fn test_controller_init() -> Result<(), Error> {
         let num_samples = 2;
@@ -546,4 +768,179 @@ mod tests {
         controller.execute(1)?;
         Ok(())
     }
+
+    #[test]
+    fn test_controller_imported_series() -> Result<(), Error> {
+        let series = ImportedSeries::from_csv("t,x,y\n0.0,1.0,2.0\n0.1,3.0,4.0\n0.2,5.0,6.0")?;
+        let mut controller = ChaosExecutionController::default();
+        controller.import_series(series)?;
+        assert_eq!(controller.dimensionality(), DIMS_STATE2);
+        let map = SimpleDiscreteMap::new(Henon::default());
+        assert!(
+            controller
+                .set_discrete_mappers(DiscreteMapVec::Henon(vec![map]))
+                .is_err(),
+            "Imported data is read-only!"
+        );
+        controller.execute(1)?;
+        controller.execute(5)?;
+        if let ChaosDataVec::State2(chaos_data_vec) = controller.get_chaos_data()? {
+            assert_eq!(chaos_data_vec[0].data(), &[Some(State2::new(5.0, 6.0))]);
+        } else {
+            bail!("Two imported components are 2D states!");
+        }
+        assert!(
+            controller.execute(1).is_err(),
+            "The replay stops at the last state!"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_controller_poincare_section() -> Result<(), Error> {
+        let init_distr = vec![
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+        ];
+        let mut controller = ChaosExecutionController::default();
+        controller.generate_initial_chaos_data(2, InitialDistributionConfig::States(init_distr))?;
+        let system = OdeSystemSolverVec::Lorenz(vec![OdeSolver::new(Lorenz::default())]);
+        controller.set_differential_solvers(system)?;
+        assert!(
+            controller.get_section_data().is_err(),
+            "No section data without a Poincaré section!"
+        );
+        let section = PoincareSection::axis_aligned(2, 27.0, CrossingDirection::Increasing);
+        controller.set_poincare_section(Some(section));
+        let mut num_crossings = 0;
+        for _ in 0..500 {
+            controller.execute(1)?;
+            if let ChaosDataVec::State3(section_data) = controller.get_section_data()? {
+                section_data[0].data_filtered().iter().for_each(|point| {
+                    assert!((point[2] - 27.0).abs() < 1e-9, "Points lie on the section!");
+                    num_crossings += 1;
+                });
+            } else {
+                bail!("Section data must have the dimensionality of the system!");
+            }
+        }
+        assert!(num_crossings > 0, "The Lorenz attractor crosses z = 27!");
+        controller.set_poincare_section(None);
+        assert!(controller.get_section_data().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_controller_poincare_section_executions() -> Result<(), Error> {
+        let init_distr = vec![
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+        ];
+        let (num_samples, num_frames, num_executions) = (2, 25, 20);
+        let mut controller = ChaosExecutionController::default();
+        controller.generate_initial_chaos_data(
+            num_samples,
+            InitialDistributionConfig::States(init_distr),
+        )?;
+        let system = OdeSystemSolverVec::Lorenz(vec![OdeSolver::new(Lorenz::default())]);
+        controller.set_differential_solvers(system)?;
+        controller.set_poincare_section(Some(PoincareSection::axis_aligned(
+            2,
+            27.0,
+            CrossingDirection::Increasing,
+        )));
+        let mut num_crossings = 0;
+        for _ in 0..num_frames {
+            controller.execute(num_executions)?;
+            if let ChaosDataVec::State3(section_data) = controller.get_section_data()? {
+                num_crossings += section_data[0].data_filtered().len();
+            } else {
+                bail!("Section data must have the dimensionality of the system!");
+            }
+        }
+        // a loop around a wing of the attractor takes less than 20 solver steps
+        assert!(
+            num_crossings > num_samples * num_frames,
+            "All crossings of an execution are kept, found {num_crossings}!"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_controller_return_map() -> Result<(), Error> {
+        let init_distr = vec![
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 1.0 }),
+        ];
+        let (num_frames, num_executions) = (25, 20);
+        let mut controller = ChaosExecutionController::default();
+        controller.generate_initial_chaos_data(1, InitialDistributionConfig::States(init_distr))?;
+        let system = OdeSystemSolverVec::Lorenz(vec![OdeSolver::new(Lorenz::default())]);
+        controller.set_differential_solvers(system)?;
+        assert!(controller.get_return_map_data().is_err());
+        controller.set_return_map(Some(ReturnMap {
+            component: 2,
+            from_section: false,
+        }));
+        let mut num_pairs = 0;
+        for _ in 0..num_frames {
+            controller.execute(num_executions)?;
+            if let ChaosDataVec::State2(pairs) = controller.get_return_map_data()? {
+                pairs[0].data_filtered().iter().for_each(|pair| {
+                    assert!(
+                        pair[0] > 0.0 && pair[1] > 0.0,
+                        "z of Lorenz stays positive!"
+                    );
+                    num_pairs += 1;
+                });
+            } else {
+                bail!("Return maps consist of pairs!");
+            }
+        }
+        // maxima between two frames are detected as well
+        assert!(
+            num_pairs > num_frames,
+            "All maxima of an execution are kept, found {num_pairs}!"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_controller_energy() -> Result<(), Error> {
+        let init_distr = vec![
+            InitialDistributionVariant::Fixed(Fixed { value: 0.0 }),
+            InitialDistributionVariant::Linspace(Linspace {
+                low: -0.1,
+                high: 0.1,
+            }),
+            InitialDistributionVariant::Fixed(Fixed { value: 0.2 }),
+            InitialDistributionVariant::Fixed(Fixed { value: 0.1 }),
+        ];
+        let mut controller = ChaosExecutionController::default();
+        controller.generate_initial_chaos_data(3, InitialDistributionConfig::States(init_distr))?;
+        assert!(controller.energies().is_err(), "No executor yet!");
+        let system = OdeSystemSolverVec::HenonHeiles(vec![OdeSolver::new(HenonHeiles::default())]);
+        controller.set_differential_solvers(system)?;
+        controller.set_energy(0.125)?;
+        controller.set_integration_scheme(IntegrationScheme::GaussLegendre);
+        for _ in 0..100 {
+            controller.execute(1)?;
+        }
+        let (label, energies) = controller.energies()?;
+        assert_eq!(label, "Energy E");
+        assert_eq!(energies.len(), 3);
+        energies.iter().for_each(|energy| {
+            assert!(
+                (energy - 0.125).abs() < 1e-9,
+                "The symplectic scheme conserves the fixed energy!"
+            );
+        });
+        // no real momentum above the energy
+        assert!(controller.set_energy(-1.0).is_ok());
+        assert!(controller.energies()?.1.is_empty());
+        Ok(())
+    }
 }
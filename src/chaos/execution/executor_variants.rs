@@ -1,8 +1,17 @@
+use anyhow::{bail, Error};
 use delegate::delegate;
 use paste::paste;
 use strum_macros::IntoStaticStr;
 
 use crate::chaos::{
+    analysis::{
+        continuous_basins, continuous_cells, continuous_densities, continuous_networks,
+        continuous_trajectories, discrete_basins, discrete_cells, discrete_densities,
+        discrete_lattices, discrete_trajectories, lap_entropies, ogy_control, pyragas_control,
+        Basins, ControlConf, ControlledEvolution, DensityConf, Histogram, LatticeConf,
+        LatticeEvolution, NetworkConf, NetworkEvolution, ParameterPlaneConf, PoincareSection,
+        ReturnMap, Trajectory, TrajectoryConf,
+    },
     data::*,
     execution::{continuous_exec::ContinuousVecExecutor, discrete_exec::DiscreteVecExecutor},
     fractal::*,
@@ -11,7 +20,7 @@ use crate::chaos::{
 };
 
 macro_rules! create_and_implement_executor_variants {
-    ([$( $discrete_map:ident $discrete_state:expr),*] [$( $fractal_fn:ident),*] [$( $continuous_ode:ident $continuous_state:expr),*] [$( $particle_dim:ident),*]) => {
+    ([$( $discrete_map:ident $discrete_state:expr),*] [$( $fractal_fn:ident),*] [$( $continuous_ode:ident $continuous_state:expr),*] [$( $particle_dim:ident),*] [$( $network:ident $network_state:expr),*]) => {
         paste!{
             pub enum ExecutorVariant {
                 $(
@@ -33,6 +42,9 @@ macro_rules! create_and_implement_executor_variants {
                 $(
                     [<Particle $particle_dim>](ContinuousVecExecutor<[<Particle $particle_dim>], [<Particle $particle_dim SystemSolver>]>),
                 )*
+                $(
+                    $network(ContinuousVecExecutor<[<State $network_state>], [<$network Solver>]>),
+                )*
             }
             impl ExecutorVariant {
                 pub fn get_chaos_data_vec(&self) -> ChaosDataVec<'_> {
@@ -57,6 +69,54 @@ macro_rules! create_and_implement_executor_variants {
                         $(
                             [<Particle $particle_dim>](ex) => ChaosDataVec::[<Particle $particle_dim>](ex.get_chaos_data_refs()),
                         )*
+                        $(
+                            $network(ex) => ChaosDataVec::[<State $network_state>](ex.get_chaos_data_refs()),
+                        )*
+                    }
+                }
+                pub fn get_section_data_vec(&self) -> Option<ChaosDataVec<'_>> {
+                    use ExecutorVariant::*;
+                    match self {
+                        $(
+                            $continuous_ode(ex) => ex.get_section_data_refs().map(ChaosDataVec::[<State $continuous_state>]),
+                        )*
+                        _ => None,
+                    }
+                }
+                pub fn set_poincare_section(&mut self, section: Option<PoincareSection>) {
+                    use ExecutorVariant::*;
+                    match self {
+                        $(
+                            $continuous_ode(ex) => ex.set_poincare_section(section),
+                        )*
+                        _ => (),
+                    }
+                }
+                pub fn get_return_map_data_vec(&self) -> Option<ChaosDataVec<'_>> {
+                    use ExecutorVariant::*;
+                    match self {
+                        $(
+                            $continuous_ode(ex) => ex.get_return_map_data_refs().map(ChaosDataVec::State2),
+                        )*
+                        _ => None,
+                    }
+                }
+                pub fn set_return_map(&mut self, return_map: Option<ReturnMap>) {
+                    use ExecutorVariant::*;
+                    match self {
+                        $(
+                            $continuous_ode(ex) => ex.set_return_map(return_map),
+                        )*
+                        _ => (),
+                    }
+                }
+                pub fn set_integration_scheme(&mut self, scheme: IntegrationScheme) {
+                    use ExecutorVariant::*;
+                    match self {
+                        $(
+                            $continuous_ode(ex) => ex.set_integration_scheme(scheme),
+                        )*
+                        _ => (),
                     }
                 }
                 delegate! {
@@ -80,6 +140,9 @@ macro_rules! create_and_implement_executor_variants {
                         $(
                             ExecutorVariant::[<Particle $particle_dim>](ex) => ex,
                         )*
+                        $(
+                            ExecutorVariant::$network(ex) => ex,
+                        )*
                     }
                     {
                         pub fn execute_vec(&mut self, num_executions: usize, time: &Time);
@@ -113,6 +176,169 @@ macro_rules! create_and_implement_executor_variants {
                 $(
                     [<Particle $particle_dim>](Vec<[<Particle $particle_dim SystemSolver>]>),
                 )*
+                $(
+                    $network(Vec<[<$network Solver>]>),
+                )*
+            }
+
+            impl DiscreteMapVec {
+                pub fn parameter_plane_cells(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &ParameterPlaneConf,
+                ) -> Result<Vec<Option<ChaosFloat>>, Error> {
+                    match self {
+                        $(
+                            DiscreteMapVec::$discrete_map(maps) => discrete_cells::<[<State $discrete_state>], _>(maps, num_samples, init_distr, $discrete_state, conf),
+                        )*
+                        _ => bail!("Parameter planes are not available for fractals!"),
+                    }
+                }
+
+                pub fn trajectories(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &TrajectoryConf,
+                ) -> Result<Vec<Vec<Trajectory>>, Error> {
+                    match self {
+                        $(
+                            DiscreteMapVec::$discrete_map(maps) => discrete_trajectories::<[<State $discrete_state>], _>(maps, num_samples, init_distr, $discrete_state, conf),
+                        )*
+                        _ => bail!("Trajectories are not recorded for fractals!"),
+                    }
+                }
+
+                pub fn basins(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &TrajectoryConf,
+                    num_cells: usize,
+                ) -> Result<Vec<Basins>, Error> {
+                    match self {
+                        $(
+                            DiscreteMapVec::$discrete_map(maps) => discrete_basins::<[<State $discrete_state>], _>(maps, num_samples, init_distr, $discrete_state, conf, num_cells),
+                        )*
+                        _ => bail!("Basins of attraction are not available for fractals!"),
+                    }
+                }
+
+                pub fn densities(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &TrajectoryConf,
+                    density_conf: &DensityConf,
+                ) -> Result<Vec<Histogram>, Error> {
+                    match self {
+                        $(
+                            DiscreteMapVec::$discrete_map(maps) => discrete_densities::<[<State $discrete_state>], _>(maps, num_samples, init_distr, $discrete_state, conf, density_conf),
+                        )*
+                        _ => bail!("Invariant densities are not available for fractals!"),
+                    }
+                }
+            }
+
+            impl OdeSystemSolverVec {
+                pub fn parameter_plane_cells(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &ParameterPlaneConf,
+                ) -> Result<Vec<Option<ChaosFloat>>, Error> {
+                    match self {
+                        $(
+                            OdeSystemSolverVec::$continuous_ode(solvers) => continuous_cells::<[<State $continuous_state>], _>(solvers, num_samples, init_distr, $continuous_state, conf),
+                        )*
+                        _ => bail!("Parameter planes are not available for particles and oscillator networks!"),
+                    }
+                }
+
+                pub fn trajectories(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &TrajectoryConf,
+                ) -> Result<Vec<Vec<Trajectory>>, Error> {
+                    match self {
+                        $(
+                            OdeSystemSolverVec::$continuous_ode(solvers) => continuous_trajectories::<[<State $continuous_state>], _>(solvers, num_samples, init_distr, $continuous_state, conf),
+                        )*
+                        $(
+                            OdeSystemSolverVec::$network(solvers) => continuous_trajectories::<[<State $network_state>], _>(solvers, num_samples, init_distr, $network_state, conf),
+                        )*
+                        _ => bail!("Trajectories are not recorded for particles!"),
+                    }
+                }
+
+                pub fn basins(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &TrajectoryConf,
+                    num_cells: usize,
+                ) -> Result<Vec<Basins>, Error> {
+                    match self {
+                        $(
+                            OdeSystemSolverVec::$continuous_ode(solvers) => continuous_basins::<[<State $continuous_state>], _>(solvers, num_samples, init_distr, $continuous_state, conf, num_cells),
+                        )*
+                        _ => bail!("Basins of attraction are not available for particles and oscillator networks!"),
+                    }
+                }
+
+                pub fn densities(
+                    &self,
+                    num_samples: usize,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &TrajectoryConf,
+                    density_conf: &DensityConf,
+                ) -> Result<Vec<Histogram>, Error> {
+                    match self {
+                        $(
+                            OdeSystemSolverVec::$continuous_ode(solvers) => continuous_densities::<[<State $continuous_state>], _>(solvers, num_samples, init_distr, $continuous_state, conf, density_conf),
+                        )*
+                        $(
+                            OdeSystemSolverVec::$network(solvers) => continuous_densities::<[<State $network_state>], _>(solvers, num_samples, init_distr, $network_state, conf, density_conf),
+                        )*
+                        _ => bail!("Invariant densities are not available for particles!"),
+                    }
+                }
+
+                pub fn networks(
+                    &self,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &NetworkConf,
+                    trajectory_conf: &TrajectoryConf,
+                ) -> Result<Vec<NetworkEvolution>, Error> {
+                    match self {
+                        $(
+                            OdeSystemSolverVec::$continuous_ode(solvers) => {
+                                let systems: Vec<$continuous_ode> = solvers.iter().map(|solver| solver.system().clone()).collect();
+                                continuous_networks::<{ $continuous_state }, _>(&systems, init_distr, conf, trajectory_conf)
+                            }
+                        )*
+                        _ => bail!("Networks are built from copies of a differential system, not from particles or oscillators!"),
+                    }
+                }
+
+                pub fn controlled(
+                    &self,
+                    init_distr: &[InitialDistributionVariant],
+                    conf: &ControlConf,
+                    trajectory_conf: &TrajectoryConf,
+                ) -> Result<Vec<ControlledEvolution>, Error> {
+                    match self {
+                        $(
+                            OdeSystemSolverVec::$continuous_ode(solvers) => {
+                                let systems: Vec<$continuous_ode> = solvers.iter().map(|solver| solver.system().clone()).collect();
+                                pyragas_control::<{ $continuous_state }, _>(&systems, init_distr, conf, trajectory_conf)
+                            }
+                        )*
+                        _ => bail!("Delayed feedback control is not available for particles and oscillator networks!"),
+                    }
+                }
             }
         } // paste
     };
@@ -139,11 +365,24 @@ create_and_implement_executor_variants! {
         Rulkov 2,
         Zaslavskii 2,
         ReverseProbability 2,
+        Lozi 2,
+        PeterDeJong 2,
+        Hopalong 2,
+        GumowskiMira 2,
+        Bedhead 2,
+        Svensson 2,
+        DiscreteLorenz 2,
+        DelayedLogistic 2,
         Shah 3,
         Memristive 3,
+        BarnsleyFern 3,
+        SierpinskiTriangle 3,
+        SierpinskiCarpet 3,
+        HeighwayDragon 3,
+        AffineIfs 3,
         Sfsimm 4
     ]
-    [Power, Transcendental, Sinus, Sinh, Zubieta, Picard, Biomorph]
+    [Power, Transcendental, Sinus, Sinh, Zubieta, BurningShip, Tricorn, Celtic, Phoenix, Picard, Biomorph, Newton]
     [
         Brusselator 2,
         VanDerPol 2,
@@ -160,8 +399,122 @@ create_and_implement_executor_variants! {
         ThreeSpeciesLotkaVolterra 3,
         Rikitake 3,
         HindmarshRose 3,
+        Thomas 3,
+        Dadras 3,
+        Lu 3,
+        SprottA 3,
+        SprottB 3,
+        SprottC 3,
+        SprottD 3,
+        SprottE 3,
+        SprottF 3,
+        SprottG 3,
+        SprottH 3,
+        SprottI 3,
+        SprottJ 3,
+        SprottK 3,
+        SprottL 3,
+        SprottM 3,
+        SprottN 3,
+        SprottO 3,
+        SprottP 3,
+        SprottQ 3,
+        SprottR 3,
+        SprottS 3,
+        NoseHoover 3,
+        Lorenz84 3,
+        Rucklidge 3,
+        Arneodo 3,
+        FourWing 3,
+        HenonHeiles 4,
+        DoublePendulum 4,
+        RestrictedThreeBody 4,
         Ababneh 4,
         WeiWang 4
     ]
     [XY, XYZ]
+    [Kuramoto 2]
+}
+
+impl ExecutorVariant {
+    pub fn energies(&self) -> Result<(&'static str, Vec<ChaosFloat>), Error> {
+        let energies = match self {
+            ExecutorVariant::HenonHeiles(ex) => (HenonHeiles::ENERGY_LABEL, ex.energies()),
+            ExecutorVariant::DoublePendulum(ex) => (DoublePendulum::ENERGY_LABEL, ex.energies()),
+            ExecutorVariant::RestrictedThreeBody(ex) => {
+                (RestrictedThreeBody::ENERGY_LABEL, ex.energies())
+            }
+            _ => bail!(
+                "Energies are only available for the Hamiltonian systems HenonHeiles, DoublePendulum and RestrictedThreeBody!"
+            ),
+        };
+        Ok(energies)
+    }
+
+    pub fn set_energy(&mut self, energy: ChaosFloat) -> Result<(), Error> {
+        match self {
+            ExecutorVariant::HenonHeiles(ex) => ex.set_energy(energy),
+            ExecutorVariant::DoublePendulum(ex) => ex.set_energy(energy),
+            ExecutorVariant::RestrictedThreeBody(ex) => ex.set_energy(energy),
+            _ => bail!(
+                "The energy can only be fixed for the Hamiltonian systems HenonHeiles, DoublePendulum and RestrictedThreeBody!"
+            ),
+        };
+        Ok(())
+    }
+}
+
+impl DiscreteMapVec {
+    pub fn topological_entropies(&self) -> Result<Vec<Option<ChaosFloat>>, Error> {
+        let entropies = match self {
+            DiscreteMapVec::Logistic(maps) => lap_entropies(maps, (0.0, 1.0), false),
+            DiscreteMapVec::Tent(maps) => lap_entropies(maps, (0.0, 1.0), false),
+            // the image of the real line is [beta, beta + 1]
+            DiscreteMapVec::Gauss(maps) => lap_entropies(maps, (-10.0, 10.0), false),
+            DiscreteMapVec::Circle(maps) => lap_entropies(maps, (0.0, 1.0), true),
+            _ => bail!(
+                "Topological entropy is only estimated for the 1D maps Logistic, Tent, Gauss and Circle!"
+            ),
+        };
+        Ok(entropies)
+    }
+
+    pub fn lattices(
+        &self,
+        conf: &LatticeConf,
+        initial_sites: &[ChaosFloat],
+        trajectory_conf: &TrajectoryConf,
+    ) -> Result<Vec<LatticeEvolution>, Error> {
+        let evolutions = match self {
+            DiscreteMapVec::Logistic(maps) => {
+                discrete_lattices(maps, conf, initial_sites, trajectory_conf)
+            }
+            DiscreteMapVec::Tent(maps) => {
+                discrete_lattices(maps, conf, initial_sites, trajectory_conf)
+            }
+            DiscreteMapVec::Gauss(maps) => {
+                discrete_lattices(maps, conf, initial_sites, trajectory_conf)
+            }
+            DiscreteMapVec::Circle(maps) => {
+                discrete_lattices(maps, conf, initial_sites, trajectory_conf)
+            }
+            _ => bail!(
+                "Coupled map lattices are built from the 1D maps Logistic, Tent, Gauss and Circle!"
+            ),
+        };
+        Ok(evolutions)
+    }
+
+    pub fn controlled(
+        &self,
+        init_distr: &[InitialDistributionVariant],
+        conf: &ControlConf,
+        trajectory_conf: &TrajectoryConf,
+    ) -> Result<Vec<ControlledEvolution>, Error> {
+        match self {
+            DiscreteMapVec::Logistic(maps) => ogy_control(maps, init_distr, conf, trajectory_conf),
+            DiscreteMapVec::Henon(maps) => ogy_control(maps, init_distr, conf, trajectory_conf),
+            _ => bail!("OGY control is available for the Logistic and Henon maps!"),
+        }
+    }
 }
@@ -4,10 +4,12 @@ mod fractal_data;
 mod fractal_generator;
 mod julia;
 mod mandelbrot;
+mod newton;
 mod simple_variants;
 pub use self::fractal_conf::*;
 pub use self::fractal_data::*;
 pub use self::fractal_generator::AlgebraElement;
 pub use self::julia::*;
 pub use self::mandelbrot::*;
+pub use self::newton::*;
 pub use self::simple_variants::*;
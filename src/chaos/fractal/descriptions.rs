@@ -38,6 +38,7 @@ macro_rules! implement_description {
         const LABEL_ITER_ESCAPE_TRANS: &str = "Iteration criteria: num iter < 50";
         const LABEL_ITER_ESCAPE_PICARD: &str = "Iteration criteria: num iter < 30";
         const LABEL_ITER_ESCAPE_BIOMORPH: &str = "Iteration criteria: num iter < 10";
+        const LABEL_ITER_CONVERGENCE_NEWTON: &str = "Iteration criteria: num iter < 100";
         paste!{
             $(
                 impl ChaosDescription for [<MandelbrotPower $elem>] {
@@ -231,6 +232,156 @@ macro_rules! implement_description {
                     }
                 }
 
+                impl ChaosDescription for [<MandelbrotBurningShip $elem>] {
+                    fn description(&self) -> String{
+                        format!("Mandelbrot set generator for the power of the component-wise absolute value of z, known as the Burning Ship fractal of Michelitsch and Rössler. Chosen is n={}", self.power_n())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://paulbourke.net/fractals/burnship/"
+                    }
+                }
+                impl ChaosFormula for [<MandelbrotBurningShip $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= (|Re z| + i |Im z|)^n + z0",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+                impl ChaosDescription for [<JuliaBurningShip $elem>] {
+                    fn description(&self) -> String{
+                        format!("Julia set generator for the power of the component-wise absolute value of z, known as the Burning Ship fractal of Michelitsch and Rössler. Chosen is n={} and c={}", self.power_n(), self.c().pretty_print())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://paulbourke.net/fractals/burnship/"
+                    }
+                }
+                impl ChaosFormula for [<JuliaBurningShip $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= (|Re z| + i |Im z|)^n + c",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+
+                impl ChaosDescription for [<MandelbrotTricorn $elem>] {
+                    fn description(&self) -> String{
+                        format!("Mandelbrot set generator for the power of the conjugate of z, known as the Tricorn or Mandelbar set. Chosen is n={}", self.power_n())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://wikipedia.org/wiki/Tricorn_(mathematics)"
+                    }
+                }
+                impl ChaosFormula for [<MandelbrotTricorn $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= conj(z)^n + z0",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+                impl ChaosDescription for [<JuliaTricorn $elem>] {
+                    fn description(&self) -> String{
+                        format!("Julia set generator for the power of the conjugate of z, known as the Tricorn or Mandelbar set. Chosen is n={} and c={}", self.power_n(), self.c().pretty_print())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://wikipedia.org/wiki/Tricorn_(mathematics)"
+                    }
+                }
+                impl ChaosFormula for [<JuliaTricorn $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= conj(z)^n + c",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+
+                impl ChaosDescription for [<MandelbrotCeltic $elem>] {
+                    fn description(&self) -> String{
+                        format!("Mandelbrot set generator for the power of z with the absolute value of the real part, known as the Celtic Mandelbrot set. Chosen is n={}", self.power_n())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://www.paulbourke.net/fractals/mandelbrot/"
+                    }
+                }
+                impl ChaosFormula for [<MandelbrotCeltic $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= |Re(z^n)| + i Im(z^n) + z0",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+                impl ChaosDescription for [<JuliaCeltic $elem>] {
+                    fn description(&self) -> String{
+                        format!("Julia set generator for the power of z with the absolute value of the real part, known as the Celtic Mandelbrot set. Chosen is n={} and c={}", self.power_n(), self.c().pretty_print())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://www.paulbourke.net/fractals/mandelbrot/"
+                    }
+                }
+                impl ChaosFormula for [<JuliaCeltic $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= |Re(z^n)| + i Im(z^n) + c",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+
+                impl ChaosDescription for [<MandelbrotPhoenix $elem>] {
+                    fn description(&self) -> String{
+                        format!("Mandelbrot set generator for the Phoenix fractal of Shigehiro Ushiki, whose iteration remembers the previous value of z. Chosen is n={} and p={:.2}", self.power_n(), self.par_p())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://paulbourke.net/fractals/phoenix/"
+                    }
+                }
+                impl ChaosFormula for [<MandelbrotPhoenix $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= z^n + z0 + p z_prev",
+                            "z_prev= previous z, starts at 0",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+                impl ChaosDescription for [<JuliaPhoenix $elem>] {
+                    fn description(&self) -> String{
+                        format!("Julia set generator for the Phoenix fractal of Shigehiro Ushiki, whose iteration remembers the previous value of z. The classic Phoenix is the Julia set with c=0.5667 and p=-0.5. Chosen is n={}, p={:.2} and c={}", self.power_n(), self.par_p(), self.c().pretty_print())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://paulbourke.net/fractals/phoenix/"
+                    }
+                }
+                impl ChaosFormula for [<JuliaPhoenix $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "z= z^n + c + p z_prev",
+                            "z_prev= previous z, starts at 0",
+                            "z0 from initial distribution",
+                            "Bounding criteria: |z| < r",
+                            LABEL_ITER_ESCAPE_COLOR
+                        ]
+                    }
+                }
+
                 impl ChaosDescription for [<MandelbrotPicard $elem>] {
                     fn description(&self) -> String{
                         format!("Picard-Mann iteration for the generation of Mandelbrot sets for the polynomial f(z)=z^n + a z + z0. This is an adaptation of Algorithm 1 in 'On the quaternion Julia sets via Picard–Mann iteration' to the Mandelbrot set and extended by adding a linearly scaled z term with a={:.2}. Chosen is n={} and α={:.2}", self.par_a(), self.power_n(), self.alpha())
@@ -314,6 +465,47 @@ macro_rules! implement_description {
                         ]
                     }
                 }
+
+                impl ChaosDescription for [<MandelbrotNewton $elem>] {
+                    fn description(&self) -> String{
+                        format!("Nova fractal of the relaxed Newton method for the real polynomial p(z), perturbed by the start value z0. Set n>0 to pick p(z)=z^n-1 or n=0 to enter the coefficients a0 to a5. Samples stop as soon as a Newton step is shorter than the tolerance. The perturbation shifts the fixed points away from the roots of p(z), so the converged root is the 1-based index of the polynomial root nearest to the fixed point the sample converged to and 0 otherwise, colour by it or by the number of iterations. Chosen is p(z) with the coefficients {:?} and a={:.2}", self.coefficients(), self.relaxation())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://wikipedia.org/wiki/Newton_fractal"
+                    }
+                }
+                impl ChaosFormula for [<MandelbrotNewton $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "p(z)= z^n - 1 or a5 z^5 + ... + a1 z + a0",
+                            "z= z - a p(z) / p'(z) + z0",
+                            "z0 from initial distribution",
+                            "Convergence criteria: |Δz| < 1e-6",
+                            "Bounding criteria: |z| < 1e6",
+                            LABEL_ITER_CONVERGENCE_NEWTON
+                        ]
+                    }
+                }
+                impl ChaosDescription for [<JuliaNewton $elem>] {
+                    fn description(&self) -> String{
+                        format!("Newton fractal of the relaxed Newton method for the real polynomial p(z), the plain Newton method for a=1 and c=0. Set n>0 to pick p(z)=z^n-1 or n=0 to enter the coefficients a0 to a5. Samples stop as soon as a Newton step is shorter than the tolerance. The converged root is the 1-based index of the polynomial root nearest to the fixed point the sample converged to and 0 otherwise, which is the root itself for c=0, colour by it or by the number of iterations. Roots of real polynomials are spheres around the real axis for quaternions, and every pair of real roots gives a perplex root. Chosen is p(z) with the coefficients {:?}, a={:.2} and c={}", self.coefficients(), self.relaxation(), self.c().pretty_print())
+                    }
+                    fn reference(&self) -> &'static str{
+                        "https://wikipedia.org/wiki/Newton_fractal"
+                    }
+                }
+                impl ChaosFormula for [<JuliaNewton $elem>]{
+                    fn formula(&self) -> &[&'static str]{
+                        &[
+                            "p(z)= z^n - 1 or a5 z^5 + ... + a1 z + a0",
+                            "z= z - a p(z) / p'(z) + c",
+                            "z0 from initial distribution",
+                            "Convergence criteria: |Δz| < 1e-6",
+                            "Bounding criteria: |z| < 1e6",
+                            LABEL_ITER_CONVERGENCE_NEWTON
+                        ]
+                    }
+                }
             )*
         }
     };
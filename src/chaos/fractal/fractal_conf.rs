@@ -11,6 +11,10 @@ const DEFAULT_ITERATIONS_COLOR: usize = 255;
 pub const DEFAULT_ITERATIONS_PICARD: usize = 30;
 pub const DEFAULT_ITERATIONS_TRANSCENDENTAL: usize = 50;
 const DEFAULT_ITERATIONS_BIOMORPH: usize = 10;
+const DEFAULT_ITERATIONS_NEWTON: usize = 100;
+const DEFAULT_NEWTON_R: ChaosFloat = 1e6;
+const DEFAULT_NEWTON_TOLERANCE: ChaosFloat = 1e-6;
+const DEFAULT_PHOENIX_P: ChaosFloat = -0.5;
 pub trait EscapeConf {
     fn max_iterations(&self) -> usize;
     fn max_radius(&self) -> ChaosFloat;
@@ -40,11 +44,19 @@ implement_escape_conf! {
     MandelbrotSinus DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     MandelbrotSinh DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     MandelbrotZubieta DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    MandelbrotBurningShip DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    MandelbrotTricorn DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    MandelbrotCeltic DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    MandelbrotPhoenix DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     MandelbrotBiomorph DEFAULT_ITERATIONS_BIOMORPH, [Complex, Dual, Perplex, Quaternion],
     JuliaPower DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     JuliaSinus DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     JuliaSinh DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     JuliaZubieta DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    JuliaBurningShip DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    JuliaTricorn DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    JuliaCeltic DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
+    JuliaPhoenix DEFAULT_ITERATIONS_COLOR, [Complex, Dual, Perplex, Quaternion],
     JuliaBiomorph DEFAULT_ITERATIONS_BIOMORPH, [Complex, Dual, Perplex, Quaternion]
 }
 
@@ -78,10 +90,18 @@ implement_simple_conf! {
     MandelbrotSinus [Complex, Dual, Perplex, Quaternion],
     MandelbrotSinh  [Complex, Dual, Perplex, Quaternion],
     MandelbrotZubieta  [Complex, Dual, Perplex, Quaternion],
+    MandelbrotBurningShip  [Complex, Dual, Perplex, Quaternion],
+    MandelbrotTricorn  [Complex, Dual, Perplex, Quaternion],
+    MandelbrotCeltic  [Complex, Dual, Perplex, Quaternion],
+    MandelbrotPhoenix  [Complex, Dual, Perplex, Quaternion],
     JuliaPower  [Complex, Dual, Perplex, Quaternion],
     JuliaSinus  [Complex, Dual, Perplex, Quaternion],
     JuliaSinh  [Complex, Dual, Perplex, Quaternion],
-    JuliaZubieta  [Complex, Dual, Perplex, Quaternion]
+    JuliaZubieta  [Complex, Dual, Perplex, Quaternion],
+    JuliaBurningShip  [Complex, Dual, Perplex, Quaternion],
+    JuliaTricorn  [Complex, Dual, Perplex, Quaternion],
+    JuliaCeltic  [Complex, Dual, Perplex, Quaternion],
+    JuliaPhoenix  [Complex, Dual, Perplex, Quaternion]
 }
 
 macro_rules! implement_simple_conf_with_a {
@@ -149,7 +169,32 @@ macro_rules! implement_simple_julia_conf {
 }
 
 implement_simple_julia_conf! {
-    Power, Transcendental, Sinus, Sinh, Zubieta, Picard, Biomorph
+    Power, Transcendental, Sinus, Sinh, Zubieta, BurningShip, Tricorn, Celtic, Phoenix, Picard, Biomorph, Newton
+}
+
+pub trait PhoenixConf {
+    fn par_p(&self) -> ChaosFloat;
+}
+macro_rules! implement_phoenix_conf {
+    ($($variant:ident [ $($elem:ident),* ] ),*)=> {
+        $(
+            paste!{
+                $(
+                    impl PhoenixConf for [<$variant $elem>] {
+                        fn par_p(&self) -> ChaosFloat {
+                            self.p
+                        }
+                    }
+                )*
+            }
+
+        )*
+    };
+}
+
+implement_phoenix_conf! {
+    MandelbrotPhoenix [Complex, Dual, Perplex, Quaternion],
+    JuliaPhoenix [Complex, Dual, Perplex, Quaternion]
 }
 
 pub trait MannConf {
@@ -409,6 +454,66 @@ implement_trans_conf! {
     JuliaTranscendental [Complex, Dual, Perplex, Quaternion]
 }
 
+pub trait ConvergenceConf {
+    fn max_iterations(&self) -> usize;
+    fn max_radius(&self) -> ChaosFloat;
+    fn tolerance(&self) -> ChaosFloat;
+}
+
+pub trait NewtonConf {
+    // ascending coefficients a0, a1, ... of the real polynomial p(z)
+    fn coefficients(&self) -> Vec<ChaosFloat>;
+    fn relaxation(&self) -> ChaosFloat;
+}
+
+fn newton_coefficients(n: ChaosFloat, coefficients: [ChaosFloat; 6]) -> Vec<ChaosFloat> {
+    let n = n.round() as usize;
+    if n == 0 {
+        coefficients.to_vec()
+    } else {
+        // z^n - 1 with the n-th roots of unity
+        let mut unit_polynomial = vec![0.0; n + 1];
+        unit_polynomial[0] = -1.0;
+        unit_polynomial[n] = 1.0;
+        unit_polynomial
+    }
+}
+
+macro_rules! implement_newton_conf {
+    ($($variant:ident [ $($elem:ident),* ] ),*)=> {
+        $(
+            paste!{
+                $(
+                    impl ConvergenceConf for [<$variant $elem>] {
+                        fn max_iterations(&self) -> usize {
+                            DEFAULT_ITERATIONS_NEWTON
+                        }
+                        fn max_radius(&self) -> ChaosFloat {
+                            DEFAULT_NEWTON_R
+                        }
+                        fn tolerance(&self) -> ChaosFloat {
+                            DEFAULT_NEWTON_TOLERANCE
+                        }
+                    }
+                    impl NewtonConf for [<$variant $elem>] {
+                        fn coefficients(&self) -> Vec<ChaosFloat> {
+                            newton_coefficients(self.n, [self.a0, self.a1, self.a2, self.a3, self.a4, self.a5])
+                        }
+                        fn relaxation(&self) -> ChaosFloat {
+                            self.a
+                        }
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+implement_newton_conf! {
+    MandelbrotNewton [Complex, Dual, Perplex, Quaternion],
+    JuliaNewton [Complex, Dual, Perplex, Quaternion]
+}
+
 impl Default for MandelbrotPowerComplex {
     fn default() -> Self {
         Self {
@@ -800,6 +905,318 @@ impl Default for JuliaZubietaQuaternion {
     }
 }
 
+impl Default for MandelbrotBurningShipComplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotBurningShipDual {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotBurningShipPerplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotBurningShipQuaternion {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+
+impl Default for MandelbrotTricornComplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotTricornDual {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotTricornPerplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotTricornQuaternion {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+
+impl Default for MandelbrotCelticComplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotCelticDual {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotCelticPerplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for MandelbrotCelticQuaternion {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+
+impl Default for JuliaBurningShipComplex {
+    fn default() -> Self {
+        Self {
+            c_re: -1.755,
+            c_im: -0.03,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaBurningShipDual {
+    fn default() -> Self {
+        Self {
+            c_re: -1.755,
+            c_im: -0.03,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaBurningShipPerplex {
+    fn default() -> Self {
+        Self {
+            c_re: -1.755,
+            c_im: -0.03,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaBurningShipQuaternion {
+    fn default() -> Self {
+        Self {
+            c_w: -1.755,
+            c_i: -0.03,
+            c_j: 0.0,
+            c_k: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+
+impl Default for JuliaTricornComplex {
+    fn default() -> Self {
+        Self {
+            c_re: -0.4,
+            c_im: 0.6,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaTricornDual {
+    fn default() -> Self {
+        Self {
+            c_re: -0.4,
+            c_im: 0.6,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaTricornPerplex {
+    fn default() -> Self {
+        Self {
+            c_re: -0.4,
+            c_im: 0.6,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaTricornQuaternion {
+    fn default() -> Self {
+        Self {
+            c_w: -0.4,
+            c_i: 0.6,
+            c_j: 0.0,
+            c_k: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+
+impl Default for JuliaCelticComplex {
+    fn default() -> Self {
+        Self {
+            c_re: -0.8,
+            c_im: 0.15,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaCelticDual {
+    fn default() -> Self {
+        Self {
+            c_re: -0.8,
+            c_im: 0.15,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaCelticPerplex {
+    fn default() -> Self {
+        Self {
+            c_re: -0.8,
+            c_im: 0.15,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+impl Default for JuliaCelticQuaternion {
+    fn default() -> Self {
+        Self {
+            c_w: -0.8,
+            c_i: 0.15,
+            c_j: 0.0,
+            c_k: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+        }
+    }
+}
+
+impl Default for MandelbrotPhoenixComplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+impl Default for MandelbrotPhoenixDual {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+impl Default for MandelbrotPhoenixPerplex {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+impl Default for MandelbrotPhoenixQuaternion {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+
+impl Default for JuliaPhoenixComplex {
+    fn default() -> Self {
+        Self {
+            c_re: 0.5667,
+            c_im: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+impl Default for JuliaPhoenixDual {
+    fn default() -> Self {
+        Self {
+            c_re: 0.5667,
+            c_im: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+impl Default for JuliaPhoenixPerplex {
+    fn default() -> Self {
+        Self {
+            c_re: 0.5667,
+            c_im: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+impl Default for JuliaPhoenixQuaternion {
+    fn default() -> Self {
+        Self {
+            c_w: 0.5667,
+            c_i: 0.0,
+            c_j: 0.0,
+            c_k: 0.0,
+            r: DEFAULT_MANDELBROT_R,
+            n: 2.0,
+            p: DEFAULT_PHOENIX_P,
+        }
+    }
+}
+
 impl Default for MandelbrotPicardComplex {
     fn default() -> Self {
         Self {
@@ -1046,3 +1463,133 @@ impl Default for JuliaBiomorphQuaternion {
         }
     }
 }
+
+impl Default for MandelbrotNewtonComplex {
+    fn default() -> Self {
+        // Nova fractal of z^3 - 1, the coefficients of z^3 - 2z + 2 are only used for n=0
+        Self {
+            n: 3.0,
+            a: 1.0,
+            a0: 2.0,
+            a1: -2.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+impl Default for MandelbrotNewtonDual {
+    fn default() -> Self {
+        // z^3 - z with the real roots -1, 0 and 1
+        Self {
+            n: 0.0,
+            a: 1.0,
+            a0: 0.0,
+            a1: -1.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+impl Default for MandelbrotNewtonPerplex {
+    fn default() -> Self {
+        // Nova fractal of z^2 - 1 with the four perplex roots 1, -1, j and -j,
+        // the coefficients of z^3 - 2z + 2 are only used for n=0
+        Self {
+            n: 2.0,
+            a: 1.0,
+            a0: 2.0,
+            a1: -2.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+impl Default for MandelbrotNewtonQuaternion {
+    fn default() -> Self {
+        Self {
+            n: 3.0,
+            a: 1.0,
+            a0: 2.0,
+            a1: -2.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+
+impl Default for JuliaNewtonComplex {
+    fn default() -> Self {
+        // plain Newton fractal of z^3 - 1 for c=0
+        Self {
+            c_re: 0.0,
+            c_im: 0.0,
+            n: 3.0,
+            a: 1.0,
+            a0: 2.0,
+            a1: -2.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+impl Default for JuliaNewtonDual {
+    fn default() -> Self {
+        Self {
+            c_re: 0.0,
+            c_im: 0.0,
+            n: 0.0,
+            a: 1.0,
+            a0: 0.0,
+            a1: -1.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+impl Default for JuliaNewtonPerplex {
+    fn default() -> Self {
+        Self {
+            c_re: 0.0,
+            c_im: 0.0,
+            n: 2.0,
+            a: 1.0,
+            a0: 2.0,
+            a1: -2.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
+impl Default for JuliaNewtonQuaternion {
+    fn default() -> Self {
+        // the roots of real polynomials are spheres around the real axis
+        Self {
+            c_w: 0.0,
+            c_i: 0.0,
+            c_j: 0.0,
+            c_k: 0.0,
+            n: 3.0,
+            a: 1.0,
+            a0: 2.0,
+            a1: -2.0,
+            a2: 0.0,
+            a3: 1.0,
+            a4: 0.0,
+            a5: 0.0,
+        }
+    }
+}
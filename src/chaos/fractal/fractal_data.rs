@@ -5,10 +5,13 @@ use nalgebra::ComplexField;
 pub struct FractalData<E> {
     z_0: E,
     z_n: E,
+    z_prev: Option<E>,
     n: usize,
     biomorph: bool,
     first: bool,
     last: bool,
+    converged: bool,
+    root: Option<usize>,
 }
 impl<E> FractalData<E> {
     pub fn z_0(&self) -> &E {
@@ -17,8 +20,12 @@ impl<E> FractalData<E> {
     pub fn z_n(&self) -> &E {
         &self.z_n
     }
+    // the previous iterate z_{n-1} for formulas with memory, None before the first iteration
+    pub fn z_prev(&self) -> Option<&E> {
+        self.z_prev.as_ref()
+    }
     pub fn set_next_z_n(&mut self, z: E) {
-        self.z_n = z;
+        self.z_prev = Some(std::mem::replace(&mut self.z_n, z));
         self.n += 1;
     }
     pub fn num_iterations(&self) -> usize {
@@ -42,22 +49,39 @@ impl<E> FractalData<E> {
     pub fn last(&self) -> bool {
         self.last
     }
+    pub fn set_converged(&mut self, root: Option<usize>) {
+        self.converged = true;
+        self.root = root;
+    }
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+    pub fn root(&self) -> Option<usize> {
+        self.root
+    }
+    // 0 for samples that did not converge to a root, otherwise the 1-based index of the root
+    fn root_coordinate(&self) -> ChaosFloat {
+        self.root.map_or(0.0, |root| (root + 1) as ChaosFloat)
+    }
 }
 impl<E: Copy> FractalData<E> {
     pub fn new(z: E) -> Self {
         Self {
             z_0: z,
             z_n: z,
+            z_prev: None,
             n: 0,
             biomorph: false,
             first: false,
             last: false,
+            converged: false,
+            root: None,
         }
     }
 }
 
 impl<T> ValidStateCheck for FractalData<T> {
-    // not necessary since the norm of z_n is checked by IterRadiusEscape
+    // not necessary since the norm of z_n is checked by IterRadiusEscape or IterConvergence
     fn is_valid(&self) -> bool {
         true
     }
@@ -83,6 +107,7 @@ impl StateIndex for FractalComplex {
             2 => self.n as ChaosFloat,
             3 => self.z_n.real(),
             4 => self.z_n.imaginary(),
+            5 => self.root_coordinate(),
             _ => 0.0,
         }
     }
@@ -103,6 +128,7 @@ impl StateIndex for FractalDual {
             2 => self.n as ChaosFloat,
             3 => self.z_n.re,
             4 => self.z_n.eps,
+            5 => self.root_coordinate(),
             _ => 0.0,
         }
     }
@@ -122,6 +148,7 @@ impl StateIndex for FractalPerplex {
             2 => self.n as ChaosFloat,
             3 => self.z_n.t,
             4 => self.z_n.x,
+            5 => self.root_coordinate(),
             _ => 0.0,
         }
     }
@@ -145,6 +172,7 @@ impl StateIndex for FractalQuaternion {
             6 => self.z_n.i,
             7 => self.z_n.j,
             8 => self.z_n.k,
+            9 => self.root_coordinate(),
             _ => 0.0,
         }
     }
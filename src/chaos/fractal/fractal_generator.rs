@@ -23,6 +23,8 @@ pub trait AlgebraElement {
     fn arg(&self) -> ChaosFloat;
     fn real(&self) -> ChaosFloat;
     fn absolute(&self) -> Self;
+    fn absolute_real(&self) -> Self;
+    fn conjugate(&self) -> Self;
     fn norm(&self) -> ChaosFloat;
     fn zero_element() -> Self;
     fn large_element() -> Self;
@@ -77,6 +79,12 @@ impl AlgebraElement for Complex {
         let im = self.im.abs();
         Self::new(re, im)
     }
+    fn absolute_real(&self) -> Self {
+        Self::new(self.re.abs(), self.im)
+    }
+    fn conjugate(&self) -> Self {
+        self.conj()
+    }
     fn norm(&self) -> ChaosFloat {
         self.modulus()
     }
@@ -125,6 +133,12 @@ impl AlgebraElement for Perplex {
     fn absolute(&self) -> Self {
         Self::new(self.t.abs(), self.x.abs())
     }
+    fn absolute_real(&self) -> Self {
+        Self::new(self.t.abs(), self.x)
+    }
+    fn conjugate(&self) -> Self {
+        Self::new(self.t, -self.x)
+    }
     fn real(&self) -> ChaosFloat {
         self.real()
     }
@@ -189,6 +203,12 @@ impl AlgebraElement for Dual {
         let eps = self.eps.abs();
         Self::new(re, eps)
     }
+    fn absolute_real(&self) -> Self {
+        Self::new(self.re.abs(), self.eps)
+    }
+    fn conjugate(&self) -> Self {
+        Self::new(self.re, -self.eps)
+    }
     fn real(&self) -> ChaosFloat {
         self.re
     }
@@ -258,6 +278,12 @@ impl AlgebraElement for Quaternion {
         let (i, j, k) = (self.i.abs(), self.j.abs(), self.k.abs());
         Self::new(w, i, j, k)
     }
+    fn absolute_real(&self) -> Self {
+        Self::new(self.w.abs(), self.i, self.j, self.k)
+    }
+    fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.i, -self.j, -self.k)
+    }
     fn norm(&self) -> ChaosFloat {
         self.magnitude()
     }
@@ -335,10 +361,66 @@ impl IterRadiusEscape {
     }
 }
 
+pub fn iter_convergence_check<E: AlgebraElement>(
+    fractal: &mut FractalData<E>,
+    max_iterations: usize,
+    max_radius: ChaosFloat,
+) -> bool {
+    !fractal.converged() && iter_radius_escape_check(fractal, max_iterations, max_radius)
+}
+
+/// Stops the iteration once a step is shorter than the tolerance, or on escape like IterRadiusEscape.
+#[derive(Clone, Debug)]
+pub struct IterConvergence {
+    max_iterations: usize,
+    max_radius: ChaosFloat,
+    tolerance: ChaosFloat,
+}
+impl IterConvergence {
+    pub fn new<P: ConvergenceConf>(params: &P) -> Self {
+        Self {
+            max_iterations: params.max_iterations(),
+            max_radius: params.max_radius(),
+            tolerance: params.tolerance(),
+        }
+    }
+    pub fn check_convergence<E: AlgebraElement>(&self, fractal: &mut FractalData<E>) -> bool {
+        iter_convergence_check(fractal, self.max_iterations, self.max_radius)
+    }
+    pub fn is_converged(&self, step_size: ChaosFloat) -> bool {
+        step_size < self.tolerance
+    }
+}
+
 pub trait SimpleFractalFn {
     fn next_z_n<E: AlgebraElement>(&self, z: &E, c: &E) -> E;
     fn new<C: SimpleConf>(conf: C) -> Self;
 }
+#[derive(Clone, Debug)]
+pub struct Phoenix {
+    power_n: i32,
+    p: ChaosFloat,
+}
+
+impl Phoenix {
+    pub fn new<P: PhoenixConf + SimpleConf>(params: P) -> Self {
+        Self {
+            power_n: params.power_n(),
+            p: params.par_p(),
+        }
+    }
+    pub fn next_z_n<E: AlgebraElement>(&self, fractal: &FractalData<E>, c: &E) -> E {
+        // https://paulbourke.net/fractals/phoenix/ with z_{-1} = 0
+        let memory = fractal
+            .z_prev()
+            .map_or_else(E::zero_element, |z_prev| z_prev.scale(self.p));
+        match fractal.z_n().power(self.power_n) {
+            Some(z) => z.add(c).add(&memory),
+            None => E::large_element(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Picard {
     a: ChaosFloat,
@@ -68,6 +68,41 @@ impl<E: AlgebraElement> DiscreteMap for JuliaPicard<E> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct JuliaPhoenix<E> {
+    c: E,
+    phoenix: Phoenix,
+    escape: IterRadiusEscape,
+}
+
+impl<E: AlgebraElement> JuliaPhoenix<E> {
+    pub fn new<P: JuliaConf<Element = E> + EscapeConf + PhoenixConf + SimpleConf>(
+        params: P,
+    ) -> Self {
+        let c = params.c();
+        let escape = IterRadiusEscape::new(&params);
+        let phoenix = Phoenix::new(params);
+        Self { c, phoenix, escape }
+    }
+}
+
+impl<E: AlgebraElement> FractalGenerator for JuliaPhoenix<E> {
+    type Element = E;
+    fn is_set_element(&self, fractal: &mut FractalData<Self::Element>) -> bool {
+        self.escape.check_escape(fractal)
+    }
+    fn next_z_n(&self, fractal: &FractalData<Self::Element>) -> Self::Element {
+        self.phoenix.next_z_n(fractal, &self.c)
+    }
+}
+
+impl<E: AlgebraElement> DiscreteMap for JuliaPhoenix<E> {
+    type State = FractalData<E>;
+    fn execute(&self, v: &mut FractalData<E>, t: &Time) {
+        self.iteration(v, t)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct JuliaBiomorph<E> {
     c: E, // aka r
@@ -202,4 +237,21 @@ mod tests {
         assert_eq!(fractal.num_iterations(), 1);
         assert_ne!(*fractal.z_n(), z_0);
     }
+
+    #[test]
+    fn test_julia_phoenix() {
+        let map = JuliaPhoenix::new(JuliaPhoenixComplex::default());
+        let mut fractal = FractalComplex::new(Complex::new(0.1, 0.0));
+        assert!(fractal.z_prev().is_none());
+        // z_{-1} = 0 in the first step
+        map.execute(&mut fractal, &0.0);
+        assert!((fractal.z_n().re - 0.5767).abs() < 1e-12);
+        assert_eq!(*fractal.z_prev().unwrap(), Complex::new(0.1, 0.0));
+        // 0.5767^2 + 0.5667 - 0.5 * 0.1
+        map.execute(&mut fractal, &0.0);
+        assert!((fractal.z_n().re - 0.84928289).abs() < 1e-12);
+        assert_eq!(fractal.z_n().im, 0.0);
+        assert!((fractal.z_prev().unwrap().re - 0.5767).abs() < 1e-12);
+        assert_eq!(fractal.num_iterations(), 2);
+    }
 }
@@ -74,6 +74,42 @@ impl<E: AlgebraElement> DiscreteMap for MandelbrotPicard<E> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct MandelbrotPhoenix<E> {
+    phoenix: Phoenix,
+    escape: IterRadiusEscape,
+    algebra_element_type: PhantomData<E>,
+}
+
+impl<E: AlgebraElement> MandelbrotPhoenix<E> {
+    pub fn new<P: EscapeConf + PhoenixConf + SimpleConf>(params: P) -> Self {
+        let escape = IterRadiusEscape::new(&params);
+        let phoenix = Phoenix::new(params);
+        Self {
+            phoenix,
+            escape,
+            algebra_element_type: PhantomData,
+        }
+    }
+}
+
+impl<E: AlgebraElement> FractalGenerator for MandelbrotPhoenix<E> {
+    type Element = E;
+    fn is_set_element(&self, fractal: &mut FractalData<Self::Element>) -> bool {
+        self.escape.check_escape(fractal)
+    }
+    fn next_z_n(&self, fractal: &FractalData<Self::Element>) -> Self::Element {
+        self.phoenix.next_z_n(fractal, fractal.z_0())
+    }
+}
+
+impl<E: AlgebraElement> DiscreteMap for MandelbrotPhoenix<E> {
+    type State = FractalData<E>;
+    fn execute(&self, v: &mut FractalData<E>, t: &Time) {
+        self.iteration(v, t)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MandelbrotBiomorph<E> {
     viscosity: Biomorph<E>,
@@ -0,0 +1,368 @@
+use super::fractal_conf::*;
+use super::fractal_data::*;
+use super::fractal_generator::*;
+use crate::chaos::data::*;
+use crate::chaos::functions::DiscreteMap;
+
+const MAX_ROOT_ITERATIONS: usize = 500;
+const ROOT_STEP_TOLERANCE: ChaosFloat = 1e-14;
+const REAL_ROOT_TOLERANCE: ChaosFloat = 1e-6;
+
+pub trait NewtonElement: AlgebraElement + Sized {
+    fn from_real(x: ChaosFloat) -> Self;
+    /// The roots of a real polynomial in the algebra given its complex roots.
+    fn roots(complex_roots: &[Complex]) -> Vec<Self>;
+    fn distance(&self, other: &Self) -> ChaosFloat {
+        let difference = self.sub(other);
+        difference.real_norm().hypot(difference.imaginary_norm())
+    }
+    fn root_distance(&self, root: &Self) -> ChaosFloat {
+        self.distance(root)
+    }
+}
+
+fn real_roots(complex_roots: &[Complex]) -> Vec<ChaosFloat> {
+    complex_roots
+        .iter()
+        .filter(|root| root.im.abs() < REAL_ROOT_TOLERANCE)
+        .map(|root| root.re)
+        .collect()
+}
+
+impl NewtonElement for Complex {
+    fn from_real(x: ChaosFloat) -> Self {
+        Self::new(x, 0.0)
+    }
+    fn roots(complex_roots: &[Complex]) -> Vec<Self> {
+        complex_roots.to_vec()
+    }
+}
+
+impl NewtonElement for Dual {
+    fn from_real(x: ChaosFloat) -> Self {
+        Self::new(x, 0.0)
+    }
+    fn roots(complex_roots: &[Complex]) -> Vec<Self> {
+        // the real part follows the real Newton iteration and the dual part vanishes at simple roots
+        real_roots(complex_roots)
+            .into_iter()
+            .map(|root| Self::new(root, 0.0))
+            .collect()
+    }
+    fn distance(&self, other: &Self) -> ChaosFloat {
+        (self.re - other.re).hypot(self.eps - other.eps)
+    }
+}
+
+impl NewtonElement for Perplex {
+    fn from_real(x: ChaosFloat) -> Self {
+        Self::new(x, 0.0)
+    }
+    fn roots(complex_roots: &[Complex]) -> Vec<Self> {
+        // t + x and t - x are iterated independently in the idempotent basis (1 ± j) / 2
+        let real_roots = real_roots(complex_roots);
+        real_roots
+            .iter()
+            .flat_map(|r_plus| {
+                real_roots.iter().map(move |r_minus| {
+                    Self::new((r_plus + r_minus) / 2.0, (r_plus - r_minus) / 2.0)
+                })
+            })
+            .collect()
+    }
+}
+
+impl NewtonElement for Quaternion {
+    fn from_real(x: ChaosFloat) -> Self {
+        Self::new(x, 0.0, 0.0, 0.0)
+    }
+    fn roots(complex_roots: &[Complex]) -> Vec<Self> {
+        // a pair of conjugated complex roots is a single sphere of quaternion roots
+        complex_roots
+            .iter()
+            .filter(|root| root.im > -REAL_ROOT_TOLERANCE)
+            .map(|root| Self::new(root.re, root.im.abs(), 0.0, 0.0))
+            .collect()
+    }
+    fn root_distance(&self, root: &Self) -> ChaosFloat {
+        (self.w - root.w).hypot(self.imaginary_norm() - root.imaginary_norm())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Polynomial {
+    // ascending coefficients without leading zeros
+    coefficients: Vec<ChaosFloat>,
+}
+
+impl Polynomial {
+    fn new(mut coefficients: Vec<ChaosFloat>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+    fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+    fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, a)| k as ChaosFloat * a)
+            .collect();
+        Self::new(coefficients)
+    }
+    fn evaluate<E: NewtonElement>(&self, z: &E) -> E {
+        // Horner scheme
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(E::zero_element(), |p, a| p.mul(z).add(&E::from_real(*a)))
+    }
+    fn complex_roots(&self) -> Vec<Complex> {
+        // Durand-Kerner method for the monic polynomial
+        let degree = self.degree();
+        if degree == 0 {
+            return Vec::new();
+        }
+        let leading = self.coefficients[degree];
+        let monic: Vec<Complex> = self
+            .coefficients
+            .iter()
+            .map(|a| Complex::new(a / leading, 0.0))
+            .collect();
+        let seed = Complex::new(0.4, 0.9);
+        let mut roots: Vec<Complex> = (0..degree).map(|k| seed.powu(k as u32)).collect();
+        for _ in 0..MAX_ROOT_ITERATIONS {
+            let mut max_step: ChaosFloat = 0.0;
+            for i in 0..degree {
+                let z = roots[i];
+                let p = monic
+                    .iter()
+                    .rev()
+                    .fold(Complex::new(0.0, 0.0), |p, a| p * z + a);
+                let denominator = roots
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .fold(Complex::new(1.0, 0.0), |d, (_, root)| d * (z - root));
+                let step = p / denominator;
+                roots[i] = z - step;
+                max_step = max_step.max(step.norm());
+            }
+            if max_step < ROOT_STEP_TOLERANCE {
+                break;
+            }
+        }
+        roots
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Newton<E> {
+    polynomial: Polynomial,
+    derivative: Polynomial,
+    relaxation: E,
+    roots: Vec<E>,
+    convergence: IterConvergence,
+}
+
+impl<E: NewtonElement> Newton<E> {
+    pub fn new<P: NewtonConf + ConvergenceConf>(params: &P) -> Self {
+        let polynomial = Polynomial::new(params.coefficients());
+        let derivative = polynomial.derivative();
+        let roots = E::roots(&polynomial.complex_roots());
+        Self {
+            polynomial,
+            derivative,
+            relaxation: E::from_real(params.relaxation()),
+            roots,
+            convergence: IterConvergence::new(params),
+        }
+    }
+    pub fn check_convergence(&self, fractal: &mut FractalData<E>) -> bool {
+        self.convergence.check_convergence(fractal)
+    }
+    pub fn next_z_n(&self, z: &E, c: &E) -> E {
+        let p_z = self.polynomial.evaluate(z);
+        let dp_z = self.derivative.evaluate(z);
+        match p_z.div(&dp_z) {
+            Some(newton_step) => z.sub(&self.relaxation.mul(&newton_step)).add(c),
+            None => E::large_element(),
+        }
+    }
+    pub fn root_index(&self, z: &E) -> Option<usize> {
+        // a perturbation c shifts the fixed points away from the roots, so the
+        // converged samples are clustered by the nearest root
+        self.roots
+            .iter()
+            .map(|root| z.root_distance(root))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(i, _)| i)
+    }
+    pub fn set_next_z_n(&self, fractal: &mut FractalData<E>, next_z: E) {
+        let step_size = next_z.distance(fractal.z_n());
+        fractal.set_next_z_n(next_z);
+        if self.convergence.is_converged(step_size) {
+            let root = self.root_index(fractal.z_n());
+            fractal.set_converged(root);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MandelbrotNewton<E> {
+    // Nova fractal - the Newton method is perturbed by z0
+    newton: Newton<E>,
+}
+
+impl<E: NewtonElement> MandelbrotNewton<E> {
+    pub fn new<P: NewtonConf + ConvergenceConf>(params: P) -> Self {
+        Self {
+            newton: Newton::new(&params),
+        }
+    }
+}
+
+impl<E: NewtonElement> FractalGenerator for MandelbrotNewton<E> {
+    type Element = E;
+    fn is_set_element(&self, fractal: &mut FractalData<Self::Element>) -> bool {
+        self.newton.check_convergence(fractal)
+    }
+    fn next_z_n(&self, fractal: &FractalData<Self::Element>) -> Self::Element {
+        self.newton.next_z_n(fractal.z_n(), fractal.z_0())
+    }
+    fn iteration(&self, fractal: &mut FractalData<Self::Element>, _t: &Time) {
+        if self.is_set_element(fractal) {
+            let next_z = self.next_z_n(fractal);
+            self.newton.set_next_z_n(fractal, next_z);
+        }
+    }
+}
+
+impl<E: NewtonElement> DiscreteMap for MandelbrotNewton<E> {
+    type State = FractalData<E>;
+    fn execute(&self, v: &mut FractalData<E>, t: &Time) {
+        self.iteration(v, t)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct JuliaNewton<E> {
+    c: E,
+    newton: Newton<E>,
+}
+
+impl<E: NewtonElement> JuliaNewton<E> {
+    pub fn new<P: JuliaConf<Element = E> + NewtonConf + ConvergenceConf>(params: P) -> Self {
+        Self {
+            c: params.c(),
+            newton: Newton::new(&params),
+        }
+    }
+}
+
+impl<E: NewtonElement> FractalGenerator for JuliaNewton<E> {
+    type Element = E;
+    fn is_set_element(&self, fractal: &mut FractalData<Self::Element>) -> bool {
+        self.newton.check_convergence(fractal)
+    }
+    fn next_z_n(&self, fractal: &FractalData<Self::Element>) -> Self::Element {
+        self.newton.next_z_n(fractal.z_n(), &self.c)
+    }
+    fn iteration(&self, fractal: &mut FractalData<Self::Element>, _t: &Time) {
+        if self.is_set_element(fractal) {
+            let next_z = self.next_z_n(fractal);
+            self.newton.set_next_z_n(fractal, next_z);
+        }
+    }
+}
+
+impl<E: NewtonElement> DiscreteMap for JuliaNewton<E> {
+    type State = FractalData<E>;
+    fn execute(&self, v: &mut FractalData<E>, t: &Time) {
+        self.iteration(v, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iterate<E: NewtonElement + Copy, M: DiscreteMap<State = FractalData<E>>>(
+        map: &M,
+        z: E,
+    ) -> FractalData<E> {
+        let mut fractal = FractalData::new(z);
+        for _ in 0..200 {
+            map.execute(&mut fractal, &0.0);
+        }
+        fractal
+    }
+
+    #[test]
+    fn test_newton_roots() {
+        let map = JuliaNewton::new(JuliaNewtonComplex::default());
+        let fractal = iterate(&map, Complex::new(-1.0, 1.0));
+        assert!(fractal.converged());
+        let root = map.newton.roots[fractal.root().expect("converged to a root")];
+        assert!((root - Complex::new(-0.5, 0.75_f64.sqrt())).norm() < 1e-9);
+        assert!((fractal.z_n() - root).norm() < 1e-9);
+        let num_iterations = fractal.num_iterations();
+        assert_eq!(iterate(&map, *fractal.z_n()).num_iterations(), 1);
+        // z^3 - 2z + 2 has an attracting 2-cycle between 0 and 1
+        let conf = JuliaNewtonComplex {
+            n: 0.0,
+            ..Default::default()
+        };
+        let map = JuliaNewton::new(conf);
+        assert_eq!(map.newton.roots.len(), 3);
+        let fractal = iterate(&map, Complex::new(0.01, 0.0));
+        assert!(!fractal.converged());
+        assert!(fractal.last());
+        assert!(fractal.num_iterations() > num_iterations);
+        // the four perplex roots of z^2 - 1
+        let map = JuliaNewton::new(JuliaNewtonPerplex::default());
+        assert_eq!(map.newton.roots.len(), 4);
+        let fractal = iterate(&map, Perplex::new(0.2, 1.5));
+        assert!(fractal.root().is_some());
+        assert_eq!(
+            map.newton.root_index(&Perplex::new(0.0, 1.0)),
+            fractal.root()
+        );
+        // quaternion roots of z^3 - 1 lie on spheres
+        let map = JuliaNewton::new(JuliaNewtonQuaternion::default());
+        assert_eq!(map.newton.roots.len(), 2);
+        let fractal = iterate(&map, Quaternion::new(-1.0, 0.0, 0.7, 0.7));
+        assert!(fractal.converged());
+        let root = fractal.z_n();
+        assert!((root.w + 0.5).abs() < 1e-9);
+        assert!((root.imaginary_norm() - 0.75_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nova_roots() {
+        let map = MandelbrotNewton::new(MandelbrotNewtonComplex::default());
+        let mut roots: Vec<usize> = [
+            Complex::new(0.3, 0.0),
+            Complex::new(0.0, 0.3),
+            Complex::new(0.0, -0.3),
+        ]
+        .into_iter()
+        .map(|z_0| {
+            let fractal = iterate(&map, z_0);
+            assert!(fractal.converged());
+            // the fixed point is shifted away from the root by z0
+            assert!(fractal.z_n().norm() > 1.2);
+            fractal.root().expect("converged to a fixed point")
+        })
+        .collect();
+        roots.sort();
+        roots.dedup();
+        assert_eq!(roots.len(), 3);
+    }
+}
@@ -86,6 +86,64 @@ impl SimpleFractalFn for SimpleZubieta {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct SimpleBurningShip {
+    power_n: i32,
+}
+
+impl SimpleFractalFn for SimpleBurningShip {
+    fn new<C: SimpleConf>(conf: C) -> Self {
+        Self {
+            power_n: conf.power_n(),
+        }
+    }
+    fn next_z_n<E: AlgebraElement>(&self, z: &E, c: &E) -> E {
+        // https://paulbourke.net/fractals/burnship/
+        match z.absolute().power(self.power_n) {
+            Some(z) => z.add(c),
+            None => E::large_element(),
+        }
+    }
+}
+#[derive(Clone, Debug)]
+pub struct SimpleTricorn {
+    power_n: i32,
+}
+
+impl SimpleFractalFn for SimpleTricorn {
+    fn new<C: SimpleConf>(conf: C) -> Self {
+        Self {
+            power_n: conf.power_n(),
+        }
+    }
+    fn next_z_n<E: AlgebraElement>(&self, z: &E, c: &E) -> E {
+        // https://wikipedia.org/wiki/Tricorn_(mathematics)
+        match z.conjugate().power(self.power_n) {
+            Some(z) => z.add(c),
+            None => E::large_element(),
+        }
+    }
+}
+#[derive(Clone, Debug)]
+pub struct SimpleCeltic {
+    power_n: i32,
+}
+
+impl SimpleFractalFn for SimpleCeltic {
+    fn new<C: SimpleConf>(conf: C) -> Self {
+        Self {
+            power_n: conf.power_n(),
+        }
+    }
+    fn next_z_n<E: AlgebraElement>(&self, z: &E, c: &E) -> E {
+        // absolute value of the real part after the power
+        match z.power(self.power_n) {
+            Some(z) => z.absolute_real().add(c),
+            None => E::large_element(),
+        }
+    }
+}
+
 macro_rules! generate_simple_variants {
     ($( $variant: ident ),*)=> {
         paste!{
@@ -130,4 +188,37 @@ macro_rules! generate_simple_variants {
     };
 }
 
-generate_simple_variants! {Power, Sinus, Sinh, Zubieta}
+generate_simple_variants! {Power, Sinus, Sinh, Zubieta, BurningShip, Tricorn, Celtic}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::data::Complex;
+    use crate::chaos::functions::*;
+    #[test]
+    fn test_burning_ship_tricorn_celtic() {
+        let z = Complex::new(-1.0, 2.0);
+        let next = |z_n: Complex, expected: Complex| {
+            assert!((z_n - expected).norm() < 1e-9, "{z_n} != {expected}");
+        };
+        // z^2 = -3 - 4i
+        next(
+            SimplePower::new(MandelbrotPowerComplex::default()).next_z_n(&z, &z),
+            Complex::new(-4.0, -2.0),
+        );
+        // (1 + 2i)^2 = conj(z)^2 = -3 + 4i
+        next(
+            SimpleBurningShip::new(MandelbrotBurningShipComplex::default()).next_z_n(&z, &z),
+            Complex::new(-4.0, 6.0),
+        );
+        next(
+            SimpleTricorn::new(MandelbrotTricornComplex::default()).next_z_n(&z, &z),
+            Complex::new(-4.0, 6.0),
+        );
+        // |Re(z^2)| + i Im(z^2) = 3 - 4i
+        next(
+            SimpleCeltic::new(MandelbrotCelticComplex::default()).next_z_n(&z, &z),
+            Complex::new(2.0, -2.0),
+        );
+    }
+}
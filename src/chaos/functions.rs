@@ -3,6 +3,16 @@ mod chaotic_function_configs;
 mod differential_eq;
 #[allow(clippy::derivable_impls)] // macro would fail
 mod discrete_maps;
+#[allow(clippy::unnecessary_to_owned)] // would create different type
+mod hamiltonian;
+#[allow(clippy::derivable_impls)] // macro would fail
+mod ifs;
+mod kuramoto;
 pub use self::chaotic_function_configs::*;
-pub use self::differential_eq::{empty_into_iter, Integrator, OdeSolver, OdeSolverTrait};
+pub use self::differential_eq::{
+    empty_into_iter, rk4_step, IntegrationScheme, Integrator, OdeSolver, OdeSolverTrait,
+    NUM_RK4_SUBSTEPS, ODE_STEP_SIZE,
+};
 pub use self::discrete_maps::{DiscreteMap, SimpleDiscreteMap};
+pub use self::hamiltonian::Hamiltonian;
+pub use self::kuramoto::{order_parameter, KuramotoSolver};
@@ -1,4 +1,5 @@
-use super::discrete_maps::check_zaslavskii;
+use super::discrete_maps::{check_bedhead, check_zaslavskii};
+use super::ifs::{check_affine_ifs, Ifs};
 use crate::chaos::data::ChaosFloat;
 use crate::chaos::fractal::*;
 use paste::paste;
@@ -6,12 +7,14 @@ use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 use std::fmt;
 macro_rules! generate_chaotic_function_configs {
-    ($($variant:ident $par_check_code:ident{ $($field:ident: ($field_min:expr, $field_max:expr)),* } ),*)=> {
+    ($($variant:ident $par_check_code:ident{ $($field:ident: ($field_min:expr, $field_max:expr)),* } $([ $($derived:ident: $derived_ty:ty),* ])? ),*)=> {
         $(
             #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
             #[serde(default)]
             pub struct $variant {
                 $(pub $field: ChaosFloat,)*
+                // derived from the parameters by the check
+                $($(#[serde(skip)] pub $derived: $derived_ty,)*)?
             }
             paste!{
                 impl $variant {
@@ -55,64 +58,117 @@ generate_chaotic_function_configs! {
     Rulkov no_check { alpha: (0.0, 10.0), mu: (0.0, 0.1), delta: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     Zaslavskii check_zaslavskii { eps: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), nu: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), mu: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     ReverseProbability no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r_threshold: (0.0, 1.0) },
+    Lozi no_check { a: (-2.0, 2.0), b: (-1.0, 1.0) },
+    PeterDeJong no_check { a: (-PI, PI), b: (-PI, PI), c: (-PI, PI), d: (-PI, PI) },
+    Hopalong no_check { a: (-20.0, 20.0), b: (-20.0, 20.0), c: (-20.0, 20.0) },
+    GumowskiMira no_check { alpha: (0.0, 0.1), sigma: (0.0, 1.0), mu: (-1.0, 1.0) },
+    Bedhead check_bedhead { a: (-1.0, 1.0), b: (-1.0, 1.0) },
+    Svensson no_check { a: (-PI, PI), b: (-PI, PI), c: (-10.0, 10.0), d: (-10.0, 10.0) },
+    DiscreteLorenz no_check { a: (0.0, 2.0), b: (0.0, 1.0) },
+    DelayedLogistic no_check { a: (0.0, 4.0) },
     Shah no_check { alpha: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), beta: (-10.0, 10.0), gamma: (-1.0, 1.0), delta: (-1.0, 1.0) },
     Memristive no_check { k: (-10.0, 10.0), a: (-10.0, 10.0) },
     Sfsimm no_check { p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b: (0.0, 2.0*PI), r: (0.0, 2.0*PI) },
+    BarnsleyFern no_check { },
+    SierpinskiTriangle no_check { r: (0.0, 1.0) },
+    SierpinskiCarpet no_check { r: (0.0, 1.0) },
+    HeighwayDragon no_check { },
+    AffineIfs check_affine_ifs { a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), d1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), e1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), f1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p1: (0.0, 1.0), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), d2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), e2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), f2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p2: (0.0, 1.0), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), d3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), e3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), f3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p3: (0.0, 1.0), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), d4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), e4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), f4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p4: (0.0, 1.0) } [ifs: Ifs<4>],
     MandelbrotPowerComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotTranscendentalComplex no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinusComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinhComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotZubietaComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotBurningShipComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotTricornComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotCelticComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotPhoenixComplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPicardComplex no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotNewtonComplex no_check { n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     MandelbrotBiomorphComplex check_mandelbrotbiomorph_complex {r: (0.0, ChaosFloat::INFINITY), m_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_re: (-1.0, 1.0), a_im: (-1.0, 1.0), b_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPowerComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaTranscendentalComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinusComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinhComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaZubietaComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaBurningShipComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaTricornComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaCelticComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaPhoenixComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPicardComplex no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaNewtonComplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     JuliaBiomorphComplex check_juliabiomorph_complex {r: (0.0, ChaosFloat::INFINITY), c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_re: (-1.0, 1.0), a_im: (-1.0, 1.0), b_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPowerDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotTranscendentalDual no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinusDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinhDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotZubietaDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotBurningShipDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotTricornDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotCelticDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotPhoenixDual no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPicardDual no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotNewtonDual no_check { n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     MandelbrotBiomorphDual check_mandelbrotbiomorph_dual {r: (0.0, ChaosFloat::INFINITY), m_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_re: (-1.0, 1.0), a_im: (-1.0, 1.0), b_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPowerDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaTranscendentalDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinusDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinhDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaZubietaDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaBurningShipDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaTricornDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaCelticDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaPhoenixDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPicardDual no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaNewtonDual no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     JuliaBiomorphDual check_juliabiomorph_dual {r: (0.0, ChaosFloat::INFINITY), c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_re: (-1.0, 1.0), a_im: (-1.0, 1.0), b_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPowerPerplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotTranscendentalPerplex no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinusPerplex no_check { r: (0.0, 20.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinhPerplex no_check { r: (0.0, 20.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotZubietaPerplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotBurningShipPerplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotTricornPerplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotCelticPerplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotPhoenixPerplex no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPicardPerplex no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotNewtonPerplex no_check { n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     MandelbrotBiomorphPerplex check_mandelbrotbiomorph_perplex {r: (0.0, ChaosFloat::INFINITY), m_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_re: (-1.0, 1.0), a_im: (-1.0, 1.0), b_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPowerPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaTranscendentalPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinusPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, 20.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinhPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, 20.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaZubietaPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaBurningShipPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaTricornPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaCelticPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaPhoenixPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPicardPerplex no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaNewtonPerplex no_check { c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     JuliaBiomorphPerplex check_juliabiomorph_perplex {r: (0.0, ChaosFloat::INFINITY), c_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_re: (-1.0, 1.0), a_im: (-1.0, 1.0), b_re: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_im: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPowerQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotTranscendentalQuaternion no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinusQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotSinhQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotZubietaQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotBurningShipQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotTricornQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotCelticQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotPhoenixQuaternion no_check { r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     MandelbrotPicardQuaternion no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    MandelbrotNewtonQuaternion no_check { n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     MandelbrotBiomorphQuaternion check_mandelbrotbiomorph_quaternion {r: (0.0, ChaosFloat::INFINITY), m_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_w: (-1.0, 1.0), a_i: (-1.0, 1.0), a_j: (-1.0, 1.0), a_k: (-1.0, 1.0), b_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPowerQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaTranscendentalQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY),  b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinusQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaSinhQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaZubietaQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaBurningShipQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaTricornQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaCelticQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaPhoenixQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), r: (0.0, ChaosFloat::INFINITY), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), p: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     JuliaPicardQuaternion no_check {a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
+    JuliaNewtonQuaternion no_check { c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), n: (0.0, 12.0), a: (0.0, 2.0), a0: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a1: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a2: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a3: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a4: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a5: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     JuliaBiomorphQuaternion check_juliabiomorph_quaternion {r: (0.0, ChaosFloat::INFINITY), c_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), m_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), a_w: (-1.0, 1.0), a_i: (-1.0, 1.0), a_j: (-1.0, 1.0), a_k: (-1.0, 1.0), b_w: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_i: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_j: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b_k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), alpha: (0.0, 1.0), n: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY)  },
     Brusselator no_check { a: (0.0, ChaosFloat::INFINITY), b: (0.0, ChaosFloat::INFINITY) },
     VanDerPol no_check { mu: (0.0, 4.0) },
@@ -129,8 +185,39 @@ generate_chaotic_function_configs! {
     ThreeSpeciesLotkaVolterra no_check { b: (0.0, ChaosFloat::INFINITY), d1: (0.0, ChaosFloat::INFINITY), d2: (0.0, ChaosFloat::INFINITY),  a11: (0.0, ChaosFloat::INFINITY), a12: (0.0, ChaosFloat::INFINITY), a13: (0.0, ChaosFloat::INFINITY), a21: (0.0, ChaosFloat::INFINITY), a23: (0.0, ChaosFloat::INFINITY), a31: (0.0, ChaosFloat::INFINITY), a32: (0.0, ChaosFloat::INFINITY) },
     Rikitake no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), mu: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     HindmarshRose no_check { a: (0.0, ChaosFloat::INFINITY), b: (0.0, ChaosFloat::INFINITY), c: (0.0, ChaosFloat::INFINITY), d: (0.0, ChaosFloat::INFINITY), r: (0.0, 0.1), i: (-10.0, 10.0) },
+    Thomas no_check { b: (0.0, 1.0) },
+    Dadras no_check { a: (0.0, 10.0), b: (0.0, 10.0), c: (0.0, 10.0), d: (0.0, 10.0), e: (0.0, 20.0) },
+    Lu no_check { a: (0.0, 50.0), b: (0.0, 10.0), c: (0.0, 40.0) },
+    SprottA no_check { a: (-5.0, 5.0) },
+    SprottB no_check { a: (-5.0, 5.0) },
+    SprottC no_check { a: (-5.0, 5.0) },
+    SprottD no_check { a: (-5.0, 5.0) },
+    SprottE no_check { a: (-5.0, 5.0) },
+    SprottF no_check { a: (-5.0, 5.0) },
+    SprottG no_check { a: (-5.0, 5.0) },
+    SprottH no_check { a: (-5.0, 5.0) },
+    SprottI no_check { a: (-5.0, 5.0) },
+    SprottJ no_check { a: (-5.0, 5.0) },
+    SprottK no_check { a: (-5.0, 5.0) },
+    SprottL no_check { a: (-5.0, 5.0) },
+    SprottM no_check { a: (-5.0, 5.0) },
+    SprottN no_check { a: (-5.0, 5.0) },
+    SprottO no_check { a: (-5.0, 5.0) },
+    SprottP no_check { a: (-5.0, 5.0) },
+    SprottQ no_check { a: (-5.0, 5.0) },
+    SprottR no_check { a: (-5.0, 5.0) },
+    SprottS no_check { a: (-5.0, 5.0) },
+    NoseHoover no_check { t: (0.1, 10.0), q: (0.1, 10.0) },
+    Lorenz84 no_check { a: (0.0, 1.0), b: (0.0, 10.0), f: (0.0, 20.0), g: (0.0, 5.0) },
+    Rucklidge no_check { kappa: (0.0, 10.0), lambda: (0.0, 20.0) },
+    Arneodo no_check { a: (-10.0, 10.0), b: (-10.0, 10.0), c: (-2.0, 2.0) },
+    FourWing no_check { a: (-1.0, 1.0), b: (-1.0, 1.0), c: (-1.0, 1.0) },
+    HenonHeiles no_check { lambda: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
+    DoublePendulum no_check { m1: (0.0, ChaosFloat::INFINITY), m2: (0.0, ChaosFloat::INFINITY), l1: (0.0, ChaosFloat::INFINITY), l2: (0.0, ChaosFloat::INFINITY), g: (0.0, ChaosFloat::INFINITY) },
+    RestrictedThreeBody no_check { mu: (0.0, 0.5) },
     Ababneh no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     WeiWang no_check { a: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), b: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), c: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), d: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
+    Kuramoto no_check { k: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     ParticleXY no_check { s: (-10.0, 10.0), m: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), l: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) },
     ParticleXYZ no_check { s: (-10.0, 10.0), m: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY), l: (ChaosFloat::NEG_INFINITY, ChaosFloat::INFINITY) }
 }
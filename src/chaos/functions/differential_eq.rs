@@ -3,22 +3,78 @@ use ode_solvers::dop_shared::IntegrationError as Error;
 use ode_solvers::Rk4;
 use ode_solvers::System;
 use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Mul};
 use std::vec::IntoIter;
 use std::vec::Vec;
 
 use super::chaotic_function_configs::*;
+use crate::chaos::analysis::{EventHistory, PoincareSection, ReturnMap, SectionState};
 use crate::chaos::data::*;
 use crate::chaos::labels::{ChaosDescription, ChaosFormula};
+use strum_macros::EnumIter;
+
+// time between two consecutive states of the solver output
+pub const ODE_STEP_SIZE: Time = 1e-1;
+// fixed Runge-Kutta steps between two consecutive states of coupled systems
+pub const NUM_RK4_SUBSTEPS: usize = 10;
+
+/// Integration scheme of the Hamiltonian systems, all other systems keep their own solver.
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
+pub enum IntegrationScheme {
+    #[default]
+    Dop853,
+    ImplicitMidpoint,
+    GaussLegendre,
+}
+
+impl From<IntegrationScheme> for &'static str {
+    fn from(val: IntegrationScheme) -> Self {
+        match val {
+            IntegrationScheme::Dop853 => "Dormand-Prince 8",
+            IntegrationScheme::ImplicitMidpoint => "Implicit Midpoint",
+            IntegrationScheme::GaussLegendre => "Gauss-Legendre 4",
+        }
+    }
+}
 
 pub fn empty_into_iter<P>() -> IntoIter<P> {
     let v: Vec<P> = Vec::new();
     v.into_iter()
 }
 
+/// Classical Runge-Kutta step of states that are evolved together, e.g. the nodes of a network.
+pub fn rk4_step<E>(states: &mut [E], t: Time, dt: Time, velocities: impl Fn(Time, &[E]) -> Vec<E>)
+where
+    E: Copy + Add<Output = E> + Mul<ChaosFloat, Output = E>,
+{
+    let shifted = |slopes: &[E], factor: ChaosFloat| -> Vec<E> {
+        states
+            .iter()
+            .zip(slopes.iter())
+            .map(|(x, slope)| *x + *slope * (factor * dt))
+            .collect()
+    };
+    let k1 = velocities(t, states);
+    let k2 = velocities(t + 0.5 * dt, &shifted(&k1, 0.5));
+    let k3 = velocities(t + 0.5 * dt, &shifted(&k2, 0.5));
+    let k4 = velocities(t + dt, &shifted(&k3, 1.0));
+    states.iter_mut().enumerate().for_each(|(i, x)| {
+        *x = *x + (k1[i] + k2[i] * 2.0 + k3[i] * 2.0 + k4[i]) * (dt / 6.0);
+    });
+}
+
 pub trait Integrator {
     type Input;
     type Output;
     fn integrate(&self, y0: &Self::Input) -> Result<IntoIter<Self::Output>, Error>;
+    fn integrate_with(
+        &self,
+        y0: &Self::Input,
+        _scheme: IntegrationScheme,
+    ) -> Result<IntoIter<Self::Output>, Error> {
+        self.integrate(y0)
+    }
     fn integration_time(&self) -> Time {
         thread_rng().gen_range(1.0..25.0)
     }
@@ -35,6 +91,33 @@ pub trait OdeSolverTrait {
         new_state_indices: Vec<usize>,
     );
     fn initial_states(&mut self, states: &mut [Option<Self::State>]);
+    fn set_poincare_section(&mut self, _section: Option<PoincareSection>) {}
+    fn set_integration_scheme(&mut self, _scheme: IntegrationScheme) {}
+    fn section_data(&self) -> Option<&ChaosData<Self::State>> {
+        None
+    }
+    fn set_return_map(&mut self, _return_map: Option<ReturnMap>) {}
+    fn return_map_data(&self) -> Option<&ChaosData<State2>> {
+        None
+    }
+}
+
+// events of a single state that are detected inside the step loop of the solver
+#[derive(Clone)]
+struct StateEvents<V> {
+    section_points: Vec<V>,
+    history: EventHistory,
+    return_map_pairs: Vec<State2>,
+}
+
+impl<V> StateEvents<V> {
+    fn new() -> Self {
+        Self {
+            section_points: Vec::new(),
+            history: Default::default(),
+            return_map_pairs: Vec::new(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -44,18 +127,44 @@ where
 {
     system: T,
     iterators: Vec<IntoIter<V>>,
+    section: Option<PoincareSection>,
+    return_map: Option<ReturnMap>,
+    // section crossings and return map pairs of each state during the current execution
+    events: Vec<StateEvents<V>>,
+    // all crossings and pairs of the last execution
+    section_data: ChaosData<V>,
+    return_map_data: ChaosData<State2>,
+    scheme: IntegrationScheme,
 }
 
-impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Output = V>>
-    OdeSolver<V, T>
+impl<
+        V: ValidStateCheck + SectionState,
+        T: Clone + System<Time, V> + Integrator<Input = V, Output = V>,
+    > OdeSolver<V, T>
 {
     pub fn new(system: T) -> Self {
         Self {
             system,
             iterators: Vec::new(),
+            section: None,
+            return_map: None,
+            events: Vec::new(),
+            section_data: Default::default(),
+            return_map_data: Default::default(),
+            scheme: Default::default(),
         }
     }
 
+    pub fn system(&self) -> &T {
+        &self.system
+    }
+
+    fn reset_events(&mut self) {
+        self.events
+            .iter_mut()
+            .for_each(|events| *events = StateEvents::new());
+    }
+
     fn remove_v_and_iter(state: &mut Option<V>) -> IntoIter<V> {
         *state = None;
         empty_into_iter()
@@ -64,15 +173,18 @@ impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Outp
     fn execute_state(
         state: &mut Option<V>,
         iter: &mut IntoIter<V>,
-        system: &T,
+        (system, scheme): (&T, IntegrationScheme),
         num_executions: usize,
+        (section, return_map): (&Option<PoincareSection>, &Option<ReturnMap>),
+        events: &mut StateEvents<V>,
     ) {
         if let Some(y) = state {
             for _ in 0..num_executions {
+                let y_prev = *y;
                 let next_state = iter.next();
                 match next_state {
                     Some(state) => *y = state,
-                    None => match system.integrate(y) {
+                    None => match system.integrate_with(y, scheme) {
                         Ok(new_iter) => {
                             *iter = new_iter;
                             if let Some(y_new) = iter.next() {
@@ -89,22 +201,58 @@ impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Outp
                     *iter = Self::remove_v_and_iter(state);
                     return;
                 }
+                let crossing = section
+                    .as_ref()
+                    .and_then(|section| section.crossing(&y_prev, y));
+                if let Some(return_map) = return_map {
+                    if let Some(pair) =
+                        return_map.next_pair(&mut events.history, (&y_prev, y), crossing.as_ref())
+                    {
+                        events.return_map_pairs.push(pair);
+                    }
+                }
+                if let Some(point) = crossing {
+                    events.section_points.push(point);
+                }
             }
         }
     }
 }
 
-impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Output = V>>
-    OdeSolverTrait for OdeSolver<V, T>
+impl<
+        V: ValidStateCheck + SectionState,
+        T: Clone + System<Time, V> + Integrator<Input = V, Output = V>,
+    > OdeSolverTrait for OdeSolver<V, T>
 {
     type State = V;
     fn execute(&mut self, states: &mut [Option<V>], num_executions: usize) {
+        self.events.resize_with(states.len(), StateEvents::new);
         states
             .iter_mut()
             .zip(self.iterators.iter_mut())
-            .for_each(|(state, iter)| {
-                Self::execute_state(state, iter, &self.system, num_executions);
+            .zip(self.events.iter_mut())
+            .for_each(|((state, iter), events)| {
+                Self::execute_state(
+                    state,
+                    iter,
+                    (&self.system, self.scheme),
+                    num_executions,
+                    (&self.section, &self.return_map),
+                    events,
+                );
             });
+        *self.section_data.data_mut() = self
+            .events
+            .iter_mut()
+            .flat_map(|events| events.section_points.drain(..))
+            .map(Some)
+            .collect();
+        *self.return_map_data.data_mut() = self
+            .events
+            .iter_mut()
+            .flat_map(|events| events.return_map_pairs.drain(..))
+            .map(Some)
+            .collect();
     }
 
     fn reinit_states(&mut self, all_states: &mut [Option<V>], new_indices: Vec<usize>) {
@@ -113,10 +261,14 @@ impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Outp
             let y0 = state
                 .as_mut()
                 .expect("States to reinit for the solver must all be Some");
-            self.iterators[i] = match self.system.integrate(y0) {
+            self.iterators[i] = match self.system.integrate_with(y0, self.scheme) {
                 Ok(iter) => iter,
                 Err(_e) => Self::remove_v_and_iter(state),
             };
+            if let Some(events) = self.events.get_mut(i) {
+                // the new trajectory starts without events
+                events.history = Default::default();
+            }
         }
     }
 
@@ -125,7 +277,7 @@ impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Outp
             .iter_mut()
             .map(|state| {
                 if let Some(y0) = state {
-                    match self.system.integrate(y0) {
+                    match self.system.integrate_with(y0, self.scheme) {
                         Ok(iter) => iter,
                         Err(_e) => Self::remove_v_and_iter(state),
                     }
@@ -134,6 +286,41 @@ impl<V: ValidStateCheck, T: Clone + System<Time, V> + Integrator<Input = V, Outp
                 }
             })
             .collect();
+        self.events.clear();
+    }
+
+    fn set_poincare_section(&mut self, section: Option<PoincareSection>) {
+        if self.section != section {
+            self.section = section;
+            self.section_data = Default::default();
+            self.reset_events();
+        }
+    }
+
+    fn set_integration_scheme(&mut self, scheme: IntegrationScheme) {
+        if self.scheme != scheme {
+            self.scheme = scheme;
+            // the next execution integrates all states again with the new scheme
+            self.iterators
+                .iter_mut()
+                .for_each(|iter| *iter = empty_into_iter());
+        }
+    }
+
+    fn section_data(&self) -> Option<&ChaosData<V>> {
+        self.section.as_ref().map(|_| &self.section_data)
+    }
+
+    fn set_return_map(&mut self, return_map: Option<ReturnMap>) {
+        if self.return_map != return_map {
+            self.return_map = return_map;
+            self.return_map_data = Default::default();
+            self.reset_events();
+        }
+    }
+
+    fn return_map_data(&self) -> Option<&ChaosData<State2>> {
+        self.return_map.as_ref().map(|_| &self.return_map_data)
     }
 }
 
@@ -150,7 +337,7 @@ macro_rules! implement_integrator_Rk4 {
                         0.0,
                         y0.to_owned(),
                         self.integration_time(),
-                        1e-1,
+                        ODE_STEP_SIZE,
                     );
                     let _ = stepper.integrate(); // Rk4 res is always Ok
                     let mut iter = stepper.y_out().to_owned().into_iter();
@@ -172,7 +359,7 @@ macro_rules! implement_integrator_Dop853 {
                         self.clone(),
                         0.0,
                         self.integration_time(),
-                        1e-1,
+                        ODE_STEP_SIZE,
                         y0.to_owned(),
                         1e-2,
                         1e-2,
@@ -195,6 +382,32 @@ implement_integrator_Rk4! {
     GenesioTesi, State3,
     BurkeShaw, State3,
     Rikitake, State3,
+    Thomas, State3,
+    Dadras, State3,
+    SprottA, State3,
+    SprottB, State3,
+    SprottC, State3,
+    SprottD, State3,
+    SprottE, State3,
+    SprottF, State3,
+    SprottG, State3,
+    SprottH, State3,
+    SprottI, State3,
+    SprottJ, State3,
+    SprottK, State3,
+    SprottL, State3,
+    SprottM, State3,
+    SprottN, State3,
+    SprottO, State3,
+    SprottP, State3,
+    SprottQ, State3,
+    SprottR, State3,
+    SprottS, State3,
+    NoseHoover, State3,
+    Lorenz84, State3,
+    Rucklidge, State3,
+    Arneodo, State3,
+    FourWing, State3,
     WeiWang, State4
 }
 implement_integrator_Dop853! {
@@ -205,7 +418,8 @@ implement_integrator_Dop853! {
     RabinovichFabrikant, State3,
     Halvorsen, State3,
     ThreeSpeciesLotkaVolterra, State3,
-    HindmarshRose, State3
+    HindmarshRose, State3,
+    Lu, State3
 }
 // On the Prediction of Chaotic Time Series using Neural Networks
 // TODO Mackey Glass as 1D example ?
@@ -701,6 +915,312 @@ impl ChaosFormula for HindmarshRose {
     }
 }
 
+impl System<Time, State3> for Thomas {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        dy[0] = y[1].sin() - self.b * y[0];
+        dy[1] = y[2].sin() - self.b * y[1];
+        dy[2] = y[0].sin() - self.b * y[2];
+    } // Thomas' cyclically symmetric attractor on Wikipedia (with defaults)
+}
+impl Default for Thomas {
+    fn default() -> Self {
+        Self { b: 0.208186 }
+    }
+}
+impl ChaosDescription for Thomas {
+    fn description(&self) -> String {
+        "Thomas' cyclically symmetric attractor is invariant under the cyclic exchange of x, y and z. It can be seen as the motion of a particle in a frictional lattice of forces. The dissipation 'b' is the bifurcation parameter: b > 1 has a single stable fixed point, the attractor becomes chaotic around b=0.208186, and for b=0 the dynamics is a conservative chaotic random walk through the whole space.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Thomas%27_cyclically_symmetric_attractor"
+    }
+}
+impl ChaosFormula for Thomas {
+    fn formula(&self) -> &[&'static str] {
+        &["dx= sin(y) - b x", "dy= sin(z) - b y", "dz= sin(x) - b z"]
+    }
+}
+
+impl System<Time, State3> for Dadras {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        let (x, y, z) = (y[0], y[1], y[2]);
+        dy[0] = y - self.a * x + self.b * y * z;
+        dy[1] = self.c * y - x * z + z;
+        dy[2] = self.d * x * y - self.e * z;
+    } // Dadras attractor by Paul Bourke (with defaults)
+}
+impl Default for Dadras {
+    fn default() -> Self {
+        Self {
+            a: 3.0,
+            b: 2.7,
+            c: 1.7,
+            d: 2.0,
+            e: 9.0,
+        }
+    }
+}
+impl ChaosDescription for Dadras {
+    fn description(&self) -> String {
+        "The Dadras system by Sara Dadras and Hamid Reza Momeni is a three dimensional autonomous system that generates two, three or four scroll attractors depending on its five parameters. The defaults show the four scroll attractor.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://paulbourke.net/fractals/dadras/"
+    }
+}
+impl ChaosFormula for Dadras {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "dx= y - a x + b y z",
+            "dy= c y - x z + z",
+            "dz= d x y - e z",
+        ]
+    }
+}
+
+impl System<Time, State3> for Lu {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        dy[0] = self.a * (y[1] - y[0]);
+        dy[1] = -y[0] * y[2] + self.c * y[1];
+        dy[2] = y[0] * y[1] - self.b * y[2];
+    } // Lü Chen attractor on Wikipedia (with defaults)
+}
+impl Default for Lu {
+    fn default() -> Self {
+        Self {
+            a: 36.0,
+            b: 3.0,
+            c: 20.0,
+        }
+    }
+}
+impl ChaosDescription for Lu {
+    fn description(&self) -> String {
+        "The Lü system by Jinhu Lü and Guanrong Chen bridges the gap between the Lorenz and the Chen attractor. It has the same quadratic terms and its equation dy= -x z + c y lies between the Lorenz equation dy= ρ x - x z - y and the Chen equation dy= (c - a) x - x z + c y. The attractor changes from a Lorenz like to a Chen like shape when the parameter 'c' is increased from about 13 to 28.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Multiscroll_attractor"
+    }
+}
+impl ChaosFormula for Lu {
+    fn formula(&self) -> &[&'static str] {
+        &["dx= a (y - x)", "dy= -x z + c y", "dz= x y - b z"]
+    }
+}
+
+const SPROTT_REFERENCE: &str = "https://doi.org/10.1103/PhysRevE.50.R647";
+macro_rules! implement_sprott_flows {
+    ($($case:ident $letter:literal, $a_default:expr, |$a:ident, $x:ident, $y:ident, $z:ident| [$dx:expr, $dy:expr, $dz:expr], [$($formula:expr),*]);*) => {
+        $(
+            impl System<Time, State3> for $case {
+                fn system(&self, _t: Time, v: &State3, dv: &mut State3) {
+                    let $a = self.a;
+                    let ($x, $y, $z) = (v[0], v[1], v[2]);
+                    dv[0] = $dx;
+                    dv[1] = $dy;
+                    dv[2] = $dz;
+                } // Some simple chaotic flows paper by J. C. Sprott (Table 1 with defaults)
+            }
+            impl Default for $case {
+                fn default() -> Self {
+                    Self { a: $a_default }
+                }
+            }
+            impl ChaosDescription for $case {
+                fn description(&self) -> String {
+                    let terms = if $letter <= 'E' {
+                        "five terms and two quadratic nonlinearities"
+                    } else {
+                        "six terms and one quadratic nonlinearity"
+                    };
+                    format!("Sprott case {} is one of the 19 algebraically simplest chaotic flows with {terms}, which J. C. Sprott found by a computer search. The parameter 'a' takes the place of the original coefficient {}. Range over it to leave or enter the chaotic regime.", $letter, $a_default)
+                }
+                fn reference(&self) -> &'static str {
+                    SPROTT_REFERENCE
+                }
+            }
+            impl ChaosFormula for $case {
+                fn formula(&self) -> &[&'static str] {
+                    &[$($formula),*]
+                }
+            }
+        )*
+    };
+}
+implement_sprott_flows! {
+    SprottA 'A', 1.0, |a, x, y, z| [y, -x + y * z, a - y * y], ["dx= y", "dy= -x + y z", "dz= a - y²"];
+    SprottB 'B', 1.0, |a, x, y, z| [y * z, x - y, a - x * y], ["dx= y z", "dy= x - y", "dz= a - x y"];
+    SprottC 'C', 1.0, |a, x, y, z| [y * z, x - y, a - x * x], ["dx= y z", "dy= x - y", "dz= a - x²"];
+    SprottD 'D', 3.0, |a, x, y, z| [-y, x + z, x * z + a * y * y], ["dx= -y", "dy= x + z", "dz= x z + a y²"];
+    SprottE 'E', 4.0, |a, x, y, z| [y * z, x * x - y, 1.0 - a * x], ["dx= y z", "dy= x² - y", "dz= 1 - a x"];
+    SprottF 'F', 0.5, |a, x, y, z| [y + z, -x + a * y, x * x - z], ["dx= y + z", "dy= -x + a y", "dz= x² - z"];
+    SprottG 'G', 0.4, |a, x, y, z| [a * x + z, x * z - y, -x + y], ["dx= a x + z", "dy= x z - y", "dz= -x + y"];
+    SprottH 'H', 0.5, |a, x, y, z| [-y + z * z, x + a * y, x - z], ["dx= -y + z²", "dy= x + a y", "dz= x - z"];
+    SprottI 'I', 0.2, |a, x, y, z| [-a * y, x + z, x + y * y - z], ["dx= -a y", "dy= x + z", "dz= x + y² - z"];
+    SprottJ 'J', 2.0, |a, x, y, z| [2.0 * z, -a * y + z, -x + y + y * y], ["dx= 2 z", "dy= -a y + z", "dz= -x + y + y²"];
+    SprottK 'K', 0.3, |a, x, y, z| [x * y - z, x - y, x + a * z], ["dx= x y - z", "dy= x - y", "dz= x + a z"];
+    SprottL 'L', 3.9, |a, x, y, z| [y + a * z, 0.9 * x * x - y, 1.0 - x], ["dx= y + a z", "dy= 0.9 x² - y", "dz= 1 - x"];
+    SprottM 'M', 1.7, |a, x, y, z| [-z, -x * x - y, a + a * x + y], ["dx= -z", "dy= -x² - y", "dz= a + a x + y"];
+    SprottN 'N', 2.0, |a, x, y, z| [-2.0 * y, x + z * z, 1.0 + y - a * z], ["dx= -2 y", "dy= x + z²", "dz= 1 + y - a z"];
+    SprottO 'O', 2.7, |a, x, y, z| [y, x - z, x + x * z + a * y], ["dx= y", "dy= x - z", "dz= x + x z + a y"];
+    SprottP 'P', 2.7, |a, x, y, z| [a * y + z, -x + y * y, x + y], ["dx= a y + z", "dy= -x + y²", "dz= x + y"];
+    SprottQ 'Q', 3.1, |a, x, y, z| [-z, x - y, a * x + y * y + 0.5 * z], ["dx= -z", "dy= x - y", "dz= a x + y² + 0.5 z"];
+    SprottR 'R', 0.9, |a, x, y, z| [a - y, 0.4 + z, x * y - z], ["dx= a - y", "dy= 0.4 + z", "dz= x y - z"];
+    SprottS 'S', 4.0, |a, x, y, z| [-x - a * y, x + z * z, 1.0 + x], ["dx= -x - a y", "dy= x + z²", "dz= 1 + x"]
+}
+
+impl System<Time, State3> for NoseHoover {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        dy[0] = y[1];
+        dy[1] = -y[0] - y[2] * y[1];
+        dy[2] = (y[1] * y[1] - self.t) / self.q;
+    } // Nosé-Hoover thermostat on Wikipedia for a harmonic oscillator of unit mass and frequency
+}
+impl Default for NoseHoover {
+    fn default() -> Self {
+        Self { t: 1.0, q: 1.0 }
+    }
+}
+impl ChaosDescription for NoseHoover {
+    fn description(&self) -> String {
+        "The Nosé-Hoover oscillator is a harmonic oscillator (x, y) in contact with a heat bath. The friction ζ is driven by the thermostat towards a mean kinetic energy y² at the temperature 'T', and the thermostat mass 'Q' sets how slowly the friction reacts. The system conserves the phase space volume on average, so there is no attractor: chaotic orbits fill a sea around invariant tori. For T=Q=1 it is Sprott case A with z= -ζ. Try the initial state (x=0, y=5, ζ=0) for the chaotic sea and (x=0, y=1, ζ=0) for a torus.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Nos%C3%A9%E2%80%93Hoover_thermostat"
+    }
+}
+impl ChaosFormula for NoseHoover {
+    fn formula(&self) -> &[&'static str] {
+        &["dx= y", "dy= -x - ζ y", "dζ= (y² - T) / Q"]
+    }
+}
+
+impl System<Time, State3> for Lorenz84 {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        let (x, y, z) = (y[0], y[1], y[2]);
+        dy[0] = -y * y - z * z - self.a * x + self.a * self.f;
+        dy[1] = x * y - self.b * x * z - y + self.g;
+        dy[2] = self.b * x * y + x * z - z;
+    } // Irregularity: a fundamental property of the atmosphere paper by E. N. Lorenz (with defaults)
+}
+impl Default for Lorenz84 {
+    fn default() -> Self {
+        Self {
+            a: 0.25,
+            b: 4.0,
+            f: 8.0,
+            g: 1.0,
+        }
+    }
+}
+impl ChaosDescription for Lorenz84 {
+    fn description(&self) -> String {
+        "The Lorenz-84 model is a low order model of the general atmospheric circulation at mid latitudes. The state x is the strength of the westerly wind, y and z are the cosine and sine phases of a chain of superposed large scale eddies. The forcings 'f' and 'g' are the thermal contrasts between the equator and the pole and between oceans and continents, 'a' is the damping of the westerly wind and 'b' the displacement of the eddies by the wind.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://doi.org/10.3402/tellusa.v36i2.11473"
+    }
+}
+impl ChaosFormula for Lorenz84 {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "dx= -y² - z² - a x + a f",
+            "dy= x y - b x z - y + g",
+            "dz= b x y + x z - z",
+        ]
+    }
+}
+
+impl System<Time, State3> for Rucklidge {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        dy[0] = -self.kappa * y[0] + self.lambda * y[1] - y[1] * y[2];
+        dy[1] = y[0];
+        dy[2] = -y[2] + y[1] * y[1];
+    } // Rucklidge attractor by dynamicmath.xyz (with defaults)
+}
+impl Default for Rucklidge {
+    fn default() -> Self {
+        Self {
+            kappa: 2.0,
+            lambda: 6.7,
+        }
+    }
+}
+impl ChaosDescription for Rucklidge {
+    fn description(&self) -> String {
+        "The Rucklidge system is a model of double convection, the convection of a fluid in a horizontal layer under the influence of a magnetic field or rotation. The parameter 'κ' damps the convective motion x and 'λ' is the strength of the driving. The attractor resembles the Lorenz attractor with its two lobes.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://www.dynamicmath.xyz/strange-attractors/"
+    }
+}
+impl ChaosFormula for Rucklidge {
+    fn formula(&self) -> &[&'static str] {
+        &["dx= -κ x + λ y - y z", "dy= x", "dz= -z + y²"]
+    }
+}
+
+impl System<Time, State3> for Arneodo {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        dy[0] = y[1];
+        dy[1] = y[2];
+        dy[2] = -self.a * y[0] - self.b * y[1] - y[2] + self.c * y[0].powi(3);
+    } // Arneodo attractor by dynamicmath.xyz (with defaults)
+}
+impl Default for Arneodo {
+    fn default() -> Self {
+        Self {
+            a: -5.5,
+            b: 3.5,
+            c: -1.0,
+        }
+    }
+}
+impl ChaosDescription for Arneodo {
+    fn description(&self) -> String {
+        "The Arneodo system by Alain Arneodo, Pierre Coullet and Charles Tresser is a jerk system, i.e. a single third order differential equation for x with the velocity y and the acceleration z. Its cubic nonlinearity c x³ creates a spiral attractor that is similar to the Rössler attractor.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://www.dynamicmath.xyz/strange-attractors/"
+    }
+}
+impl ChaosFormula for Arneodo {
+    fn formula(&self) -> &[&'static str] {
+        &["dx= y", "dy= z", "dz= -a x - b y - z + c x³"]
+    }
+}
+
+impl System<Time, State3> for FourWing {
+    fn system(&self, _t: Time, y: &State3, dy: &mut State3) {
+        let (x, y, z) = (y[0], y[1], y[2]);
+        dy[0] = self.a * x + y * z;
+        dy[1] = self.b * x + self.c * y - x * z;
+        dy[2] = -z - x * y;
+    } // Four-Wing attractor by dynamicmath.xyz (with defaults)
+}
+impl Default for FourWing {
+    fn default() -> Self {
+        Self {
+            a: 0.2,
+            b: 0.01,
+            c: -0.4,
+        }
+    }
+}
+impl ChaosDescription for FourWing {
+    fn description(&self) -> String {
+        "The four-wing attractor consists of four butterfly wings around the z-axis, which the trajectories visit one after another in an irregular order. It is built from three quadratic terms like the Lorenz system, but its symmetry under the rotation by 180° around each axis doubles the number of wings.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://www.dynamicmath.xyz/strange-attractors/"
+    }
+}
+impl ChaosFormula for FourWing {
+    fn formula(&self) -> &[&'static str] {
+        &["dx= a x + y z", "dy= b x + c y - x z", "dz= -z - x y"]
+    }
+}
+
 impl System<Time, State4> for Ababneh {
     fn system(&self, _t: Time, y: &State4, dy: &mut State4) {
         let (x, y, z, w) = (y[0], y[1], y[2], y[3]);
@@ -720,7 +1240,7 @@ impl Integrator for Ababneh {
             self.clone(),
             0.0,
             self.integration_time(),
-            1e-1,
+            ODE_STEP_SIZE,
             y0.to_owned(),
             1e-1,
             1e-1,
@@ -801,6 +1321,22 @@ impl ChaosFormula for WeiWang {
 mod tests {
     use super::*;
     #[test]
+    fn test_rk4_step() {
+        // dx = -x and the rotation (dy, dz) = (-z, y) are solved exactly up to the step error
+        let dt = ODE_STEP_SIZE / NUM_RK4_SUBSTEPS as Time;
+        let mut decay = [1.0];
+        let mut rotation = [State2::new(1.0, 0.0)];
+        for n in 0..(10 * NUM_RK4_SUBSTEPS) {
+            let t = n as Time * dt;
+            rk4_step(&mut decay, t, dt, |_, x| vec![-x[0]]);
+            rk4_step(&mut rotation, t, dt, |_, v| {
+                vec![State2::new(-v[0][1], v[0][0])]
+            });
+        }
+        assert!((decay[0] - (-1.0_f64).exp()).abs() < 1e-9);
+        assert!((rotation[0] - State2::new(1.0_f64.cos(), 1.0_f64.sin())).norm() < 1e-9);
+    }
+    #[test]
     fn test_lorentz() {
         let num_points = 2;
         let (x, y, z) = (1.0, 2.0, 3.0);
@@ -825,6 +1361,26 @@ mod tests {
         assert_eq!(v, w, "Both states should be changed deterministically!");
     }
     #[test]
+    fn test_sprott_flows() {
+        let v = State3::new(1.0, 2.0, 3.0);
+        let mut dv = State3::zeros();
+        SprottA::default().system(0.0, &v, &mut dv);
+        assert_eq!(dv, State3::new(2.0, 5.0, -3.0));
+        SprottS { a: 2.0 }.system(0.0, &v, &mut dv);
+        assert_eq!(dv, State3::new(-5.0, 10.0, 2.0));
+        // the cyclic exchange of the variables is a symmetry of the Thomas system
+        Thomas::default().system(0.0, &v, &mut dv);
+        let mut dw = State3::zeros();
+        Thomas::default().system(0.0, &State3::new(2.0, 3.0, 1.0), &mut dw);
+        assert_eq!(dw, State3::new(dv[1], dv[2], dv[0]));
+        // the Nosé-Hoover oscillator with T=Q=1 is Sprott case A with the friction ζ= -z
+        SprottA::default().system(0.0, &v, &mut dv);
+        NoseHoover::default().system(0.0, &State3::new(1.0, 2.0, -3.0), &mut dw);
+        assert_eq!(dw, State3::new(dv[0], dv[1], -dv[2]));
+        NoseHoover { t: 2.0, q: 0.5 }.system(0.0, &v, &mut dw);
+        assert_eq!(dw[2], 4.0);
+    }
+    #[test]
     fn test_ababneh() {
         let num_points = 1;
         let (x, y, z, w) = (1.0, 1.0, 1.0, 1.0);
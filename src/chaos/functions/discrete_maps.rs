@@ -16,6 +16,9 @@ impl<P> SimpleDiscreteMap<P> {
     pub fn new(conf: P) -> Self {
         Self { conf }
     }
+    pub fn conf(&self) -> &P {
+        &self.conf
+    }
 }
 
 impl<V, P: DiscreteMap<State = V>> DiscreteMap for SimpleDiscreteMap<P> {
@@ -107,12 +110,16 @@ impl ChaosFormula for Gauss {
     }
 }
 
+impl Circle {
+    /// Lift of the circle map to the real line, the phase θ is the fractional part.
+    pub fn lift(&self, theta: ChaosFloat) -> ChaosFloat {
+        theta + self.omega + self.k * (2.0 * PI * theta).sin() / (2.0 * PI)
+    }
+}
 impl DiscreteMap for Circle {
     type State = State1;
     fn execute(&self, v: &mut State1, _t: &Time) {
-        let theta = v[0];
-        v[0] =
-            (theta + self.omega + self.k * (2.0 * PI * theta).sin() / (2.0 * PI)).rem_euclid(1.0);
+        v[0] = self.lift(v[0]).rem_euclid(1.0);
     } // Arnold tongue standard circle map on Wikipedia (with defaults)
 }
 impl Default for Circle {
@@ -655,6 +662,266 @@ impl ChaosFormula for ReverseProbability {
     }
 }
 
+impl DiscreteMap for Lozi {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = 1.0 - self.a * x.abs() + y;
+        v[1] = self.b * x;
+    } // Lozi map on Scholarpedia (with defaults)
+}
+impl Default for Lozi {
+    fn default() -> Self {
+        Self { a: 1.7, b: 0.5 }
+    }
+}
+impl ChaosDescription for Lozi {
+    fn description(&self) -> String {
+        "The Lozi map replaces the square of the Hénon map by an absolute value. Its attractor consists of straight line pieces with sharp corners, and for the piecewise linear map the existence of a strange attractor can be proven. The classic parameters are a=1.7 and b=0.5.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "http://www.scholarpedia.org/article/Lozi_map"
+    }
+}
+impl ChaosFormula for Lozi {
+    fn formula(&self) -> &[&'static str] {
+        &["x= 1 - a |x| + y", "y= b x"]
+    }
+}
+
+impl DiscreteMap for PeterDeJong {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = (self.a * y).sin() - (self.b * x).cos();
+        v[1] = (self.c * x).sin() - (self.d * y).cos();
+    } // Peter de Jong Attractors on paulbourke.net (with parameter sets)
+}
+impl Default for PeterDeJong {
+    fn default() -> Self {
+        Self {
+            a: 1.4,
+            b: -2.3,
+            c: 2.4,
+            d: -2.1,
+        }
+    }
+}
+impl ChaosDescription for PeterDeJong {
+    fn description(&self) -> String {
+        "The Peter de Jong map draws thin veils and filaments inside the square [-2, 2]². Many million points and colouring by density bring out the structure of the attractor. Try for instance a=2.01, b=-2.53, c=1.61, d=-0.33 or a=-2.7, b=-0.09, c=-0.86, d=-2.2.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://paulbourke.net/fractals/peterdejong/"
+    }
+}
+impl ChaosFormula for PeterDeJong {
+    fn formula(&self) -> &[&'static str] {
+        &["x= sin(a y) - cos(b x)", "y= sin(c x) - cos(d y)"]
+    }
+}
+
+impl DiscreteMap for Hopalong {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = y - x.signum() * (self.b * x - self.c).abs().sqrt();
+        v[1] = self.a - x;
+    } // Hopalong Attractor on paulbourke.net (with parameter sets)
+}
+impl Default for Hopalong {
+    fn default() -> Self {
+        Self {
+            a: 7.17,
+            b: 8.44,
+            c: 2.56,
+        }
+    }
+}
+impl ChaosDescription for Hopalong {
+    fn description(&self) -> String {
+        "The Hopalong map by Barry Martin appeared in the Scientific American column 'Computer Recreations' of A. K. Dewdney. The orbit hops around the origin and fills a growing pattern of rings and crystals, so keep a long trajectory. Try for instance a=0.4, b=1, c=0 or a=-11, b=0.05, c=0.5.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://paulbourke.net/fractals/hopalong/"
+    }
+}
+impl ChaosFormula for Hopalong {
+    fn formula(&self) -> &[&'static str] {
+        &["x= y - sign(x) √|b x - c|", "y= a - x"]
+    }
+}
+
+impl GumowskiMira {
+    fn g(&self, x: ChaosFloat) -> ChaosFloat {
+        self.mu * x + 2.0 * (1.0 - self.mu) * x * x / (1.0 + x * x)
+    }
+}
+impl DiscreteMap for GumowskiMira {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        let x_new = y + self.alpha * (1.0 - self.sigma * y * y) * y + self.g(x);
+        v[0] = x_new;
+        v[1] = -x + self.g(x_new);
+    } // Recurrences and discrete dynamic systems by I. Gumowski and C. Mira (with defaults)
+}
+impl Default for GumowskiMira {
+    fn default() -> Self {
+        Self {
+            alpha: 0.008,
+            sigma: 0.05,
+            mu: -0.7,
+        }
+    }
+}
+impl ChaosDescription for GumowskiMira {
+    fn description(&self) -> String {
+        "The Gumowski-Mira map was set up at CERN to model the motion of particles in an accelerator. For a small dissipation α it is close to a conservative map, and the shape of the attractor is controlled by μ. Small changes of μ between -1 and 1 produce a large variety of flowers, spirals and wings.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://doi.org/10.1007/BFb0089135"
+    }
+}
+impl ChaosFormula for GumowskiMira {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "x= y + α (1 - σ y²) y + G(x)",
+            "y= -x + G(x_new)",
+            "G(x)= μ x + 2 (1 - μ) x² / (1 + x²)",
+        ]
+    }
+}
+
+// b divides both coordinates, so it keeps a distance to zero
+const MIN_BEDHEAD_B: ChaosFloat = 0.1;
+pub fn check_bedhead(conf: &mut Bedhead) {
+    if conf.b.abs() < MIN_BEDHEAD_B {
+        conf.b = MIN_BEDHEAD_B.copysign(conf.b);
+    }
+}
+
+impl DiscreteMap for Bedhead {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = (x * y / self.b).sin() * y + (self.a * x - y).cos();
+        v[1] = x + y.sin() / self.b;
+    } // 2D Strange Attractors on the Softology blog (with parameter sets)
+}
+impl Default for Bedhead {
+    fn default() -> Self {
+        Self { a: -0.81, b: -0.92 }
+    }
+}
+impl ChaosDescription for Bedhead {
+    fn description(&self) -> String {
+        "The Bedhead attractor by Ivan Emrich resembles messy hair after a night of sleep. Try the start state (x=1, y=1) and parameters like a=-0.67, b=0.83 or a=0.65, b=0.73. Values of b closer to zero than ±0.1 are not allowed, since b divides both coordinates.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://softologyblog.wordpress.com/2017/03/04/2d-strange-attractors/"
+    }
+}
+impl ChaosFormula for Bedhead {
+    fn formula(&self) -> &[&'static str] {
+        &["x= sin(x y / b) y + cos(a x - y)", "y= x + sin(y) / b"]
+    }
+}
+
+impl DiscreteMap for Svensson {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = self.d * (self.a * x).sin() - (self.b * y).sin();
+        v[1] = self.c * (self.a * x).cos() + (self.b * y).cos();
+    } // 2D Strange Attractors on the Softology blog (with parameter sets)
+}
+impl Default for Svensson {
+    fn default() -> Self {
+        Self {
+            a: 1.4,
+            b: 1.56,
+            c: 1.4,
+            d: -6.56,
+        }
+    }
+}
+impl ChaosDescription for Svensson {
+    fn description(&self) -> String {
+        "The Svensson map by Johnny Svensson is a variation of the Peter de Jong map with the amplitudes c and d. Try for instance a=1.5, b=-1.8, c=1.6, d=0.9.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://softologyblog.wordpress.com/2017/03/04/2d-strange-attractors/"
+    }
+}
+impl ChaosFormula for Svensson {
+    fn formula(&self) -> &[&'static str] {
+        &["x= d sin(a x) - sin(b y)", "y= c cos(a x) + cos(b y)"]
+    }
+}
+
+impl DiscreteMap for DiscreteLorenz {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = (1.0 + self.a * self.b) * x - self.b * x * y;
+        v[1] = (1.0 - self.b) * y + self.b * x * x;
+    } // Computational chaos paper by E. N. Lorenz (with defaults)
+}
+impl Default for DiscreteLorenz {
+    fn default() -> Self {
+        Self { a: 1.25, b: 0.75 }
+    }
+}
+impl ChaosDescription for DiscreteLorenz {
+    fn description(&self) -> String {
+        "E. N. Lorenz derived this map as an Euler discretization with the step size b of a two dimensional flow, which has no chaos as a differential system. For large steps like b=0.75 the discretization itself becomes chaotic, which shows how numerical schemes can produce computational chaos.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://doi.org/10.1016/0167-2789(89)90072-9"
+    }
+}
+impl ChaosFormula for DiscreteLorenz {
+    fn formula(&self) -> &[&'static str] {
+        &["x= (1 + a b) x - b x y", "y= (1 - b) y + b x²"]
+    }
+}
+
+impl DiscreteMap for DelayedLogistic {
+    type State = State2;
+    fn execute(&self, v: &mut State2, _t: &Time) {
+        let x = v[0];
+        let y = v[1];
+        v[0] = self.a * x * (1.0 - y);
+        v[1] = x;
+    } // Delayed logistic map of J. Maynard Smith (with defaults)
+}
+impl Default for DelayedLogistic {
+    fn default() -> Self {
+        Self { a: 2.27 }
+    }
+}
+impl ChaosDescription for DelayedLogistic {
+    fn description(&self) -> String {
+        "The delayed logistic map models a population whose growth depends on the population of the previous generation, with y as the delayed copy of x. For a > 2 the fixed point loses stability in a Neimark-Sacker bifurcation and the orbit runs on an invariant circle, which folds and breaks up into chaos around a=2.27.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Logistic_map"
+    }
+}
+impl ChaosFormula for DelayedLogistic {
+    fn formula(&self) -> &[&'static str] {
+        &["x= a x (1 - y)", "y= x"]
+    }
+}
+
 impl DiscreteMap for Shah {
     type State = State3;
     fn execute(&self, v: &mut State3, t: &Time) {
@@ -846,6 +1113,38 @@ mod tests {
         );
     }
     #[test]
+    fn test_attractor_maps() {
+        let mut v = State2::new(0.5, -0.2);
+        Lozi::default().execute(&mut v, &0.0);
+        assert_eq!(v, State2::new(1.0 - 1.7 * 0.5 - 0.2, 0.5 * 0.5));
+        // the discrete Lorenz map is an Euler step of size b
+        let (a, b) = (1.25, 0.75);
+        let mut v = State2::new(0.5, -0.2);
+        DiscreteLorenz { a, b }.execute(&mut v, &0.0);
+        let (dx, dy) = (a * 0.5 + 0.5 * 0.2, 0.2 + 0.5 * 0.5);
+        assert!((v[0] - (0.5 + b * dx)).abs() < 1e-12);
+        assert!((v[1] - (-0.2 + b * dy)).abs() < 1e-12);
+        let bounded = |map: &dyn DiscreteMap<State = State2>| {
+            let mut v = State2::new(0.1, 0.1);
+            (0..10_000).all(|_| {
+                map.execute(&mut v, &0.0);
+                v[0].abs() < 100.0 && v[1].abs() < 100.0
+            })
+        };
+        assert!(bounded(&Lozi::default()));
+        assert!(bounded(&PeterDeJong::default()));
+        assert!(bounded(&Hopalong::default()));
+        assert!(bounded(&GumowskiMira::default()));
+        assert!(bounded(&Bedhead::default()));
+        assert!(bounded(&Svensson::default()));
+        assert!(bounded(&DiscreteLorenz::default()));
+        assert!(bounded(&DelayedLogistic::default()));
+        let mut bedhead = Bedhead { a: -0.81, b: 0.0 };
+        bedhead.par_range_check();
+        assert_eq!(bedhead.b, 0.1);
+        assert!(bounded(&bedhead));
+    }
+    #[test]
     fn test_description() {
         let map = Sfsimm::default();
         assert_eq!(map.formula().len(), 4);
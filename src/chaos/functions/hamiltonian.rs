@@ -0,0 +1,418 @@
+use nalgebra::{DMatrix, DVector, SMatrix, SVector};
+use ode_solvers::dop853::Dop853;
+use ode_solvers::dop_shared::IntegrationError as Error;
+use ode_solvers::System;
+use std::vec::IntoIter;
+
+use super::chaotic_function_configs::*;
+use super::differential_eq::{IntegrationScheme, Integrator, ODE_STEP_SIZE};
+use crate::chaos::data::*;
+use crate::chaos::labels::{ChaosDescription, ChaosFormula};
+
+// symplectic steps between two consecutive states of the solver output
+const NUM_SUBSTEPS: usize = 10;
+const MAX_NEWTON_ITERATIONS: usize = 50;
+const NEWTON_TOLERANCE: ChaosFloat = 1e-14;
+const FINITE_DIFFERENCE: ChaosFloat = 1e-6;
+
+/// Conservative systems with a conserved energy (or Jacobi constant) as first integral.
+pub trait Hamiltonian {
+    type State;
+    const ENERGY_LABEL: &'static str;
+    fn energy(&self, v: &Self::State) -> ChaosFloat;
+    /// The state with its last momentum (or velocity) chosen such that the energy has the given value.
+    /// None if no such state exists, e.g. outside of the Hill region.
+    fn with_energy(&self, v: &Self::State, energy: ChaosFloat) -> Option<Self::State>;
+}
+
+/// Implicit Runge-Kutta step with the stage increments solved by a simplified Newton iteration.
+/// Gauss collocation methods are symplectic for every Hamiltonian system.
+fn gauss_step<const D: usize, const S: usize, T: System<Time, SVector<ChaosFloat, D>>>(
+    system: &T,
+    y: &mut SVector<ChaosFloat, D>,
+    h: Time,
+    (a, b): (&[[ChaosFloat; S]; S], &[ChaosFloat; S]),
+) {
+    let velocity = |state: &SVector<ChaosFloat, D>| {
+        let mut slope = SVector::<ChaosFloat, D>::zeros();
+        system.system(0.0, state, &mut slope);
+        slope
+    };
+    let mut jacobian = SMatrix::<ChaosFloat, D, D>::zeros();
+    for j in 0..D {
+        let mut shift = SVector::<ChaosFloat, D>::zeros();
+        shift[j] = FINITE_DIFFERENCE;
+        jacobian.set_column(
+            j,
+            &((velocity(&(*y + shift)) - velocity(&(*y - shift))) / (2.0 * FINITE_DIFFERENCE)),
+        );
+    }
+    // I - h A ⊗ J approximates the derivative of the stage equations
+    let newton_matrix = DMatrix::from_fn(S * D, S * D, |r, c| {
+        let identity = if r == c { 1.0 } else { 0.0 };
+        identity - h * a[r / D][c / D] * jacobian[(r % D, c % D)]
+    })
+    .lu();
+    let mut increments = [SVector::<ChaosFloat, D>::zeros(); S];
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let slopes = increments.map(|z| velocity(&(*y + z)));
+        let residual = DVector::from_fn(S * D, |r, _| {
+            let (i, k) = (r / D, r % D);
+            h * (0..S).map(|j| a[i][j] * slopes[j][k]).sum::<ChaosFloat>() - increments[i][k]
+        });
+        let correction = match newton_matrix.solve(&residual) {
+            Some(correction) => correction,
+            None => break,
+        };
+        (0..S * D).for_each(|r| increments[r / D][r % D] += correction[r]);
+        if correction.amax() <= NEWTON_TOLERANCE * (1.0 + y.amax()) {
+            break; // NaN increments are ignored by the maximum, the solver removes them
+        }
+    }
+    let slopes = increments.map(|z| velocity(&(*y + z)));
+    (0..S).for_each(|i| *y += slopes[i] * (h * b[i]));
+}
+
+fn symplectic_integration<const D: usize, T: System<Time, SVector<ChaosFloat, D>>>(
+    system: &T,
+    y0: &SVector<ChaosFloat, D>,
+    scheme: IntegrationScheme,
+    integration_time: Time,
+) -> IntoIter<SVector<ChaosFloat, D>> {
+    let h = ODE_STEP_SIZE / NUM_SUBSTEPS as Time;
+    let sqrt3_6 = 3.0_f64.sqrt() / 6.0;
+    let gauss_legendre_a = [[0.25, 0.25 - sqrt3_6], [0.25 + sqrt3_6, 0.25]];
+    let num_steps = (integration_time / ODE_STEP_SIZE).ceil() as usize;
+    let mut y = *y0;
+    let mut states = Vec::with_capacity(num_steps);
+    for _ in 0..num_steps {
+        for _ in 0..NUM_SUBSTEPS {
+            match scheme {
+                IntegrationScheme::GaussLegendre => {
+                    gauss_step(system, &mut y, h, (&gauss_legendre_a, &[0.5, 0.5]))
+                }
+                _ => gauss_step(system, &mut y, h, (&[[0.5]], &[1.0])),
+            }
+        }
+        states.push(y);
+        if !y.iter().all(|x| x.is_finite()) {
+            break;
+        }
+    }
+    states.into_iter()
+}
+
+macro_rules! implement_integrator_hamiltonian {
+    ($($system: ident),*) => {
+        $(
+            impl Integrator for $system {
+                type Input = State4;
+                type Output = State4;
+                fn integrate(&self, y0: &State4) -> Result<IntoIter<State4>, Error> {
+                    self.integrate_with(y0, Default::default())
+                }
+                fn integrate_with(
+                    &self,
+                    y0: &State4,
+                    scheme: IntegrationScheme,
+                ) -> Result<IntoIter<State4>, Error> {
+                    if scheme != IntegrationScheme::Dop853 {
+                        return Ok(symplectic_integration(self, y0, scheme, self.integration_time()));
+                    }
+                    // tight tolerances as the energy would drift otherwise
+                    let mut stepper = Dop853::new(
+                        self.clone(),
+                        0.0,
+                        self.integration_time(),
+                        ODE_STEP_SIZE,
+                        y0.to_owned(),
+                        1e-9,
+                        1e-9,
+                    );
+                    stepper.integrate()?;
+                    let mut iter = stepper.y_out().to_owned().into_iter();
+                    iter.next();
+                    Ok(iter)
+                }
+            }
+        )*
+    };
+}
+
+implement_integrator_hamiltonian! {
+    HenonHeiles,
+    DoublePendulum,
+    RestrictedThreeBody
+}
+
+/// Momentum p with a p² + b p + c = 0 that is closest to the previous momentum.
+fn closest_root(
+    (a, b, c): (ChaosFloat, ChaosFloat, ChaosFloat),
+    p_prev: ChaosFloat,
+) -> Option<ChaosFloat> {
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 || a == 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let (p_plus, p_minus) = ((-b + root) / (2.0 * a), (-b - root) / (2.0 * a));
+    if (p_plus - p_prev).abs() <= (p_minus - p_prev).abs() {
+        Some(p_plus)
+    } else {
+        Some(p_minus)
+    }
+}
+
+impl HenonHeiles {
+    fn potential(&self, x: ChaosFloat, y: ChaosFloat) -> ChaosFloat {
+        (x * x + y * y) / 2.0 + self.lambda * (x * x * y - y.powi(3) / 3.0)
+    }
+}
+impl System<Time, State4> for HenonHeiles {
+    fn system(&self, _t: Time, v: &State4, dv: &mut State4) {
+        let (x, y, px, py) = (v[0], v[1], v[2], v[3]);
+        dv[0] = px;
+        dv[1] = py;
+        dv[2] = -x - 2.0 * self.lambda * x * y;
+        dv[3] = -y - self.lambda * (x * x - y * y);
+    } // Hénon-Heiles system on Wikipedia (with defaults)
+}
+impl Hamiltonian for HenonHeiles {
+    type State = State4;
+    const ENERGY_LABEL: &'static str = "Energy E";
+    fn energy(&self, v: &State4) -> ChaosFloat {
+        (v[2] * v[2] + v[3] * v[3]) / 2.0 + self.potential(v[0], v[1])
+    }
+    fn with_energy(&self, v: &State4, energy: ChaosFloat) -> Option<State4> {
+        let kinetic = energy - self.potential(v[0], v[1]) - v[2] * v[2] / 2.0;
+        let py = closest_root((0.5, 0.0, -kinetic), v[3])?;
+        Some(State4::new(v[0], v[1], v[2], py))
+    }
+}
+impl Default for HenonHeiles {
+    fn default() -> Self {
+        Self { lambda: 1.0 }
+    }
+}
+impl ChaosDescription for HenonHeiles {
+    fn description(&self) -> String {
+        "The Hénon-Heiles system models the motion of a star around the center of a galaxy with the state (x, y, px, py). Its energy E is conserved, so fix the energy of all initial states and use a symplectic integration scheme. For λ=1 the orbits stay bounded for E < 1/6. The Poincaré section x=0 with increasing x shows regular tori in (y, py) for E=1/12 and mostly chaotic orbits for E=1/6.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/H%C3%A9non%E2%80%93Heiles_system"
+    }
+}
+impl ChaosFormula for HenonHeiles {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "dx= px",
+            "dy= py",
+            "dpx= -x - 2 λ x y",
+            "dpy= -y - λ (x² - y²)",
+            "E= (px² + py²) / 2 + (x² + y²) / 2 + λ (x² y - y³ / 3)",
+        ]
+    }
+}
+
+impl DoublePendulum {
+    fn potential(&self, theta1: ChaosFloat, theta2: ChaosFloat) -> ChaosFloat {
+        -(self.m1 + self.m2) * self.g * self.l1 * theta1.cos()
+            - self.m2 * self.g * self.l2 * theta2.cos()
+    }
+    // kinetic energy a p2² + b p2 + c as polynomial in the second momentum
+    fn kinetic_coefficients(&self, v: &State4) -> (ChaosFloat, ChaosFloat, ChaosFloat) {
+        let (m1, m2, l1, l2) = (self.m1, self.m2, self.l1, self.l2);
+        let delta = v[0] - v[1];
+        let denominator = 2.0 * m2 * l1 * l1 * l2 * l2 * (m1 + m2 * delta.sin().powi(2));
+        (
+            (m1 + m2) * l1 * l1 / denominator,
+            -2.0 * m2 * l1 * l2 * v[2] * delta.cos() / denominator,
+            m2 * l2 * l2 * v[2] * v[2] / denominator,
+        )
+    }
+}
+impl System<Time, State4> for DoublePendulum {
+    fn system(&self, _t: Time, v: &State4, dv: &mut State4) {
+        let (m1, m2, l1, l2, g) = (self.m1, self.m2, self.l1, self.l2, self.g);
+        let (theta1, theta2, p1, p2) = (v[0], v[1], v[2], v[3]);
+        let delta = theta1 - theta2;
+        let (sin_delta, cos_delta) = delta.sin_cos();
+        let mass_term = m1 + m2 * sin_delta * sin_delta;
+        let c1 = p1 * p2 * sin_delta / (l1 * l2 * mass_term);
+        let c2 = (m2 * l2 * l2 * p1 * p1 + (m1 + m2) * l1 * l1 * p2 * p2
+            - 2.0 * m2 * l1 * l2 * p1 * p2 * cos_delta)
+            * (2.0 * delta).sin()
+            / (2.0 * l1 * l1 * l2 * l2 * mass_term * mass_term);
+        dv[0] = (l2 * p1 - l1 * p2 * cos_delta) / (l1 * l1 * l2 * mass_term);
+        dv[1] = (l1 * (m1 + m2) * p2 - l2 * m2 * p1 * cos_delta) / (l1 * l2 * l2 * m2 * mass_term);
+        dv[2] = -(m1 + m2) * g * l1 * theta1.sin() - c1 + c2;
+        dv[3] = -m2 * g * l2 * theta2.sin() + c1 - c2;
+    } // Double pendulum on Wikipedia for point masses
+}
+impl Hamiltonian for DoublePendulum {
+    type State = State4;
+    const ENERGY_LABEL: &'static str = "Energy E";
+    fn energy(&self, v: &State4) -> ChaosFloat {
+        let (a, b, c) = self.kinetic_coefficients(v);
+        a * v[3] * v[3] + b * v[3] + c + self.potential(v[0], v[1])
+    }
+    fn with_energy(&self, v: &State4, energy: ChaosFloat) -> Option<State4> {
+        let (a, b, c) = self.kinetic_coefficients(v);
+        let p2 = closest_root((a, b, c + self.potential(v[0], v[1]) - energy), v[3])?;
+        Some(State4::new(v[0], v[1], v[2], p2))
+    }
+}
+impl Default for DoublePendulum {
+    fn default() -> Self {
+        Self {
+            m1: 1.0,
+            m2: 1.0,
+            l1: 1.0,
+            l2: 1.0,
+            g: 9.81,
+        }
+    }
+}
+impl ChaosDescription for DoublePendulum {
+    fn description(&self) -> String {
+        "The double pendulum consists of two point masses m₁ and m₂ on massless rods of the lengths l₁ and l₂. The state (θ₁, θ₂, p₁, p₂) holds the angles to the vertical and their canonical momenta. Small energies give regular swinging, while the motion becomes chaotic as soon as the pendulum can flip over. Fix the energy of all initial states and look at the Poincaré section θ₁=0 with increasing θ₁.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Double_pendulum"
+    }
+}
+impl ChaosFormula for DoublePendulum {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "Δ= θ₁ - θ₂, M= m₁ + m₂ sin²Δ",
+            "dθ₁= (l₂ p₁ - l₁ p₂ cos Δ) / (l₁² l₂ M)",
+            "dθ₂= (l₁ (m₁ + m₂) p₂ - l₂ m₂ p₁ cos Δ) / (l₁ l₂² m₂ M)",
+            "dp₁= -(m₁ + m₂) g l₁ sin θ₁ - C₁ + C₂",
+            "dp₂= -m₂ g l₂ sin θ₂ + C₁ - C₂",
+            "C₁= p₁ p₂ sin Δ / (l₁ l₂ M)",
+            "C₂= (m₂ l₂² p₁² + (m₁ + m₂) l₁² p₂² - 2 m₂ l₁ l₂ p₁ p₂ cos Δ) sin 2Δ / (2 l₁² l₂² M²)",
+        ]
+    }
+}
+
+impl RestrictedThreeBody {
+    // effective potential Ω of the rotating frame
+    fn omega(&self, x: ChaosFloat, y: ChaosFloat) -> ChaosFloat {
+        let mu = self.mu;
+        let r1 = (x + mu).hypot(y);
+        let r2 = (x - 1.0 + mu).hypot(y);
+        (x * x + y * y) / 2.0 + (1.0 - mu) / r1 + mu / r2
+    }
+}
+impl System<Time, State4> for RestrictedThreeBody {
+    fn system(&self, _t: Time, v: &State4, dv: &mut State4) {
+        let mu = self.mu;
+        let (x, y, vx, vy) = (v[0], v[1], v[2], v[3]);
+        let r1_cubed = (x + mu).hypot(y).powi(3);
+        let r2_cubed = (x - 1.0 + mu).hypot(y).powi(3);
+        dv[0] = vx;
+        dv[1] = vy;
+        dv[2] = 2.0 * vy + x - (1.0 - mu) * (x + mu) / r1_cubed - mu * (x - 1.0 + mu) / r2_cubed;
+        dv[3] = -2.0 * vx + y - (1.0 - mu) * y / r1_cubed - mu * y / r2_cubed;
+    } // Circular restricted three-body problem on Scholarpedia (Earth-Moon mass ratio)
+}
+impl Hamiltonian for RestrictedThreeBody {
+    type State = State4;
+    const ENERGY_LABEL: &'static str = "Jacobi constant C";
+    fn energy(&self, v: &State4) -> ChaosFloat {
+        2.0 * self.omega(v[0], v[1]) - v[2] * v[2] - v[3] * v[3]
+    }
+    fn with_energy(&self, v: &State4, energy: ChaosFloat) -> Option<State4> {
+        let speed_squared = 2.0 * self.omega(v[0], v[1]) - energy - v[2] * v[2];
+        let vy = closest_root((1.0, 0.0, -speed_squared), v[3])?;
+        Some(State4::new(v[0], v[1], v[2], vy))
+    }
+}
+impl Default for RestrictedThreeBody {
+    fn default() -> Self {
+        Self { mu: 0.01215 }
+    }
+}
+impl ChaosDescription for RestrictedThreeBody {
+    fn description(&self) -> String {
+        "The planar circular restricted three-body problem follows a small body in the rotating frame of two primaries with the mass ratio μ, which orbit their center of mass on circles. The large primary sits at (-μ, 0) and the small one at (1 - μ, 0), the default is the Earth-Moon system. The state (x, y, vx, vy) conserves the Jacobi constant C instead of an energy. Larger values of C confine the body to smaller Hill regions around the primaries. Fix C for all initial states and look at the Poincaré section y=0 with increasing y.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "http://www.scholarpedia.org/article/Three_body_problem"
+    }
+}
+impl ChaosFormula for RestrictedThreeBody {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "dx= vx",
+            "dy= vy",
+            "dvx= 2 vy + ∂Ω/∂x",
+            "dvy= -2 vx + ∂Ω/∂y",
+            "Ω= (x² + y²) / 2 + (1 - μ) / r₁ + μ / r₂",
+            "C= 2 Ω - vx² - vy²",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn check_hamiltonian<T>(system: &T, v: State4, is_canonical: bool)
+    where
+        T: System<Time, State4> + Hamiltonian<State = State4>,
+    {
+        let mut dv = State4::zeros();
+        system.system(0.0, &v, &mut dv);
+        let eps = 1e-6;
+        let gradient = State4::from_fn(|i, _| {
+            let shift = State4::from_fn(|j, _| if i == j { eps } else { 0.0 });
+            (system.energy(&(v + shift)) - system.energy(&(v - shift))) / (2.0 * eps)
+        });
+        assert!(gradient.dot(&dv).abs() < 1e-6, "The energy is conserved");
+        if is_canonical {
+            // dq = ∂H/∂p and dp = -∂H/∂q
+            let expected = State4::new(gradient[2], gradient[3], -gradient[0], -gradient[1]);
+            assert!((dv - expected).amax() < 1e-6, "{dv} != {expected}");
+        }
+        // symplectic integration keeps the energy close to its initial value
+        [
+            IntegrationScheme::ImplicitMidpoint,
+            IntegrationScheme::GaussLegendre,
+        ]
+        .into_iter()
+        .for_each(|scheme| {
+            let energy = system.energy(&v);
+            symplectic_integration(system, &v, scheme, 20.0).for_each(|state| {
+                assert!((system.energy(&state) - energy).abs() < 1e-3 * (1.0 + energy.abs()));
+            });
+        });
+        let v_fixed = system
+            .with_energy(&v, system.energy(&v) + 0.01)
+            .expect("The energy surface is reachable");
+        assert!((system.energy(&v_fixed) - system.energy(&v) - 0.01).abs() < 1e-9);
+        assert_eq!(v_fixed.fixed_rows::<3>(0), v.fixed_rows::<3>(0));
+    }
+    #[test]
+    fn test_hamiltonian_systems() {
+        check_hamiltonian(
+            &HenonHeiles::default(),
+            State4::new(0.0, 0.1, 0.3, 0.2),
+            true,
+        );
+        check_hamiltonian(
+            &DoublePendulum::default(),
+            State4::new(1.0, -0.5, 0.3, 0.2),
+            true,
+        );
+        // the velocities of the rotating frame are no canonical momenta
+        check_hamiltonian(
+            &RestrictedThreeBody::default(),
+            State4::new(0.5, 0.0, 0.0, 1.1),
+            false,
+        );
+        // no real momentum below the potential energy
+        assert!(HenonHeiles::default()
+            .with_energy(&State4::new(0.0, 0.5, 0.0, 0.0), 0.0)
+            .is_none());
+    }
+}
@@ -0,0 +1,384 @@
+use super::chaotic_function_configs::*;
+use super::discrete_maps::DiscreteMap;
+use crate::chaos::data::*;
+use crate::chaos::labels::{ChaosDescription, ChaosFormula};
+use rand::Rng;
+use rand_distr::Distribution;
+use std::sync::OnceLock;
+
+/// Affine map (x, y) -> (a x + b y + e, c x + d y + f) that is chosen with weight p.
+#[derive(PartialEq, Clone, Copy, Debug)]
+struct AffineMap {
+    a: ChaosFloat,
+    b: ChaosFloat,
+    c: ChaosFloat,
+    d: ChaosFloat,
+    e: ChaosFloat,
+    f: ChaosFloat,
+    p: ChaosFloat,
+}
+
+impl AffineMap {
+    const fn new(coefficients: [ChaosFloat; 7]) -> Self {
+        let [a, b, c, d, e, f, p] = coefficients;
+        Self {
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+            p,
+        }
+    }
+    /// Contraction by r towards the fixed point (x, y) with equal weights.
+    fn contraction(r: ChaosFloat, (x, y): (ChaosFloat, ChaosFloat)) -> Self {
+        Self::new([r, 0.0, 0.0, r, (1.0 - r) * x, (1.0 - r) * y, 1.0])
+    }
+}
+
+/// Affine maps of an IFS with the cumulative weights of the random map choice.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Ifs<const N: usize> {
+    maps: [AffineMap; N],
+    cumulative_weights: [ChaosFloat; N],
+}
+
+impl<const N: usize> Ifs<N> {
+    fn new(maps: [AffineMap; N]) -> Self {
+        let mut total_weight = 0.0;
+        let cumulative_weights = maps.map(|map| {
+            total_weight += map.p.max(0.0);
+            total_weight
+        });
+        Self {
+            maps,
+            cumulative_weights,
+        }
+    }
+    /// One step of the chaos game, the map i is chosen with the probability pᵢ relative to the sum of all weights.
+    fn chaos_game(&self, v: &mut State3) {
+        let total_weight = self.cumulative_weights.last().copied().unwrap_or(0.0);
+        if total_weight <= 0.0 {
+            v.fill(ChaosFloat::NAN);
+            return;
+        }
+        let r_gen: rand_distr::Uniform<ChaosFloat> = rand_distr::Uniform::new(0.0, total_weight);
+        let r = r_gen.sample(&mut rand::thread_rng());
+        // the last map with a positive weight catches rounding errors of r
+        let last = self
+            .cumulative_weights
+            .partition_point(|weight| *weight < total_weight);
+        let index = self
+            .cumulative_weights
+            .partition_point(|weight| *weight <= r)
+            .min(last);
+        apply_map(v, &self.maps[index], index, N);
+    }
+}
+
+/// Chaos game of contractions by r towards the given points, which are chosen with equal probability.
+fn contraction_game(v: &mut State3, r: ChaosFloat, points: &[(ChaosFloat, ChaosFloat)]) {
+    let index = rand::thread_rng().gen_range(0..points.len());
+    apply_map(
+        v,
+        &AffineMap::contraction(r, points[index]),
+        index,
+        points.len(),
+    );
+}
+
+/// Applies the map i to the state (x, y, c) with c as the colour coordinate of flame renderings.
+/// The IFS draws the plane (x, y), the third state only holds the colour and is shown by the
+/// feature colouring mode. The colour moves half the way towards the colour i / (n - 1) of the map.
+fn apply_map(v: &mut State3, map: &AffineMap, index: usize, num_maps: usize) {
+    let (x, y, c) = (v[0], v[1], v[2]);
+    let map_color = if num_maps > 1 {
+        index as ChaosFloat / (num_maps - 1) as ChaosFloat
+    } else {
+        0.0
+    };
+    v[0] = map.a * x + map.b * y + map.e;
+    v[1] = map.c * x + map.d * y + map.f;
+    v[2] = (c + map_color) / 2.0;
+}
+
+const FORMULA_IFS: [&str; 4] = [
+    "i= random map with probability pᵢ",
+    "x= aᵢ x + bᵢ y + eᵢ",
+    "y= cᵢ x + dᵢ y + fᵢ",
+    "c= (c + i / (n - 1)) / 2",
+];
+
+const BARNSLEY_FERN_MAPS: [AffineMap; 4] = [
+    AffineMap::new([0.0, 0.0, 0.0, 0.16, 0.0, 0.0, 0.01]),
+    AffineMap::new([0.85, 0.04, -0.04, 0.85, 0.0, 1.6, 0.85]),
+    AffineMap::new([0.2, -0.26, 0.23, 0.22, 0.0, 1.6, 0.07]),
+    AffineMap::new([-0.15, 0.28, 0.26, 0.24, 0.0, 0.44, 0.07]),
+];
+static BARNSLEY_FERN: OnceLock<Ifs<4>> = OnceLock::new();
+
+impl DiscreteMap for BarnsleyFern {
+    type State = State3;
+    fn execute(&self, v: &mut State3, _t: &Time) {
+        BARNSLEY_FERN
+            .get_or_init(|| Ifs::new(BARNSLEY_FERN_MAPS))
+            .chaos_game(v);
+    } // Barnsley fern on Wikipedia (with defaults)
+}
+impl Default for BarnsleyFern {
+    fn default() -> Self {
+        Self {}
+    }
+}
+impl ChaosDescription for BarnsleyFern {
+    fn description(&self) -> String {
+        "The Barnsley fern is the attractor of an iterated function system (IFS) of four affine maps, that was described by the mathematician Michael Barnsley. The chaos game picks one map per step at random, so any start state is drawn onto the fern. The third state c is the colour coordinate of flame renderings. It remembers the recently applied maps and colours the stem, the leaflets and the two lowest pinnae differently if the points are coloured by c.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Barnsley_fern"
+    }
+}
+impl ChaosFormula for BarnsleyFern {
+    fn formula(&self) -> &[&'static str] {
+        &FORMULA_IFS
+    }
+}
+
+// corners of the equilateral triangle with side length 1, √3/2 is its height
+const TRIANGLE_CORNERS: [(ChaosFloat, ChaosFloat); 3] =
+    [(0.0, 0.0), (1.0, 0.0), (0.5, 0.866_025_403_784_438_6)];
+
+impl DiscreteMap for SierpinskiTriangle {
+    type State = State3;
+    fn execute(&self, v: &mut State3, _t: &Time) {
+        contraction_game(v, self.r, &TRIANGLE_CORNERS);
+    } // Chaos game on Wikipedia (with defaults)
+}
+impl Default for SierpinskiTriangle {
+    fn default() -> Self {
+        Self { r: 0.5 }
+    }
+}
+impl ChaosDescription for SierpinskiTriangle {
+    fn description(&self) -> String {
+        "The chaos game draws the Sierpinski triangle by jumping the ratio 1 - r towards a randomly chosen corner of an equilateral triangle. For r=0.5 the three copies of the triangle just touch, smaller ratios leave a Cantor dust and larger ratios let the copies overlap. The third state c is the colour coordinate of flame renderings, colour by c to separate the sub triangles of each corner.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Chaos_game"
+    }
+}
+impl ChaosFormula for SierpinskiTriangle {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "i= random corner (xᵢ, yᵢ) of (0, 0), (1, 0), (0.5, √3/2)",
+            "x= r x + (1 - r) xᵢ",
+            "y= r y + (1 - r) yᵢ",
+            "c= (c + i / 2) / 2",
+        ]
+    }
+}
+
+// corners and edge midpoints of the unit square
+const CARPET_POINTS: [(ChaosFloat, ChaosFloat); 8] = [
+    (0.0, 0.0),
+    (0.5, 0.0),
+    (1.0, 0.0),
+    (0.0, 0.5),
+    (1.0, 0.5),
+    (0.0, 1.0),
+    (0.5, 1.0),
+    (1.0, 1.0),
+];
+
+impl DiscreteMap for SierpinskiCarpet {
+    type State = State3;
+    fn execute(&self, v: &mut State3, _t: &Time) {
+        contraction_game(v, self.r, &CARPET_POINTS);
+    } // Sierpinski carpet on Wikipedia (with defaults)
+}
+impl Default for SierpinskiCarpet {
+    fn default() -> Self {
+        Self { r: 1.0 / 3.0 }
+    }
+}
+impl ChaosDescription for SierpinskiCarpet {
+    fn description(&self) -> String {
+        "The Sierpinski carpet is the attractor of eight contractions of the unit square towards its corners and edge midpoints. For r=1/3 the eight copies tile the square without its middle third. The third state c is the colour coordinate of flame renderings.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Sierpi%C5%84ski_carpet"
+    }
+}
+impl ChaosFormula for SierpinskiCarpet {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "i= random point (xᵢ, yᵢ) of {0, 0.5, 1}² without (0.5, 0.5)",
+            "x= r x + (1 - r) xᵢ",
+            "y= r y + (1 - r) yᵢ",
+            "c= (c + i / 7) / 2",
+        ]
+    }
+}
+
+const HEIGHWAY_DRAGON_MAPS: [AffineMap; 2] = [
+    AffineMap::new([0.5, -0.5, 0.5, 0.5, 0.0, 0.0, 0.5]),
+    AffineMap::new([-0.5, -0.5, 0.5, -0.5, 1.0, 0.0, 0.5]),
+];
+static HEIGHWAY_DRAGON: OnceLock<Ifs<2>> = OnceLock::new();
+
+impl DiscreteMap for HeighwayDragon {
+    type State = State3;
+    fn execute(&self, v: &mut State3, _t: &Time) {
+        HEIGHWAY_DRAGON
+            .get_or_init(|| Ifs::new(HEIGHWAY_DRAGON_MAPS))
+            .chaos_game(v);
+    } // Dragon curve on Wikipedia
+}
+impl Default for HeighwayDragon {
+    fn default() -> Self {
+        Self {}
+    }
+}
+impl ChaosDescription for HeighwayDragon {
+    fn description(&self) -> String {
+        "The Heighway dragon is the limit of folding a paper strip in half over and over again. As an iterated function system it consists of two similarity maps in the complex plane, which rotate by 45° and 135° and scale by 1/√2. Colour by the third state c to see the two halves of the dragon.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Dragon_curve"
+    }
+}
+impl ChaosFormula for HeighwayDragon {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "z= (1 + i) z / 2 or 1 - (1 - i) z / 2",
+            "c= (c + 0) / 2 or (c + 1) / 2",
+        ]
+    }
+}
+
+impl<const N: usize> Default for Ifs<N> {
+    fn default() -> Self {
+        // without weights the chaos game has no map to choose
+        Self::new([AffineMap::new([0.0; 7]); N])
+    }
+}
+
+/// Assembles the four maps once per configuration instead of once per step.
+pub fn check_affine_ifs(conf: &mut AffineIfs) {
+    conf.ifs = Ifs::new(conf.maps());
+}
+
+impl AffineIfs {
+    fn maps(&self) -> [AffineMap; 4] {
+        [
+            AffineMap::new([
+                self.a1, self.b1, self.c1, self.d1, self.e1, self.f1, self.p1,
+            ]),
+            AffineMap::new([
+                self.a2, self.b2, self.c2, self.d2, self.e2, self.f2, self.p2,
+            ]),
+            AffineMap::new([
+                self.a3, self.b3, self.c3, self.d3, self.e3, self.f3, self.p3,
+            ]),
+            AffineMap::new([
+                self.a4, self.b4, self.c4, self.d4, self.e4, self.f4, self.p4,
+            ]),
+        ]
+    }
+}
+impl DiscreteMap for AffineIfs {
+    type State = State3;
+    fn execute(&self, v: &mut State3, _t: &Time) {
+        self.ifs.chaos_game(v);
+    } // Maple leaf of Barnsley's Fractals Everywhere (with defaults)
+}
+impl Default for AffineIfs {
+    fn default() -> Self {
+        let mut conf = Self {
+            a1: 0.14,
+            b1: 0.01,
+            c1: 0.0,
+            d1: 0.51,
+            e1: -0.08,
+            f1: -1.31,
+            p1: 0.1,
+            a2: 0.43,
+            b2: 0.52,
+            c2: -0.45,
+            d2: 0.5,
+            e2: 1.49,
+            f2: -0.75,
+            p2: 0.35,
+            a3: 0.45,
+            b3: -0.49,
+            c3: 0.47,
+            d3: 0.47,
+            e3: -1.62,
+            f3: -0.74,
+            p3: 0.35,
+            a4: 0.49,
+            b4: 0.0,
+            c4: 0.0,
+            d4: 0.51,
+            e4: 0.02,
+            f4: 1.62,
+            p4: 0.2,
+            ifs: Default::default(),
+        };
+        conf.par_range_check();
+        conf
+    }
+}
+impl ChaosDescription for AffineIfs {
+    fn description(&self) -> String {
+        "An iterated function system (IFS) of up to four user defined affine maps, more maps are not supported. Use the weights to switch maps off for fewer maps. Each step of the chaos game applies map i with the probability pᵢ relative to the sum of all weights, a weight of 0 switches the map off. The defaults draw a maple leaf. As long as all maps are contractions, the points converge onto the attractor of the IFS. The attractor lies in the plane of the first two states x and y. The third state c is only the colour coordinate of flame renderings, choose the feature c in the colouring mode to colour each part of the attractor by the maps that produced it.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Iterated_function_system"
+    }
+}
+impl ChaosFormula for AffineIfs {
+    fn formula(&self) -> &[&'static str] {
+        &FORMULA_IFS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_chaos_game() {
+        let mut v = State3::new(0.3, 0.2, 0.5);
+        let map = SierpinskiTriangle::default();
+        for _ in 0..1000 {
+            map.execute(&mut v, &0.0);
+            // the triangle is invariant under all three contractions
+            assert!(v[1] >= -1e-12 && v[1] <= 3.0_f64.sqrt() * v[0].min(1.0 - v[0]) + 1e-12);
+            assert!((0.0..=1.0).contains(&v[2]));
+        }
+        let mut v = State3::new(0.0, 0.0, 0.0);
+        for _ in 0..1000 {
+            BarnsleyFern::default().execute(&mut v, &0.0);
+            // the fern lies within -2.2 < x < 2.7 and 0 <= y < 10
+            assert!(v[0] > -2.2 && v[0] < 2.7 && v[1] >= 0.0 && v[1] < 10.0);
+        }
+        let map = AffineIfs::default();
+        assert_eq!(map.ifs, Ifs::new(map.maps()));
+        let mut v = State3::new(0.0, 0.0, 0.0);
+        let mut map = AffineIfs {
+            p1: 0.0,
+            p2: 0.0,
+            p3: 0.0,
+            ..map
+        };
+        map.par_range_check();
+        map.execute(&mut v, &0.0);
+        // only the fourth map with the colour 3 / 3 is left
+        assert_eq!(v, State3::new(0.02, 1.62, 0.5));
+        let mut map = AffineIfs { p4: 0.0, ..map };
+        map.par_range_check();
+        map.execute(&mut v, &0.0);
+        assert!(v.iter().all(|x| x.is_nan()));
+    }
+}
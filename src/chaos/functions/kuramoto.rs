@@ -0,0 +1,143 @@
+use super::chaotic_function_configs::Kuramoto;
+use super::differential_eq::{rk4_step, OdeSolverTrait, NUM_RK4_SUBSTEPS, ODE_STEP_SIZE};
+use crate::chaos::data::*;
+use crate::chaos::labels::{ChaosDescription, ChaosFormula};
+use std::f64::consts::TAU;
+
+impl Default for Kuramoto {
+    fn default() -> Self {
+        Self { k: 1.0 }
+    }
+}
+impl ChaosDescription for Kuramoto {
+    fn description(&self) -> String {
+        "The Kuramoto model describes a population of phase oscillators that are coupled through the sine of their phase differences. Every sample is one oscillator with the state (θ, ω) of its phase and its natural frequency, so the initial distributions draw the phases and the frequency spread. Above a critical coupling strength 'K' the oscillators lock to a common rhythm and the order parameter r grows from about 0 towards 1.".into()
+    }
+    fn reference(&self) -> &'static str {
+        "https://wikipedia.org/wiki/Kuramoto_model"
+    }
+}
+impl ChaosFormula for Kuramoto {
+    fn formula(&self) -> &[&'static str] {
+        &[
+            "dθᵢ= ωᵢ + K / N ∑ⱼ Aᵢⱼ sin(θⱼ - θᵢ)",
+            "dωᵢ= 0",
+            "r e^(iψ)= 1 / N ∑ⱼ e^(iθⱼ)",
+        ]
+    }
+}
+
+/// Order parameter r and mean phase ψ of all oscillators that did not escape.
+pub fn order_parameter(states: &[Option<State2>]) -> Option<(ChaosFloat, ChaosFloat)> {
+    let phases: Vec<ChaosFloat> = states.iter().flatten().map(|state| state[0]).collect();
+    if phases.is_empty() {
+        return None;
+    }
+    let n = phases.len() as ChaosFloat;
+    let re = phases.iter().map(|theta| theta.cos()).sum::<ChaosFloat>() / n;
+    let im = phases.iter().map(|theta| theta.sin()).sum::<ChaosFloat>() / n;
+    Some((re.hypot(im), im.atan2(re)))
+}
+
+/// Integrates all samples as one network of phase oscillators.
+#[derive(Clone)]
+pub struct KuramotoSolver {
+    system: Kuramoto,
+    // all-to-all coupling without an adjacency matrix
+    coupling: Option<CouplingMatrix>,
+}
+
+impl KuramotoSolver {
+    pub fn new(system: Kuramoto) -> Self {
+        Self {
+            system,
+            coupling: None,
+        }
+    }
+
+    pub fn set_coupling(&mut self, coupling: CouplingMatrix) {
+        self.coupling = Some(coupling);
+    }
+
+    // the oscillators are the samples at `nodes`, escaped samples are left out
+    fn phase_velocities(
+        &self,
+        nodes: &[usize],
+        phases: &[ChaosFloat],
+        frequencies: &[ChaosFloat],
+    ) -> Vec<ChaosFloat> {
+        let k = self.system.k / nodes.len() as ChaosFloat;
+        match &self.coupling {
+            Some(coupling) => phases
+                .iter()
+                .zip(frequencies.iter())
+                .zip(nodes.iter())
+                .map(|((theta_i, omega_i), i)| {
+                    let interaction: ChaosFloat = phases
+                        .iter()
+                        .zip(nodes.iter())
+                        .map(|(theta_j, j)| coupling.weight(*i, *j) * (theta_j - theta_i).sin())
+                        .sum();
+                    omega_i + k * interaction
+                })
+                .collect(),
+            None => {
+                // mean field: ∑ⱼ sin(θⱼ - θᵢ) = S cos(θᵢ) - C sin(θᵢ)
+                let sum_sin: ChaosFloat = phases.iter().map(|theta| theta.sin()).sum();
+                let sum_cos: ChaosFloat = phases.iter().map(|theta| theta.cos()).sum();
+                phases
+                    .iter()
+                    .zip(frequencies.iter())
+                    .map(|(theta, omega)| {
+                        omega + k * (sum_sin * theta.cos() - sum_cos * theta.sin())
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl OdeSolverTrait for KuramotoSolver {
+    type State = State2;
+    fn execute(&mut self, states: &mut [Option<State2>], num_executions: usize) {
+        let nodes: Vec<usize> = states
+            .iter()
+            .enumerate()
+            .filter_map(|(i, state)| state.as_ref().map(|_| i))
+            .collect();
+        if nodes.is_empty() {
+            return;
+        }
+        let mut phases: Vec<ChaosFloat> = nodes
+            .iter()
+            .filter_map(|i| states[*i].map(|state| state[0]))
+            .collect();
+        let frequencies: Vec<ChaosFloat> = nodes
+            .iter()
+            .filter_map(|i| states[*i].map(|state| state[1]))
+            .collect();
+        let dt = ODE_STEP_SIZE / NUM_RK4_SUBSTEPS as Time;
+        for _ in 0..(num_executions * NUM_RK4_SUBSTEPS) {
+            rk4_step(&mut phases, 0.0, dt, |_, phases| {
+                self.phase_velocities(&nodes, phases, &frequencies)
+            });
+        }
+        nodes
+            .iter()
+            .zip(phases.iter().zip(frequencies.iter()))
+            .for_each(|(i, (theta, omega))| {
+                let state = State2::new(theta.rem_euclid(TAU), *omega);
+                states[*i] = state.is_valid().then_some(state);
+            });
+    }
+
+    fn reinit_states(
+        &mut self,
+        _all_states: &mut [Option<State2>],
+        _new_state_indices: Vec<usize>,
+    ) {
+        // the network has no per sample integration state
+    }
+
+    fn initial_states(&mut self, _states: &mut [Option<State2>]) {}
+}
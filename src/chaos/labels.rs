@@ -61,15 +61,23 @@ pub const LABELS_COMPLEX: [&str; NUM_DIMS_FRACTALCOMPLEX] = [
     "fractal iteration",
     "z real",
     "z imaginary",
+    "converged root",
+];
+pub const LABELS_DUAL: [&str; NUM_DIMS_FRACTALDUAL] = [
+    "c real",
+    "c ε",
+    "fractal iteration",
+    "z real",
+    "z ε",
+    "converged root",
 ];
-pub const LABELS_DUAL: [&str; NUM_DIMS_FRACTALDUAL] =
-    ["c real", "c ε", "fractal iteration", "z real", "z ε"];
 pub const LABELS_PERPLEX: [&str; NUM_DIMS_FRACTALPERPLEX] = [
     "c time",
     "c space",
     "fractal iteration",
     "z time",
     "z space",
+    "converged root",
 ];
 pub const LABELS_QUATERNION: [&str; NUM_DIMS_FRACTALQUATERNION] = [
     "c w",
@@ -81,12 +89,14 @@ pub const LABELS_QUATERNION: [&str; NUM_DIMS_FRACTALQUATERNION] = [
     "z i",
     "z j",
     "z k",
+    "converged root",
 ];
 pub const LABELS_SHORT_COMPLEX: [&str; NUM_DIMS_FRACTALCOMPLEX] =
-    ["c re", "c im", "iter", "z re", "z im"];
-pub const LABELS_SHORT_DUAL: [&str; NUM_DIMS_FRACTALDUAL] = ["c re", "c ε", "iter", "z re", "z ε"];
+    ["c re", "c im", "iter", "z re", "z im", "root"];
+pub const LABELS_SHORT_DUAL: [&str; NUM_DIMS_FRACTALDUAL] =
+    ["c re", "c ε", "iter", "z re", "z ε", "root"];
 pub const LABELS_SHORT_PERPLEX: [&str; NUM_DIMS_FRACTALPERPLEX] =
-    ["c t", "c x", "iter", "z t", "z x"];
+    ["c t", "c x", "iter", "z t", "z x", "root"];
 pub const LABELS_SHORT_QUATERNION: [&str; NUM_DIMS_FRACTALQUATERNION] = [
-    "c w", "c i", "c j", "c k", "iter", "z w", "z i", "z j", "z k",
+    "c w", "c i", "c j", "c k", "iter", "z w", "z i", "z j", "z k", "root",
 ];
@@ -4,7 +4,12 @@ mod main_panels;
 use self::conf_panels::*;
 pub use self::egui_utils::*;
 use self::main_panels::*;
-use crate::chaos::{benchmark::ChaosInitSchema, *};
+use crate::chaos::{
+    analysis::{ParameterPlaneSchema, PointSource, TrajectoryConf, TrajectorySchema},
+    benchmark::ChaosInitSchema,
+    data::{DistributionDimensions, ImportedSeries},
+    *,
+};
 use crate::gui::tooltips::*;
 use anyhow::{bail, Error};
 use egui::{
@@ -20,8 +25,23 @@ pub struct ChaosApp {
     open_conf_panel: ConfPanel,
     initial_panel: InitialPanel,
     execute_panel: ExecutionPanel,
+    #[serde(skip)] // avoid saving imported data
+    import_panel: ImportPanel,
     plot_panel: PlotPanel,
     benchmark_panel: BenchmarkPanel,
+    parameter_plane_panel: ParameterPlanePanel,
+    spectrum_panel: SpectrumPanel,
+    dimension_panel: DimensionPanel,
+    basin_panel: BasinPanel,
+    recurrence_panel: RecurrencePanel,
+    entropy_panel: EntropyPanel,
+    density_panel: DensityPanel,
+    rotation_panel: RotationPanel,
+    embedding_panel: EmbeddingPanel,
+    lattice_panel: LatticePanel,
+    kuramoto_panel: KuramotoPanel,
+    network_panel: NetworkPanel,
+    control_panel: ControlPanel,
     open_main_panel: MainPanel,
     #[serde(skip)] // avoid saving ChaosData arrays
     chaos_controller: ChaosExecutionController,
@@ -40,6 +60,19 @@ impl PartialEq for ChaosApp {
         && self.initial_panel == other.initial_panel
         && self.execute_panel == other.execute_panel
         && self.benchmark_panel == other.benchmark_panel
+        && self.parameter_plane_panel == other.parameter_plane_panel
+        && self.spectrum_panel == other.spectrum_panel
+        && self.dimension_panel == other.dimension_panel
+        && self.basin_panel == other.basin_panel
+        && self.recurrence_panel == other.recurrence_panel
+        && self.entropy_panel == other.entropy_panel
+        && self.density_panel == other.density_panel
+        && self.rotation_panel == other.rotation_panel
+        && self.embedding_panel == other.embedding_panel
+        && self.lattice_panel == other.lattice_panel
+        && self.kuramoto_panel == other.kuramoto_panel
+        && self.network_panel == other.network_panel
+        && self.control_panel == other.control_panel
     }
 }
 
@@ -88,13 +121,35 @@ impl ChaosApp {
         }
     }
 
+    fn add_section_series(&mut self) {
+        if let Ok(data) = self.chaos_controller.get_section_data() {
+            self.plot_panel.add_point_series(data)
+        }
+    }
+
+    fn add_return_map_series(&mut self) {
+        if let Ok(data) = self.chaos_controller.get_return_map_data() {
+            self.plot_panel.add_return_map_series(data)
+        }
+    }
+
     fn chaos_data_loop(&mut self) -> Result<(), Error> {
-        if self.plot_panel.generate_new_data {
+        if let Some(series) = self.import_panel.import_toggle() {
+            self.import_chaos_data(series)?;
+        } else if self.plot_panel.generate_new_data {
             self.plot_panel.generate_new_data = false;
             self.generate_initial_chaos_data()?;
         } else if self.init_chaotic_function {
             self.init_chaotic_function = false;
             self.initialize_chaotic_functions()?;
+            self.update_energy_readout();
+        } else if let Some(energy) = self.execute_panel.energy_toggle() {
+            let energy_result = self.chaos_controller.set_energy(energy);
+            // trajectories jump onto the energy surface
+            self.plot_panel.reset_plot_trajectory();
+            self.add_point_series();
+            self.update_energy_readout();
+            energy_result?;
         } else if self.executes && self.plot_panel.check_frame_rate() {
             self.execute_chaotic_function()?;
         };
@@ -128,6 +183,18 @@ impl ChaosApp {
                     chaos_init.diff_system_vec = Some(diff_system_vec);
                     chaos_init.pars = (par, par_values);
                 }
+                SelectedChaoticFunction::ParameterPlaneDiscreteMaps(map_vec, pars_x, _) => {
+                    chaos_init.discrete_map_vec = Some(map_vec);
+                    chaos_init.pars = pars_x;
+                }
+                SelectedChaoticFunction::ParameterPlaneDifferentialSystems(
+                    diff_system_vec,
+                    pars_x,
+                    _,
+                ) => {
+                    chaos_init.diff_system_vec = Some(diff_system_vec);
+                    chaos_init.pars = pars_x;
+                }
                 SelectedChaoticFunction::Nothing => {
                     bail!("Cannot init chaotic function as it is not set in the execute panel!")
                 }
@@ -138,6 +205,149 @@ impl ChaosApp {
         Ok(())
     }
 
+    fn parameter_plane_loop(&mut self) -> Result<(), Error> {
+        if self.parameter_plane_panel.parameter_plane_toggle() {
+            let (discrete_map_vec, diff_system_vec, pars_x, pars_y) = match self
+                .execute_panel
+                .chosen_chaotic_function()
+            {
+                SelectedChaoticFunction::ParameterPlaneDiscreteMaps(map_vec, pars_x, pars_y) => {
+                    (Some(map_vec), None, pars_x, pars_y)
+                }
+                SelectedChaoticFunction::ParameterPlaneDifferentialSystems(
+                    diff_system_vec,
+                    pars_x,
+                    pars_y,
+                ) => (None, Some(diff_system_vec), pars_x, pars_y),
+                _ => {
+                    let error = "Select two parameter ranges in the execute panel to span a parameter plane!";
+                    self.parameter_plane_panel.set_error(String::from(error));
+                    bail!(error)
+                }
+            };
+            let schema = ParameterPlaneSchema {
+                num_samples: self.initial_panel.number_of_samples(),
+                init_distr: self.initial_panel.initial_distributions(),
+                discrete_map_vec,
+                diff_system_vec,
+                pars_x,
+                pars_y,
+                conf: self.parameter_plane_panel.conf(),
+            };
+            self.parameter_plane_panel.parameter_plane(schema);
+        };
+        Ok(())
+    }
+
+    fn trajectory_schema(
+        &mut self,
+        conf: TrajectoryConf,
+        observable_only: bool,
+    ) -> Result<TrajectorySchema, Error> {
+        // model-based analyses keep the system of the execute panel
+        let imported = self
+            .import_panel
+            .analysed_series()
+            .filter(|_| observable_only);
+        if let Some(series) = imported {
+            return Ok(TrajectorySchema {
+                num_samples: 1,
+                init_distr: Default::default(),
+                discrete_map_vec: None,
+                diff_system_vec: None,
+                pars: Default::default(),
+                conf,
+                imported: Some(series.clone()),
+            });
+        }
+        let (discrete_map_vec, diff_system_vec, pars) =
+            match self.execute_panel.chosen_chaotic_function() {
+                SelectedChaoticFunction::SingleDiscreteMap(map_vec) => {
+                    (Some(map_vec), None, Default::default())
+                }
+                SelectedChaoticFunction::SingleDifferentialSystem(diff_system_vec) => {
+                    (None, Some(diff_system_vec), Default::default())
+                }
+                SelectedChaoticFunction::ParametrizedDiscreteMaps(map_vec, par, par_values) => {
+                    (Some(map_vec), None, (par, par_values))
+                }
+                SelectedChaoticFunction::ParametrizedDifferentialSystems(
+                    diff_system_vec,
+                    par,
+                    par_values,
+                ) => (None, Some(diff_system_vec), (par, par_values)),
+                _ => bail!(
+                "Select a chaotic function with at most one parameter range in the execute panel!"
+            ),
+            };
+        Ok(TrajectorySchema {
+            num_samples: self.initial_panel.number_of_samples(),
+            init_distr: self.initial_panel.initial_distributions(),
+            discrete_map_vec,
+            diff_system_vec,
+            pars,
+            conf,
+            imported: None,
+        })
+    }
+
+    // imported data replaces the chaotic function of trajectory analyses
+    fn trajectories_are_ready(&self) -> bool {
+        self.import_panel.analysed_series().is_some()
+            || self.execute_panel.chaotic_function_is_chosen()
+    }
+
+    fn trajectory_dimensionality(&self) -> DistributionDimensions {
+        match self.import_panel.analysed_series() {
+            Some(series) => DistributionDimensions::State(series.num_dims()),
+            None => self.initial_panel.dimensionality(),
+        }
+    }
+
+    fn trajectory_analysis_loop<P: TrajectoryAnalysisPanel>(
+        &mut self,
+        panel: fn(&mut Self) -> &mut P,
+    ) -> Result<(), Error> {
+        if panel(self).toggle() {
+            self.run_trajectory_analysis(panel)?;
+        };
+        Ok(())
+    }
+
+    fn run_trajectory_analysis<P: TrajectoryAnalysisPanel>(
+        &mut self,
+        panel: fn(&mut Self) -> &mut P,
+    ) -> Result<(), Error> {
+        let conf = panel(self).conf();
+        let observable_only = panel(self).observable_only();
+        match self.trajectory_schema(conf, observable_only) {
+            Ok(schema) => panel(self).run(schema),
+            Err(e) => {
+                panel(self).set_error(e.to_string());
+                return Err(e);
+            }
+        };
+        Ok(())
+    }
+
+    fn dimension_loop(&mut self) -> Result<(), Error> {
+        if self.dimension_panel.toggle() {
+            match self.dimension_panel.source() {
+                PointSource::Ensemble => {
+                    let par = self.plot_panel.plotted_parameter();
+                    self.dimension_panel
+                        .ensemble_dimension(self.chaos_controller.get_chaos_data(), par);
+                }
+                PointSource::Trajectory => {
+                    self.run_trajectory_analysis(|app| &mut app.dimension_panel)?;
+                }
+            };
+            self.execute_panel
+                .set_attractor_estimate(self.dimension_panel.estimate());
+        };
+        Ok(())
+    }
+
     fn generate_initial_chaos_data(&mut self) -> Result<(), Error> {
         let init_distr = self.initial_panel.initial_distributions();
         self.executes = self
@@ -151,6 +361,16 @@ impl ChaosApp {
         self.add_point_series();
         chaos_data_gen_result
     }
+    fn import_chaos_data(&mut self, series: ImportedSeries) -> Result<(), Error> {
+        let import_result = self.chaos_controller.import_series(series);
+        self.plot_panel.set_no_parametrized_plotting();
+        self.plot_panel.reset_plot_trajectory();
+        self.add_point_series();
+        // replay the imported states right away
+        self.executes = import_result.is_ok();
+        import_result
+    }
+
     fn initialize_chaotic_functions(&mut self) -> Result<(), Error> {
         match self.execute_panel.chosen_chaotic_function() {
             SelectedChaoticFunction::SingleDiscreteMap(map_vec) => {
@@ -175,19 +395,52 @@ impl ChaosApp {
                     .set_differential_solvers(diff_system_vec)?;
                 self.plot_panel.set_parametrized_plotting(par, par_values);
             }
+            SelectedChaoticFunction::ParameterPlaneDiscreteMaps(..)
+            | SelectedChaoticFunction::ParameterPlaneDifferentialSystems(..) => {
+                bail!("Two parameter ranges are only evaluated in the parameter plane mode!")
+            }
             SelectedChaoticFunction::Nothing => {
                 bail!("Cannot init chaotic function as it is not set in the execute panel!")
             }
         };
         Ok(())
     }
+    fn update_energy_readout(&mut self) {
+        let readout = self
+            .chaos_controller
+            .energies()
+            .ok()
+            .map(|(label, energies)| {
+                if energies.is_empty() {
+                    return format!("{label}: no states left");
+                }
+                let min = energies.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = energies.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                format!("{label} in [{min:.6}, {max:.6}]")
+            });
+        self.execute_panel.set_energy_readout(readout);
+    }
+
     fn execute_chaotic_function(&mut self) -> Result<(), Error> {
+        let poincare_section = self.plot_panel.poincare_section();
+        self.chaos_controller.set_poincare_section(poincare_section);
+        let return_map = self.plot_panel.return_map();
+        self.chaos_controller.set_return_map(return_map);
+        self.chaos_controller
+            .set_integration_scheme(self.execute_panel.integration_scheme());
         self.chaos_controller
             .execute(self.execute_panel.num_executions())?;
+        self.update_energy_readout();
         if self.plot_panel.reinit_data() {
             self.chaos_controller.reinit_states()?;
         }
-        self.add_point_series();
+        if return_map.is_some() {
+            self.add_return_map_series();
+        } else if poincare_section.is_some() {
+            self.add_section_series();
+        } else {
+            self.add_point_series();
+        }
         Ok(())
     }
 
@@ -228,6 +481,79 @@ impl ChaosApp {
                     self.benchmark_panel
                         .conf_ui(self.execute_panel.chaotic_function_is_chosen(), ui);
                 }
+                MainPanel::ParameterPlane => {
+                    self.parameter_plane_panel.conf_ui(
+                        self.execute_panel.chaotic_function_is_chosen(),
+                        self.initial_panel.dimensionality(),
+                        ui,
+                    );
+                }
+                MainPanel::Spectrum => {
+                    self.spectrum_panel.conf_ui(
+                        self.trajectories_are_ready(),
+                        self.trajectory_dimensionality(),
+                        ui,
+                    );
+                }
+                MainPanel::Dimension => {
+                    self.dimension_panel
+                        .conf_ui(self.trajectories_are_ready(), ui);
+                }
+                MainPanel::Basins => {
+                    self.basin_panel
+                        .conf_ui(self.execute_panel.chaotic_function_is_chosen(), ui);
+                }
+                MainPanel::Recurrence => {
+                    self.recurrence_panel
+                        .conf_ui(self.trajectories_are_ready(), ui);
+                }
+                MainPanel::Entropy => {
+                    self.entropy_panel.conf_ui(
+                        self.trajectories_are_ready(),
+                        self.trajectory_dimensionality(),
+                        ui,
+                    );
+                }
+                MainPanel::Density => {
+                    self.density_panel.conf_ui(
+                        self.execute_panel.chaotic_function_is_chosen(),
+                        self.initial_panel.dimensionality(),
+                        ui,
+                    );
+                }
+                MainPanel::Rotation => {
+                    self.rotation_panel
+                        .conf_ui(self.execute_panel.chaotic_function_is_chosen(), ui);
+                }
+                MainPanel::Embedding => {
+                    self.embedding_panel.conf_ui(
+                        self.trajectories_are_ready(),
+                        self.trajectory_dimensionality(),
+                        ui,
+                    );
+                }
+                MainPanel::Lattice => {
+                    self.lattice_panel
+                        .conf_ui(self.execute_panel.chaotic_function_is_chosen(), ui);
+                }
+                MainPanel::Kuramoto => {
+                    self.kuramoto_panel
+                        .conf_ui(self.execute_panel.chaotic_function_is_chosen(), ui);
+                }
+                MainPanel::Network => {
+                    self.network_panel.conf_ui(
+                        self.execute_panel.chaotic_function_is_chosen(),
+                        self.initial_panel.dimensionality(),
+                        ui,
+                    );
+                }
+                MainPanel::Control => {
+                    self.control_panel.conf_ui(
+                        self.execute_panel.chaotic_function_is_chosen(),
+                        self.initial_panel.dimensionality(),
+                        ui,
+                    );
+                }
             };
         });
     }
@@ -269,10 +595,26 @@ impl ChaosApp {
             ConfPanel::Initial => {
                 self.initial_panel.ui(ui);
             }
+            ConfPanel::Import => {
+                self.import_panel.ui(ui);
+            }
             ConfPanel::Execution => {
                 let (dims, num_exec_limit) = match self.open_main_panel {
                     MainPanel::ChaoticPlot => (self.chaos_controller.dimensionality(), 100),
                     MainPanel::Benchmark => (self.initial_panel.dimensionality(), 10_000),
+                    MainPanel::ParameterPlane
+                    | MainPanel::Spectrum
+                    | MainPanel::Dimension
+                    | MainPanel::Basins
+                    | MainPanel::Recurrence
+                    | MainPanel::Entropy
+                    | MainPanel::Density
+                    | MainPanel::Rotation
+                    | MainPanel::Embedding
+                    | MainPanel::Lattice
+                    | MainPanel::Kuramoto
+                    | MainPanel::Network
+                    | MainPanel::Control => (self.initial_panel.dimensionality(), 1),
                 };
                 self.execute_panel.ui(ui, dims, num_exec_limit);
             }
@@ -289,6 +631,58 @@ impl ChaosApp {
                 let _ = self.benchmark_loop();
                 self.benchmark_panel.ui(ui);
             }
+            MainPanel::ParameterPlane => {
+                let _ = self.parameter_plane_loop();
+                self.parameter_plane_panel.ui(ui);
+            }
+            MainPanel::Spectrum => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.spectrum_panel);
+                self.spectrum_panel.ui(ui);
+            }
+            MainPanel::Dimension => {
+                let _ = self.dimension_loop();
+                self.dimension_panel.ui(ui);
+            }
+            MainPanel::Basins => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.basin_panel);
+                self.basin_panel.ui(ui);
+            }
+            MainPanel::Recurrence => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.recurrence_panel);
+                self.recurrence_panel.ui(ui);
+            }
+            MainPanel::Entropy => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.entropy_panel);
+                self.entropy_panel.ui(ui);
+            }
+            MainPanel::Density => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.density_panel);
+                self.density_panel.ui(ui);
+            }
+            MainPanel::Rotation => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.rotation_panel);
+                self.rotation_panel.ui(ui);
+            }
+            MainPanel::Embedding => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.embedding_panel);
+                self.embedding_panel.ui(mouse_over_main_panel, ui);
+            }
+            MainPanel::Lattice => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.lattice_panel);
+                self.lattice_panel.ui(ui);
+            }
+            MainPanel::Kuramoto => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.kuramoto_panel);
+                self.kuramoto_panel.ui(ui);
+            }
+            MainPanel::Network => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.network_panel);
+                self.network_panel.ui(ui);
+            }
+            MainPanel::Control => {
+                let _ = self.trajectory_analysis_loop(|app| &mut app.control_panel);
+                self.control_panel.ui(ui);
+            }
         }
     }
 }
@@ -300,9 +694,23 @@ impl eframe::App for ChaosApp {
     }
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
         let mut mouse_over_main_panel = true;
+        self.import_panel.receive_dropped_files(ctx);
         let conf_align = match self.open_main_panel {
             MainPanel::ChaoticPlot => Align2::LEFT_TOP,
-            MainPanel::Benchmark => Align2::CENTER_TOP,
+            MainPanel::Benchmark
+            | MainPanel::ParameterPlane
+            | MainPanel::Spectrum
+            | MainPanel::Dimension
+            | MainPanel::Basins
+            | MainPanel::Recurrence
+            | MainPanel::Entropy
+            | MainPanel::Density
+            | MainPanel::Rotation
+            | MainPanel::Embedding
+            | MainPanel::Lattice
+            | MainPanel::Kuramoto
+            | MainPanel::Network
+            | MainPanel::Control => Align2::CENTER_TOP,
         };
         conf_window("Configuration", conf_align).show(ctx, |ui| {
             let response = ui
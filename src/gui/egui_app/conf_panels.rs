@@ -1,8 +1,10 @@
 mod execute;
 mod execute_chaotic_function_view;
+mod import;
 mod initial;
 mod initial_distribution_view;
 pub use self::execute::*;
+pub use self::import::ImportPanel;
 pub use self::initial::InitialPanel;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
@@ -12,6 +14,7 @@ pub enum ConfPanel {
     #[default]
     Initial,
     Execution,
+    Import,
 }
 
 impl From<ConfPanel> for &'static str {
@@ -19,6 +22,7 @@ impl From<ConfPanel> for &'static str {
         match val {
             ConfPanel::Initial => "Initial Distribution",
             ConfPanel::Execution => "Chaotic Functions",
+            ConfPanel::Import => "Imported Data",
         }
     }
 }
@@ -1,7 +1,11 @@
 use crate::{
     chaos::data::{DistributionDimensions, FractalDimensions},
+    chaos::functions::IntegrationScheme,
     chaos::{DiscreteMapVec, OdeSystemSolverVec},
-    gui::{add_hyperlink, integer_slider, tooltips::*},
+    gui::{
+        add_hyperlink, clickable_button, combo_box, group_vertical, integer_slider, tooltips::*,
+        PARAMETER_MAX, PARAMETER_MIN,
+    },
 };
 
 use super::execute_chaotic_function_view::{
@@ -22,6 +26,14 @@ pub struct ExecutionPanel {
     view_data: ChaosFunctionViewData,
     #[serde(skip)] // start without an initialized function
     pub selected_function_was_set: bool,
+    #[serde(skip)] // estimates must be recomputed for the chosen function
+    attractor_estimate: Option<String>,
+    integration_scheme: IntegrationScheme,
+    energy: f64,
+    #[serde(skip)] // only fix the energy on demand
+    fix_energy: bool,
+    #[serde(skip)] // energies are read from the current states
+    energy_readout: Option<String>,
 }
 
 impl Default for ExecutionPanel {
@@ -32,6 +44,12 @@ impl Default for ExecutionPanel {
             chaotic_diff_system: None,
             view_data: Default::default(),
             selected_function_was_set: false,
+            attractor_estimate: None,
+            integration_scheme: Default::default(),
+            // mostly chaotic orbits of the Hénon-Heiles system
+            energy: 1.0 / 6.0,
+            fix_energy: false,
+            energy_readout: None,
         }
     }
 }
@@ -41,6 +59,16 @@ pub enum SelectedChaoticFunction {
     ParametrizedDiscreteMaps(DiscreteMapVec, &'static str, Vec<f64>),
     SingleDifferentialSystem(OdeSystemSolverVec),
     ParametrizedDifferentialSystems(OdeSystemSolverVec, &'static str, Vec<f64>),
+    ParameterPlaneDiscreteMaps(
+        DiscreteMapVec,
+        (&'static str, Vec<f64>),
+        (&'static str, Vec<f64>),
+    ),
+    ParameterPlaneDifferentialSystems(
+        OdeSystemSolverVec,
+        (&'static str, Vec<f64>),
+        (&'static str, Vec<f64>),
+    ),
 }
 impl ExecutionPanel {
     pub fn chaotic_function_is_chosen(&self) -> bool {
@@ -75,6 +103,27 @@ impl ExecutionPanel {
         false // None or not compatible
     }
 
+    pub fn set_attractor_estimate(&mut self, estimate: Option<String>) {
+        self.attractor_estimate = estimate;
+    }
+
+    pub fn set_energy_readout(&mut self, readout: Option<String>) {
+        self.energy_readout = readout;
+    }
+
+    pub fn integration_scheme(&self) -> IntegrationScheme {
+        self.integration_scheme
+    }
+
+    pub fn energy_toggle(&mut self) -> Option<f64> {
+        if self.fix_energy {
+            self.fix_energy = false;
+            Some(self.energy)
+        } else {
+            None
+        }
+    }
+
     pub fn num_executions(&self) -> usize {
         self.num_executions
     }
@@ -123,6 +172,7 @@ impl ExecutionPanel {
         {
             self.chaotic_diff_system = None;
             self.selected_function_was_set = false;
+            self.attractor_estimate = None;
         }
     }
     fn fractal_ui(&mut self, ui: &mut Ui, dims: &DistributionDimensions) {
@@ -175,12 +225,48 @@ impl ExecutionPanel {
                     {
                         self.chaotic_discrete_map = None;
                         self.selected_function_was_set = false;
+                        self.attractor_estimate = None;
+                        self.energy_readout = None;
                     }
                 })
             });
         });
     }
 
+    fn hamiltonian_ui(&mut self, ui: &mut Ui) {
+        group_vertical(ui, |ui| {
+            ui.horizontal(|ui| {
+                combo_box(
+                    LABEL_INTEGRATION_SCHEME,
+                    &mut self.integration_scheme,
+                    ui,
+                    TIP_INTEGRATION_SCHEME,
+                );
+            });
+            if let Some(readout) = &self.energy_readout {
+                ui.label(readout.as_str());
+            }
+            ui.horizontal(|ui| {
+                ui.monospace(format!("{}:", LABEL_ENERGY))
+                    .on_hover_text(TIP_ENERGY);
+                ui.add(
+                    egui::DragValue::new(&mut self.energy)
+                        .speed(0.01)
+                        .clamp_range(PARAMETER_MIN..=PARAMETER_MAX),
+                );
+                if clickable_button(
+                    LABEL_FIX_ENERGY,
+                    false,
+                    self.selected_function_was_set,
+                    ui,
+                    TIP_FIX_ENERGY,
+                ) {
+                    self.fix_energy = true;
+                }
+            });
+        });
+    }
+
     fn particle_ui(&mut self, _ui: &mut Ui, num_dims: usize) {
         // since there is only one version implemented for 2 and 3 each we select it
         if !self.chaotic_function_is_chosen() {
@@ -235,7 +321,13 @@ impl ExecutionPanel {
                 self.view_data.discrete_view_ui(open, ui);
             } else if let Some(open) = &self.chaotic_diff_system {
                 self.view_data.continuous_view_ui(open, ui);
+                if open.is_hamiltonian() {
+                    self.hamiltonian_ui(ui);
+                }
             };
+            if let Some(estimate) = &self.attractor_estimate {
+                ui.label(estimate.as_str());
+            }
             ui.horizontal(|ui| {
                 integer_slider(
                     LABEL_NUM_EXECS,
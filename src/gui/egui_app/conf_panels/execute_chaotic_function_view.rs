@@ -373,11 +373,24 @@ create_and_implement_map_view_variants! {
         Rulkov 2,
         Zaslavskii 2,
         ReverseProbability 2,
+        Lozi 2,
+        PeterDeJong 2,
+        Hopalong 2,
+        GumowskiMira 2,
+        Bedhead 2,
+        Svensson 2,
+        DiscreteLorenz 2,
+        DelayedLogistic 2,
         Shah 3,
         Memristive 3,
+        BarnsleyFern 3,
+        SierpinskiTriangle 3,
+        SierpinskiCarpet 3,
+        HeighwayDragon 3,
+        AffineIfs 3,
         Sfsimm 4
     ]
-    [Power, Transcendental, Sinus, Sinh, Zubieta, Picard, Biomorph]
+    [Power, Transcendental, Sinus, Sinh, Zubieta, BurningShip, Tricorn, Celtic, Phoenix, Picard, Biomorph, Newton]
     [
         Brusselator 2,
         VanDerPol 2,
@@ -394,8 +407,39 @@ create_and_implement_map_view_variants! {
         ThreeSpeciesLotkaVolterra 3,
         Rikitake 3,
         HindmarshRose 3,
+        Thomas 3,
+        Dadras 3,
+        Lu 3,
+        SprottA 3,
+        SprottB 3,
+        SprottC 3,
+        SprottD 3,
+        SprottE 3,
+        SprottF 3,
+        SprottG 3,
+        SprottH 3,
+        SprottI 3,
+        SprottJ 3,
+        SprottK 3,
+        SprottL 3,
+        SprottM 3,
+        SprottN 3,
+        SprottO 3,
+        SprottP 3,
+        SprottQ 3,
+        SprottR 3,
+        SprottS 3,
+        NoseHoover 3,
+        Lorenz84 3,
+        Rucklidge 3,
+        Arneodo 3,
+        FourWing 3,
+        HenonHeiles 4,
+        DoublePendulum 4,
+        RestrictedThreeBody 4,
         Ababneh 4,
-        WeiWang 4
+        WeiWang 4,
+        Kuramoto 2
     ]
     [XY, XYZ]
 }
@@ -415,6 +459,24 @@ macro_rules! generate_view_variant {
                 fn reset_ranges(&mut self){
                     $( self.[<range_ $field>] = None ;)*
                 }
+                #[allow(unused_mut)]
+                fn active_ranges(&self) -> Vec<(&'static str, (f64, f64))> {
+                    let mut ranges = Vec::new();
+                    $(
+                        if let Some(par_range) = self.[<range_ $field>] {
+                            ranges.push((stringify!($field), par_range));
+                        }
+                    )*
+                    ranges
+                }
+                #[allow(unused)]
+                fn set_parameter(data: &mut $variant, par: &str, value: f64) {
+                    $(
+                        if par == stringify!($field) {
+                            data.$field = value;
+                        }
+                    )*
+                }
                 #[allow(unused)]
                 pub fn ui(&mut self, ui: &mut Ui) {
                     ui.collapsing("Info", |ui| {
@@ -438,14 +500,18 @@ macro_rules! generate_view_variant {
                             let is_no_range = self.[<range_ $field>].is_none();
                             let range_label = format!("Range {}", par_label);
                             let tooltip = if is_no_range{
-                                format!("Toggle to specify an evenly spaced range over {} (Linspace). This may create a bifurcation diagram. The current chaotic data distribution is cloned for each parameter value.", par_label)
+                                format!("Toggle to specify an evenly spaced range over {} (Linspace). This may create a bifurcation diagram. The current chaotic data distribution is cloned for each parameter value. A second range spans a parameter plane.", par_label)
                             } else{
                                 format!("Toggle to deactivate the range over {}. Toggling off takes the data set from the parameter with the smallest value and continuous with the previously selected single parameter.", par_label)
                             };
                             if clickable_button(range_label.as_str(), !is_no_range,true, ui, tooltip.as_str()){
-                                self.reset_ranges();
                                 if is_no_range{
+                                    if self.active_ranges().len() >= 2 {
+                                        self.reset_ranges();
+                                    }
                                     self.[<range_ $field>] = Some(allowed_range);
+                                } else {
+                                    self.[<range_ $field>] = None;
                                 }
                             }
                             let field_par_changed = if let Some(par_range) = self.[<range_ $field>].as_mut(){
@@ -482,19 +548,38 @@ macro_rules! impl_discrete_variants {
                 }
                 impl From<[<$variant View>]> for SelectedChaoticFunction{
                     fn from(val: [<$variant View>])->Self{
+                        if let [(par_x, (x_min, x_max)), (par_y, (y_min, y_max))] = val.active_ranges()[..] {
+                            let x_values = parameter_linspace(x_min, x_max, val.num_params);
+                            let y_values = parameter_linspace(y_min, y_max, val.num_params);
+                            let mut discrete_maps = Vec::with_capacity(x_values.len() * y_values.len());
+                            for y in y_values.iter() {
+                                for x in x_values.iter() {
+                                    let mut pars = val.data.clone();
+                                    [<$variant View>]::set_parameter(&mut pars, par_y, *y);
+                                    [<$variant View>]::set_parameter(&mut pars, par_x, *x);
+                                    pars.par_range_check();
+                                    discrete_maps.push($mapper::new(pars));
+                                }
+                            }
+                            return SelectedChaoticFunction::ParameterPlaneDiscreteMaps(DiscreteMapVec::$variant(discrete_maps), (par_x, x_values), (par_y, y_values));
+                        }
                         $(
                             if let Some((par_min, par_max)) = val.[<range_ $field>]{
                                 let par_values = parameter_linspace(par_min, par_max, val.num_params);
                                 let discrete_maps = par_values.iter().map(|par|{
                                     let mut pars = val.data.clone();
                                     pars.$field = *par;
+                                    pars.par_range_check();
                                     $mapper::new(pars)
                                 }).collect();
                                 let discrete_vec = DiscreteMapVec::$variant(discrete_maps);
                                 return SelectedChaoticFunction::ParametrizedDiscreteMaps(discrete_vec, stringify!($field), par_values);
                             }
                         )*
-                        SelectedChaoticFunction::SingleDiscreteMap(DiscreteMapVec::$variant(vec![$mapper::new(val.data.clone())]))
+                        // deserialized parameters were not checked yet
+                        let mut pars = val.data.clone();
+                        pars.par_range_check();
+                        SelectedChaoticFunction::SingleDiscreteMap(DiscreteMapVec::$variant(vec![$mapper::new(pars)]))
                     }
                 }
             }
@@ -523,64 +608,117 @@ impl_discrete_variants! {
     Rulkov, SimpleDiscreteMap, { [alpha, "α"],  [mu, "μ"], [delta, "δ"] },
     Zaslavskii, SimpleDiscreteMap, { [eps, "ε"], [nu, "ν"], [r, "r"] },
     ReverseProbability, SimpleDiscreteMap, { [c_re, "c real"], [c_im, "c imaginary"], [r_threshold, "R"] },
+    Lozi, SimpleDiscreteMap, { [a, "a"], [b, "b"] },
+    PeterDeJong, SimpleDiscreteMap, { [a, "a"], [b, "b"], [c, "c"], [d, "d"] },
+    Hopalong, SimpleDiscreteMap, { [a, "a"], [b, "b"], [c, "c"] },
+    GumowskiMira, SimpleDiscreteMap, { [alpha, "α"], [sigma, "σ"], [mu, "μ"] },
+    Bedhead, SimpleDiscreteMap, { [a, "a"], [b, "b"] },
+    Svensson, SimpleDiscreteMap, { [a, "a"], [b, "b"], [c, "c"], [d, "d"] },
+    DiscreteLorenz, SimpleDiscreteMap, { [a, "a"], [b, "b"] },
+    DelayedLogistic, SimpleDiscreteMap, { [a, "a"] },
     Shah, SimpleDiscreteMap, { [alpha, "α"], [beta, "β"], [gamma, "γ"], [delta, "δ"] },
     Memristive, SimpleDiscreteMap, { [k, "k"], [a, "a"] },
+    BarnsleyFern, SimpleDiscreteMap, {  },
+    SierpinskiTriangle, SimpleDiscreteMap, { [r, "r"] },
+    SierpinskiCarpet, SimpleDiscreteMap, { [r, "r"] },
+    HeighwayDragon, SimpleDiscreteMap, {  },
+    AffineIfs, SimpleDiscreteMap, { [a1, "a1"], [b1, "b1"], [c1, "c1"], [d1, "d1"], [e1, "e1"], [f1, "f1"], [p1, "p1"], [a2, "a2"], [b2, "b2"], [c2, "c2"], [d2, "d2"], [e2, "e2"], [f2, "f2"], [p2, "p2"], [a3, "a3"], [b3, "b3"], [c3, "c3"], [d3, "d3"], [e3, "e3"], [f3, "f3"], [p3, "p3"], [a4, "a4"], [b4, "b4"], [c4, "c4"], [d4, "d4"], [e4, "e4"], [f4, "f4"], [p4, "p4"] },
     Sfsimm, SimpleDiscreteMap, { [p, "p"], [b, "b"], [r, "r"] },
     MandelbrotPowerComplex, MandelbrotPower, { [r, "r"], [n, "n"] },
     MandelbrotTranscendentalComplex, MandelbrotTranscendental, { [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     MandelbrotSinusComplex, MandelbrotSinus, { [r, "r"], [n, "n"] },
     MandelbrotSinhComplex, MandelbrotSinh, { [r, "r"], [n, "n"] },
     MandelbrotZubietaComplex, MandelbrotZubieta, { [r, "r"], [n, "n"] },
+    MandelbrotBurningShipComplex, MandelbrotBurningShip, { [r, "r"], [n, "n"] },
+    MandelbrotTricornComplex, MandelbrotTricorn, { [r, "r"], [n, "n"] },
+    MandelbrotCelticComplex, MandelbrotCeltic, { [r, "r"], [n, "n"] },
+    MandelbrotPhoenixComplex, MandelbrotPhoenix, { [r, "r"], [n, "n"], [p, "p"] },
     MandelbrotPicardComplex, MandelbrotPicard,  { [a, "a"], [alpha, "α"], [n, "n"]  },
+    MandelbrotNewtonComplex, MandelbrotNewton, { [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     MandelbrotBiomorphComplex, MandelbrotBiomorph, { [r, "r"], [m_re, "m re"], [m_im, "m i"], [a_re, "a re"], [a_im, "a i"], [b_re, "b re"], [b_im, "b i"], [alpha, "α"], [n, "n"]  },
     JuliaPowerComplex, JuliaPower, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
     JuliaTranscendentalComplex, JuliaTranscendental, { [c_re, "c re"], [c_im, "c i"], [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     JuliaSinusComplex, JuliaSinus, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
     JuliaSinhComplex, JuliaSinh, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
     JuliaZubietaComplex, JuliaZubieta, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
+    JuliaBurningShipComplex, JuliaBurningShip, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
+    JuliaTricornComplex, JuliaTricorn, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
+    JuliaCelticComplex, JuliaCeltic, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"] },
+    JuliaPhoenixComplex, JuliaPhoenix, { [c_re, "c re"], [c_im, "c i"], [r, "r"], [n, "n"], [p, "p"] },
     JuliaPicardComplex, JuliaPicard, {  [a, "a"], [c_re, "c re"], [c_im, "c i"], [alpha, "α"], [n, "n"]  },
+    JuliaNewtonComplex, JuliaNewton, { [c_re, "c re"], [c_im, "c i"], [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     JuliaBiomorphComplex, JuliaBiomorph, { [r, "r"], [c_re, "c re"], [c_im, "c i"], [m_re, "m re"], [m_im, "m i"], [a_re, "a re"], [a_im, "a i"], [b_re, "b re"], [b_im, "b i"], [alpha, "α"], [n, "n"]  },
     MandelbrotPowerDual, MandelbrotPower, { [r, "r"], [n, "n"] },
     MandelbrotTranscendentalDual, MandelbrotTranscendental, { [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     MandelbrotSinusDual, MandelbrotSinus, { [r, "r"], [n, "n"]},
     MandelbrotSinhDual, MandelbrotSinh, { [r, "r"], [n, "n"]},
     MandelbrotZubietaDual, MandelbrotZubieta, { [r, "r"], [n, "n"]},
+    MandelbrotBurningShipDual, MandelbrotBurningShip, { [r, "r"], [n, "n"] },
+    MandelbrotTricornDual, MandelbrotTricorn, { [r, "r"], [n, "n"] },
+    MandelbrotCelticDual, MandelbrotCeltic, { [r, "r"], [n, "n"] },
+    MandelbrotPhoenixDual, MandelbrotPhoenix, { [r, "r"], [n, "n"], [p, "p"] },
     MandelbrotPicardDual, MandelbrotPicard, {  [a, "a"], [alpha, "α"], [n, "n"]   },
+    MandelbrotNewtonDual, MandelbrotNewton, { [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     MandelbrotBiomorphDual, MandelbrotBiomorph, { [r, "r"], [m_re, "m re"], [m_im, "m ε"], [a_re, "a re"], [a_im, "a ε"], [b_re, "b re"], [b_im, "b ε"], [alpha, "α"], [n, "n"]   },
     JuliaPowerDual, JuliaPower, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"] },
     JuliaTranscendentalDual, JuliaTranscendental, { [c_re, "c re"], [c_im, "c ε"], [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     JuliaSinusDual, JuliaSinus, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"]},
     JuliaSinhDual, JuliaSinh, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"]},
     JuliaZubietaDual, JuliaZubieta, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"]},
+    JuliaBurningShipDual, JuliaBurningShip, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"] },
+    JuliaTricornDual, JuliaTricorn, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"] },
+    JuliaCelticDual, JuliaCeltic, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"] },
+    JuliaPhoenixDual, JuliaPhoenix, { [c_re, "c re"], [c_im, "c ε"], [r, "r"], [n, "n"], [p, "p"] },
     JuliaPicardDual, JuliaPicard, { [a, "a"], [c_re, "c re"], [c_im, "c ε"], [alpha, "α"], [n, "n"]   },
+    JuliaNewtonDual, JuliaNewton, { [c_re, "c re"], [c_im, "c ε"], [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     JuliaBiomorphDual, JuliaBiomorph, { [r, "r"], [c_re, "c re"], [c_im, "c ε"], [m_re, "m re"], [m_im, "m ε"], [a_re, "a re"], [a_im, "a ε"], [b_re, "b re"], [b_im, "b ε"], [alpha, "α"], [n, "n"]   },
     MandelbrotPowerPerplex, MandelbrotPower, { [r, "r"], [n, "n"] },
     MandelbrotTranscendentalPerplex, MandelbrotTranscendental, { [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     MandelbrotSinusPerplex, MandelbrotSinus, { [r, "r"], [n, "n"]},
     MandelbrotSinhPerplex, MandelbrotSinh, { [r, "r"], [n, "n"]},
     MandelbrotZubietaPerplex, MandelbrotZubieta, { [r, "r"], [n, "n"]},
+    MandelbrotBurningShipPerplex, MandelbrotBurningShip, { [r, "r"], [n, "n"] },
+    MandelbrotTricornPerplex, MandelbrotTricorn, { [r, "r"], [n, "n"] },
+    MandelbrotCelticPerplex, MandelbrotCeltic, { [r, "r"], [n, "n"] },
+    MandelbrotPhoenixPerplex, MandelbrotPhoenix, { [r, "r"], [n, "n"], [p, "p"] },
     MandelbrotPicardPerplex, MandelbrotPicard, { [a, "a"], [alpha, "α"], [n, "n"]   },
+    MandelbrotNewtonPerplex, MandelbrotNewton, { [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     MandelbrotBiomorphPerplex, MandelbrotBiomorph, { [r, "r"], [m_re, "m t"], [m_im, "m x"], [a_re, "a t"], [a_im, "a x"], [b_re, "b t"], [b_im, "b x"], [alpha, "α"], [n, "n"]   },
     JuliaPowerPerplex, JuliaPower, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"] },
     JuliaTranscendentalPerplex, JuliaTranscendental, { [c_re, "c t"], [c_im, "c x"], [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     JuliaSinusPerplex, JuliaSinus, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"]},
     JuliaSinhPerplex, JuliaSinh, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"]},
     JuliaZubietaPerplex, JuliaZubieta, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"]},
+    JuliaBurningShipPerplex, JuliaBurningShip, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"] },
+    JuliaTricornPerplex, JuliaTricorn, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"] },
+    JuliaCelticPerplex, JuliaCeltic, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"] },
+    JuliaPhoenixPerplex, JuliaPhoenix, { [c_re, "c t"], [c_im, "c x"], [r, "r"], [n, "n"], [p, "p"] },
     JuliaPicardPerplex, JuliaPicard, { [a, "a"], [c_re, "c t"], [c_im, "c x"], [alpha, "α"], [n, "n"]   },
+    JuliaNewtonPerplex, JuliaNewton, { [c_re, "c t"], [c_im, "c x"], [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     JuliaBiomorphPerplex, JuliaBiomorph, { [r, "r"], [c_re, "c t"], [c_im, "c x"], [m_re, "m t"], [m_im, "m x"], [a_re, "a t"], [a_im, "a x"], [b_re, "b t"], [b_im, "b x"], [alpha, "α"], [n, "n"]   },
     MandelbrotPowerQuaternion, MandelbrotPower, { [r, "r"], [n, "n"] },
     MandelbrotTranscendentalQuaternion, MandelbrotTranscendental, { [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     MandelbrotSinusQuaternion, MandelbrotSinus, { [r, "r"], [n, "n"]},
     MandelbrotSinhQuaternion, MandelbrotSinh, { [r, "r"], [n, "n"]},
     MandelbrotZubietaQuaternion, MandelbrotZubieta, { [r, "r"], [n, "n"]},
+    MandelbrotBurningShipQuaternion, MandelbrotBurningShip, { [r, "r"], [n, "n"] },
+    MandelbrotTricornQuaternion, MandelbrotTricorn, { [r, "r"], [n, "n"] },
+    MandelbrotCelticQuaternion, MandelbrotCeltic, { [r, "r"], [n, "n"] },
+    MandelbrotPhoenixQuaternion, MandelbrotPhoenix, { [r, "r"], [n, "n"], [p, "p"] },
     MandelbrotPicardQuaternion, MandelbrotPicard, { [a, "a"], [alpha, "α"], [n, "n"]   },
+    MandelbrotNewtonQuaternion, MandelbrotNewton, { [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     MandelbrotBiomorphQuaternion, MandelbrotBiomorph, { [r, "r"], [m_w, "m w"], [m_i, "m i"], [m_j, "m j"], [m_k, "m k"], [a_w, "a w"], [a_i, "a i"], [a_j, "a j"], [a_k, "a k"], [b_w, "b w"], [b_i, "b i"], [b_j, "b j"], [b_k, "b k"], [alpha, "α"], [n, "n"]   },
     JuliaPowerQuaternion, JuliaPower, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"] },
     JuliaTranscendentalQuaternion, JuliaTranscendental, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [a, "a"], [b, "b"], [alpha, "α"], [n, "n"] },
     JuliaSinusQuaternion, JuliaSinus, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"]},
     JuliaSinhQuaternion, JuliaSinh, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"]},
     JuliaZubietaQuaternion, JuliaZubieta, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"]},
+    JuliaBurningShipQuaternion, JuliaBurningShip, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"] },
+    JuliaTricornQuaternion, JuliaTricorn, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"] },
+    JuliaCelticQuaternion, JuliaCeltic, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"] },
+    JuliaPhoenixQuaternion, JuliaPhoenix, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [r, "r"], [n, "n"], [p, "p"] },
     JuliaPicardQuaternion, JuliaPicard, { [a, "a"], [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [alpha, "α"], [n, "n"]   },
+    JuliaNewtonQuaternion, JuliaNewton, { [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [n, "n"], [a, "a"], [a0, "a0"], [a1, "a1"], [a2, "a2"], [a3, "a3"], [a4, "a4"], [a5, "a5"] },
     JuliaBiomorphQuaternion, JuliaBiomorph, { [r, "r"], [c_w, "c w"], [c_i, "c i"], [c_j, "c j"], [c_k, "c k"], [m_w, "m w"], [m_i, "m i"], [m_j, "m j"], [m_k, "m k"], [a_w, "a w"], [a_i, "a i"], [a_j, "a j"], [a_k, "a k"], [b_w, "b w"], [b_i, "b i"], [b_j, "b j"], [b_k, "b k"], [alpha, "α"], [n, "n"]   }
 }
 
@@ -593,6 +731,20 @@ macro_rules! impl_continuous_variants {
                 }
                 impl From<[<$variant View>]> for SelectedChaoticFunction{
                     fn from(val: [<$variant View>])->Self{
+                        if let [(par_x, (x_min, x_max)), (par_y, (y_min, y_max))] = val.active_ranges()[..] {
+                            let x_values = parameter_linspace(x_min, x_max, val.num_params);
+                            let y_values = parameter_linspace(y_min, y_max, val.num_params);
+                            let mut ode_solvers = Vec::with_capacity(x_values.len() * y_values.len());
+                            for y in y_values.iter() {
+                                for x in x_values.iter() {
+                                    let mut pars = val.data.clone();
+                                    [<$variant View>]::set_parameter(&mut pars, par_y, *y);
+                                    [<$variant View>]::set_parameter(&mut pars, par_x, *x);
+                                    ode_solvers.push($solver::new(pars));
+                                }
+                            }
+                            return SelectedChaoticFunction::ParameterPlaneDifferentialSystems(OdeSystemSolverVec::$variant(ode_solvers), (par_x, x_values), (par_y, y_values));
+                        }
                         $(
                             if let Some((par_min, par_max)) = val.[<range_ $field>]{
                                 let par_values = parameter_linspace(par_min, par_max, val.num_params);
@@ -629,8 +781,48 @@ impl_continuous_variants! {
     ThreeSpeciesLotkaVolterra, OdeSolver, { [b, "b"] , [d1, "d1"] , [d2, "d2"] ,  [a11, "a11"] , [a12, "a12"] , [a13, "a13"] , [a21, "a21"] , [a23, "a23"] , [a31, "a31"] , [a32, "a32"]  },
     Rikitake, OdeSolver, { [a, "a"]  ,  [mu, "μ"]   },
     HindmarshRose, OdeSolver, { [a, "a"] , [b, "b"] , [c, "c"] , [d, "d"] , [r, "r"] , [i, "i"] },
+    Thomas, OdeSolver, { [b, "b"] },
+    Dadras, OdeSolver, { [a, "a"] , [b, "b"] , [c, "c"] , [d, "d"] , [e, "e"] },
+    Lu, OdeSolver, { [a, "a"] , [b, "b"] , [c, "c"] },
+    SprottA, OdeSolver, { [a, "a"] },
+    SprottB, OdeSolver, { [a, "a"] },
+    SprottC, OdeSolver, { [a, "a"] },
+    SprottD, OdeSolver, { [a, "a"] },
+    SprottE, OdeSolver, { [a, "a"] },
+    SprottF, OdeSolver, { [a, "a"] },
+    SprottG, OdeSolver, { [a, "a"] },
+    SprottH, OdeSolver, { [a, "a"] },
+    SprottI, OdeSolver, { [a, "a"] },
+    SprottJ, OdeSolver, { [a, "a"] },
+    SprottK, OdeSolver, { [a, "a"] },
+    SprottL, OdeSolver, { [a, "a"] },
+    SprottM, OdeSolver, { [a, "a"] },
+    SprottN, OdeSolver, { [a, "a"] },
+    SprottO, OdeSolver, { [a, "a"] },
+    SprottP, OdeSolver, { [a, "a"] },
+    SprottQ, OdeSolver, { [a, "a"] },
+    SprottR, OdeSolver, { [a, "a"] },
+    SprottS, OdeSolver, { [a, "a"] },
+    NoseHoover, OdeSolver, { [t, "T"], [q, "Q"] },
+    Lorenz84, OdeSolver, { [a, "a"] , [b, "b"] , [f, "F"] , [g, "G"] },
+    Rucklidge, OdeSolver, { [kappa, "κ"] , [lambda, "λ"] },
+    Arneodo, OdeSolver, { [a, "a"] , [b, "b"] , [c, "c"] },
+    FourWing, OdeSolver, { [a, "a"] , [b, "b"] , [c, "c"] },
+    HenonHeiles, OdeSolver, { [lambda, "λ"] },
+    DoublePendulum, OdeSolver, { [m1, "m₁"] , [m2, "m₂"] , [l1, "l₁"] , [l2, "l₂"] , [g, "g"] },
+    RestrictedThreeBody, OdeSolver, { [mu, "μ"] },
     Ababneh, OdeSolver, { [a, "a"]  , [b, "b"]   },
     WeiWang, OdeSolver, { [a, "a"]  , [b, "b"]  , [c, "c"]  , [d, "d"]  , [k, "k"]   },
+    Kuramoto, KuramotoSolver, { [k, "K"] },
     ParticleXY, ParticleXYSystemSolver, { [s, "s 💥"] , [m, "m ⚡"] , [l, "l ⭐"] },
     ParticleXYZ, ParticleXYZSystemSolver, { [s, "s 💥"] , [m, "m ⚡"] , [l, "l ⭐"] }
 }
+
+impl DifferentialSystemView {
+    pub fn is_hamiltonian(&self) -> bool {
+        matches!(
+            self,
+            Self::HenonHeiles | Self::DoublePendulum | Self::RestrictedThreeBody
+        )
+    }
+}
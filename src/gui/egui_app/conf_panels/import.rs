@@ -0,0 +1,119 @@
+use crate::chaos::data::ImportedSeries;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::{Context, Ui};
+
+const CSV_HINT: &str = "t,x,y\n0.0,1.0,0.5\n0.1,1.2,0.4";
+
+pub struct ImportPanel {
+    csv_text: String,
+    new_import: bool,
+    analyse_imported: bool,
+    import_status: Result<ImportedSeries, String>,
+}
+
+impl Default for ImportPanel {
+    fn default() -> Self {
+        Self {
+            csv_text: String::new(),
+            new_import: false,
+            analyse_imported: false,
+            import_status: Err(String::from("No data imported yet.")),
+        }
+    }
+}
+
+impl ImportPanel {
+    /// The newly imported series that must be shown in the plot.
+    pub fn import_toggle(&mut self) -> Option<ImportedSeries> {
+        if self.new_import {
+            self.new_import = false;
+            self.import_status.as_ref().ok().cloned()
+        } else {
+            None
+        }
+    }
+
+    /// The imported series that replaces the chaotic function of trajectory analyses.
+    pub fn analysed_series(&self) -> Option<&ImportedSeries> {
+        match &self.import_status {
+            Ok(series) if self.analyse_imported => Some(series),
+            _ => None,
+        }
+    }
+
+    fn import(&mut self, csv: &str) {
+        match ImportedSeries::from_csv(csv) {
+            Ok(series) => {
+                self.import_status = Ok(series);
+                self.new_import = true;
+            }
+            Err(e) => self.import_status = Err(e.to_string()),
+        };
+    }
+
+    pub fn receive_dropped_files(&mut self, ctx: &Context) {
+        let dropped_files = ctx.input(|input| input.raw.dropped_files.clone());
+        if let Some(file) = dropped_files.first() {
+            // the web backend passes the content, native backends the path
+            let csv = match (&file.bytes, &file.path) {
+                (Some(bytes), _) => String::from_utf8_lossy(bytes).into_owned(),
+                (None, Some(path)) => match std::fs::read_to_string(path) {
+                    Ok(csv) => csv,
+                    Err(e) => {
+                        self.import_status = Err(format!("Cannot read {}: {e}", file.name));
+                        return;
+                    }
+                },
+                _ => return,
+            };
+            self.import(&csv);
+        }
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.import_status {
+            Ok(series) => {
+                ui.label(format!(
+                    "Imported {} states of {}",
+                    series.num_states(),
+                    series.labels().join(", ")
+                ));
+                ui.label(format!("Sample time: {:.4}", series.sample_time()));
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        group_vertical(ui, |ui| {
+            add_label(LABEL_CSV_DATA, ui, TIP_CSV_DATA);
+            egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.csv_text)
+                            .hint_text(CSV_HINT)
+                            .code_editor()
+                            .desired_rows(8),
+                    );
+                });
+        });
+        group_horizontal(ui, |ui| {
+            let is_ready = !self.csv_text.trim().is_empty();
+            if clickable_button(LABEL_IMPORT, false, is_ready, ui, TIP_IMPORT) {
+                let csv = self.csv_text.clone();
+                self.import(&csv);
+            }
+            add_checkbox(
+                LABEL_ANALYSE_IMPORTED,
+                &mut self.analyse_imported,
+                ui,
+                TIP_ANALYSE_IMPORTED,
+            );
+        });
+        self.show_summary(ui);
+    }
+}
@@ -1,16 +1,69 @@
+mod basins;
 mod benchmark;
 mod chaotic_plot;
+mod control;
+mod density;
+mod dimension;
+mod embedding;
+mod entropy;
+mod kuramoto;
+mod lattice;
+mod network;
+mod parameter_plane;
+mod recurrence;
+mod rotation;
+mod spectrum;
 
+use crate::chaos::analysis::{TrajectoryConf, TrajectorySchema};
+pub use basins::BasinPanel;
 pub use benchmark::BenchmarkPanel;
 pub use chaotic_plot::PlotPanel;
+pub use control::ControlPanel;
+pub use density::DensityPanel;
+pub use dimension::DimensionPanel;
+pub use embedding::EmbeddingPanel;
+pub use entropy::EntropyPanel;
+pub use kuramoto::KuramotoPanel;
+pub use lattice::LatticePanel;
+pub use network::NetworkPanel;
+pub use parameter_plane::ParameterPlanePanel;
+pub use recurrence::RecurrencePanel;
+pub use rotation::RotationPanel;
 use serde::{Deserialize, Serialize};
+pub use spectrum::SpectrumPanel;
 use strum_macros::EnumIter;
 
+/// Main panel that analyses recorded trajectories of the chosen function or imported series.
+pub trait TrajectoryAnalysisPanel {
+    /// Returns true once after the analysis was requested.
+    fn toggle(&mut self) -> bool;
+    fn conf(&self) -> TrajectoryConf;
+    fn run(&mut self, schema: TrajectorySchema);
+    fn set_error(&mut self, error: String);
+    /// Analyses of a single observed time series also accept imported data.
+    fn observable_only(&self) -> bool {
+        false
+    }
+}
+
 #[derive(PartialEq, Eq, Default, Clone, Copy, EnumIter, Deserialize, Serialize)]
 pub enum MainPanel {
     #[default]
     ChaoticPlot,
     Benchmark,
+    ParameterPlane,
+    Spectrum,
+    Dimension,
+    Basins,
+    Recurrence,
+    Entropy,
+    Density,
+    Rotation,
+    Embedding,
+    Lattice,
+    Kuramoto,
+    Network,
+    Control,
 }
 
 impl From<MainPanel> for &'static str {
@@ -18,6 +71,19 @@ impl From<MainPanel> for &'static str {
         match val {
             MainPanel::ChaoticPlot => "Plot",
             MainPanel::Benchmark => "Benchmark",
+            MainPanel::ParameterPlane => "Parameter Plane",
+            MainPanel::Spectrum => "Power Spectrum",
+            MainPanel::Dimension => "Dimension",
+            MainPanel::Basins => "Basins of Attraction",
+            MainPanel::Recurrence => "Recurrence",
+            MainPanel::Entropy => "Entropy",
+            MainPanel::Density => "Invariant Density",
+            MainPanel::Rotation => "Rotation Number",
+            MainPanel::Embedding => "Embedding",
+            MainPanel::Lattice => "Coupled Map Lattice",
+            MainPanel::Kuramoto => "Kuramoto",
+            MainPanel::Network => "Network Synchronization",
+            MainPanel::Control => "Chaos Control",
         }
     }
 }
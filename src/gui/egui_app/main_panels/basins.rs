@@ -0,0 +1,215 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_STEPS: usize = 10_000;
+const MAX_NUM_CELLS: usize = 1000;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct BasinPanel {
+    #[serde(skip)] // start without computing basins
+    run_basins: bool,
+    #[serde(skip)] // basins must be recomputed manually
+    heat_map: HeatMap,
+    #[serde(skip)] // basins must be recomputed manually
+    basin_status: Result<BasinResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    num_transient: usize,
+    num_steps: usize,
+    num_cells: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for BasinPanel {
+    fn default() -> Self {
+        Self {
+            run_basins: false,
+            heat_map: Default::default(),
+            basin_status: Err(String::from("No basins computed yet.")),
+            selected_set: 0,
+            num_transient: 1000,
+            num_steps: 100,
+            num_cells: 64,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for BasinPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_basins == other.run_basins
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.num_cells == other.num_cells
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for BasinPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_basins {
+            self.run_basins = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.basin_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        match basins_of_attraction(schema, self.num_cells) {
+            Ok(result) => {
+                self.basin_status = Ok(result);
+                self.show_set(0);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl BasinPanel {
+    fn show_set(&mut self, index: usize) {
+        if let Ok(result) = &self.basin_status {
+            self.selected_set = index;
+            let axis = |(state, range): (usize, (f64, f64))| {
+                (format!("State{}", state + 1), range, result.grid_size)
+            };
+            let (x_label, x_range, width) = axis(result.x_axis);
+            let (y_label, y_range, height) = axis(result.y_axis);
+            self.heat_map = HeatMap::new(
+                result.grid_labels(index),
+                (x_label.as_str(), x_range, width),
+                (y_label.as_str(), y_range, height),
+            );
+            self.heat_map.set_colormap(self.colormap);
+        }
+    }
+
+    fn set_names(result: &BasinResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.basins.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, ui: &mut Ui) {
+        ui.heading("Basins of Attraction Configuration");
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_TRAJECTORY_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_TRAJECTORY_STEPS,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_BASIN_CELLS,
+                &mut self.num_cells,
+                MAX_NUM_CELLS,
+                ui,
+                TIP_BASIN_CELLS,
+            );
+        });
+        let set_names = match &self.basin_status {
+            Ok(result) if result.basins.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.heat_map.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_BASINS, false, is_ready, ui, TIP_BASINS) {
+                self.run_basins = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.basin_status {
+            Ok(result) => {
+                let basins = &result.basins[self.selected_set];
+                let num_states = basins.labels.len().max(1) as f64;
+                let num_diverged = basins.num_diverged();
+                ui.label(format!(
+                    "Attractors: {} Diverged: {num_diverged} ({:.1}%)",
+                    basins.attractors.len(),
+                    100.0 * num_diverged as f64 / num_states
+                ));
+                basins
+                    .attractors
+                    .iter()
+                    .enumerate()
+                    .for_each(|(label, attractor)| {
+                        let centroid = attractor
+                            .centroid
+                            .iter()
+                            .map(|x| format!("{x:.3}"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        ui.label(format!(
+                            "Attractor {}: basin {:.1}% ({} states), {} cells, centroid ({centroid})",
+                            label + 1,
+                            100.0 * attractor.basin_size as f64 / num_states,
+                            attractor.basin_size,
+                            attractor.num_cells,
+                        ));
+                    });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.basin_status.is_ok() {
+            self.heat_map.ui(ui);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::chaos::analysis::{PoincareSection, ReturnMap, MAX_SECTION_DIMS};
 use crate::chaos::data::{ChaosDataVec, DistributionDimensions};
 use crate::gui::plot::*;
 use crate::gui::tooltips::*;
@@ -21,6 +22,10 @@ pub struct PlotPanel {
     point_colormap: SeriesColors,
     frame_rate: usize,
     timer: Timer,
+    use_poincare_section: bool,
+    poincare_section: PoincareSection,
+    use_return_map: bool,
+    return_map_component: usize,
 }
 
 impl Default for PlotPanel {
@@ -37,6 +42,10 @@ impl Default for PlotPanel {
             point_colormap: Default::default(),
             frame_rate: 30,
             timer: Default::default(),
+            use_poincare_section: false,
+            poincare_section: Default::default(),
+            use_return_map: false,
+            return_map_component: 0,
         }
     }
 }
@@ -44,7 +53,40 @@ impl PlotPanel {
     pub fn reinit_data(&self) -> bool {
         self.reinit_data
     }
+    pub fn poincare_section(&self) -> Option<PoincareSection> {
+        if self.use_poincare_section {
+            Some(self.poincare_section)
+        } else {
+            None
+        }
+    }
+    pub fn return_map(&self) -> Option<ReturnMap> {
+        if self.use_return_map {
+            Some(ReturnMap {
+                component: self.return_map_component,
+                from_section: self.use_poincare_section,
+            })
+        } else {
+            None
+        }
+    }
+    pub fn plotted_parameter(&self) -> (&'static str, Vec<f64>) {
+        match self.plot_2_d.get_parameter() {
+            Some(par) => (par, self.plot_2_d.get_parameter_values().to_owned()),
+            None => Default::default(),
+        }
+    }
     pub fn add_point_series(&mut self, data: ChaosDataVec<'_>) {
+        if !self.use_return_map {
+            self.add_series(data);
+        } // states and section points are no pairs of the return map
+    }
+
+    pub fn add_return_map_series(&mut self, data: ChaosDataVec<'_>) {
+        self.add_series(data);
+    }
+
+    fn add_series(&mut self, data: ChaosDataVec<'_>) {
         match self.plot_backend {
             PlotBackendVariant::EguiPlot2D => {
                 self.plot_2_d.set_point_colormap(self.point_colormap);
@@ -149,6 +191,24 @@ impl PlotPanel {
                 }
             });
         }
+        match dims {
+            DistributionDimensions::State(num_dims) if num_dims >= 3 => {
+                self.add_poincare_section_options(num_dims, ui);
+            }
+            _ => {
+                self.use_poincare_section = false;
+            }
+        };
+        match (dims, self.plot_backend) {
+            (DistributionDimensions::State(num_dims), PlotBackendVariant::EguiPlot2D)
+                if num_dims >= 2 =>
+            {
+                self.add_return_map_options(num_dims, ui);
+            }
+            _ => {
+                self.use_return_map = false;
+            }
+        };
         ui.horizontal(|ui| {
             combo_box(LABEL_COLORMAP, &mut self.point_colormap, ui, TIP_COLORMAP);
         });
@@ -161,6 +221,125 @@ impl PlotPanel {
         });
     }
 
+    fn add_poincare_section_options(&mut self, num_dims: usize, ui: &mut Ui) {
+        let mut section_changed = false;
+        ui.horizontal(|ui| {
+            section_changed |= add_checkbox(
+                LABEL_POINCARE,
+                &mut self.use_poincare_section,
+                ui,
+                TIP_POINCARE,
+            );
+        });
+        if self.use_poincare_section {
+            let section = &mut self.poincare_section;
+            // components beyond the dimension of the system do not exist
+            section.normal[num_dims.min(MAX_SECTION_DIMS)..]
+                .iter_mut()
+                .for_each(|n| *n = 0.0);
+            if section.normal.iter().all(|n| *n == 0.0) {
+                *section =
+                    PoincareSection::axis_aligned(num_dims - 1, section.offset, section.direction);
+            }
+            ui.horizontal(|ui| {
+                // axis-aligned presets of the normal vector
+                let axis = section.axis();
+                let mut preset = axis.unwrap_or(num_dims);
+                let preset_label = match axis {
+                    Some(axis) => format!("State{}", axis + 1),
+                    None => String::from("Custom"),
+                };
+                let preset_variants = (0..num_dims)
+                    .map(|axis| (axis, format!("State{}", axis + 1)))
+                    .collect();
+                if combo_box_from_string(
+                    LABEL_SECTION_AXIS,
+                    (&mut preset, preset_label),
+                    ui,
+                    preset_variants,
+                    TIP_SECTION_AXIS,
+                ) {
+                    *section =
+                        PoincareSection::axis_aligned(preset, section.offset, section.direction);
+                    section_changed = true;
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.monospace(format!("{}:", LABEL_SECTION_NORMAL))
+                    .on_hover_text(TIP_SECTION_NORMAL);
+                section.normal[..num_dims.min(MAX_SECTION_DIMS)]
+                    .iter_mut()
+                    .for_each(|n| {
+                        section_changed |= ui
+                            .add(
+                                egui::DragValue::new(n)
+                                    .speed(0.1)
+                                    .clamp_range(PARAMETER_MIN..=PARAMETER_MAX),
+                            )
+                            .changed();
+                    });
+            });
+            ui.horizontal(|ui| {
+                ui.monospace(format!("{}:", LABEL_SECTION_VALUE))
+                    .on_hover_text(TIP_SECTION_VALUE);
+                section_changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut section.offset)
+                            .speed(0.1)
+                            .clamp_range(PARAMETER_MIN..=PARAMETER_MAX),
+                    )
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                section_changed |= combo_box(
+                    LABEL_SECTION_DIRECTION,
+                    &mut section.direction,
+                    ui,
+                    TIP_SECTION_DIRECTION,
+                );
+            });
+        }
+        if section_changed {
+            // previous series show states instead of section points
+            self.reset_plot_trajectory();
+        }
+    }
+
+    fn add_return_map_options(&mut self, num_dims: usize, ui: &mut Ui) {
+        let mut return_map_changed = false;
+        if self.return_map_component >= num_dims {
+            self.return_map_component = num_dims - 1;
+            return_map_changed = true;
+        }
+        ui.horizontal(|ui| {
+            return_map_changed |= add_checkbox(
+                LABEL_RETURN_MAP,
+                &mut self.use_return_map,
+                ui,
+                TIP_RETURN_MAP,
+            );
+        });
+        if self.use_return_map {
+            ui.horizontal(|ui| {
+                let component_label = format!("State{}", self.return_map_component + 1);
+                let component_variants = (0..num_dims)
+                    .map(|component| (component, format!("State{}", component + 1)))
+                    .collect();
+                return_map_changed |= combo_box_from_string(
+                    LABEL_RETURN_MAP_FEATURE,
+                    (&mut self.return_map_component, component_label),
+                    ui,
+                    component_variants,
+                    TIP_RETURN_MAP_FEATURE,
+                );
+            });
+        }
+        if return_map_changed {
+            // previous series show states or pairs of another feature
+            self.reset_plot_trajectory();
+        }
+    }
+
     pub fn conf_ui(&mut self, dims: DistributionDimensions, ui: &mut Ui) {
         group_vertical(ui, |ui| {
             ui.heading("Plot Configuration");
@@ -0,0 +1,342 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::DistributionDimensions;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::{Ui, Vec2};
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_TRANSIENT: usize = 10_000;
+const MAX_NUM_STEPS: usize = 20_000;
+const MAX_PERIOD: usize = 16;
+const MAX_ORBIT: usize = 8;
+const MAX_PERTURBATION: f64 = 1.0;
+const MAX_GAIN: f64 = 5.0;
+const MAX_DELAY: f64 = 50.0;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct ControlPanel {
+    #[serde(skip)] // start without control
+    run_control: bool,
+    #[serde(skip)] // control must be recomputed manually
+    series_plot: LinePlot,
+    #[serde(skip)] // control must be recomputed manually
+    signal_plot: LinePlot,
+    #[serde(skip)] // control must be recomputed manually
+    control_status: Result<ControlResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    switch_on: usize,
+    period: usize,
+    // starts at 1 for the orbit closest to the chaotic attractor
+    orbit: usize,
+    max_perturbation: f64,
+    gain: f64,
+    delay: f64,
+    component: usize,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for ControlPanel {
+    fn default() -> Self {
+        Self {
+            run_control: false,
+            series_plot: Default::default(),
+            signal_plot: Default::default(),
+            control_status: Err(String::from("No control computed yet.")),
+            selected_set: 0,
+            switch_on: 500,
+            period: 1,
+            orbit: 1,
+            max_perturbation: 0.1,
+            // period one orbit of the Rössler system with a=b=0.2 and c=5.7
+            gain: 0.2,
+            delay: 5.88,
+            component: 0,
+            num_transient: 100,
+            num_steps: 2000,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for ControlPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_control == other.run_control
+            && self.switch_on == other.switch_on
+            && self.period == other.period
+            && self.orbit == other.orbit
+            && self.max_perturbation == other.max_perturbation
+            && self.gain == other.gain
+            && self.delay == other.delay
+            && self.component == other.component
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for ControlPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_control {
+            self.run_control = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.control_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let conf = ControlConf {
+            switch_on: self.switch_on,
+            period: self.period,
+            orbit: self.orbit.saturating_sub(1),
+            max_perturbation: self.max_perturbation,
+            gain: self.gain,
+            delay: self.delay,
+            variable: self.component,
+        };
+        match chaos_control(schema, &conf) {
+            Ok(result) => {
+                self.control_status = Ok(result);
+                self.show_set(self.selected_set);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl ControlPanel {
+    fn show_set(&mut self, index: usize) {
+        self.series_plot = Default::default();
+        self.signal_plot = Default::default();
+        if let Ok(result) = &self.control_status {
+            self.selected_set = index.min(result.evolutions.len().saturating_sub(1));
+            if let Some(evolution) = result.evolutions.get(self.selected_set) {
+                let times: Vec<f64> = (0..evolution.states.len())
+                    .map(|step| step as f64 * result.sample_time)
+                    .collect();
+                let values: Vec<f64> = evolution
+                    .states
+                    .iter()
+                    .map(|state| state.get(self.component).copied().unwrap_or(f64::NAN))
+                    .collect();
+                // both phases share the state at the switch-on time
+                let switch_on = result.switch_on.min(values.len());
+                let controlled_start = switch_on.saturating_sub(1);
+                let component_label = format!("State{}", self.component + 1);
+                self.series_plot = LinePlot::new("control_series", "t", &component_label);
+                self.series_plot.add_line(
+                    String::from("Uncontrolled"),
+                    &times[..switch_on],
+                    &values[..switch_on],
+                );
+                self.series_plot.add_line(
+                    String::from("Controlled"),
+                    &times[controlled_start..],
+                    &values[controlled_start..],
+                );
+                self.signal_plot = LinePlot::new("control_signal", "t", "Control Signal");
+                self.signal_plot.add_line(
+                    String::from("Control Signal"),
+                    &times,
+                    &evolution.control_signals,
+                );
+            }
+        }
+        self.series_plot.set_colormap(self.colormap);
+        self.signal_plot.set_colormap(self.colormap);
+    }
+
+    fn set_names(result: &ControlResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.evolutions.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Chaos Control Configuration");
+        if let DistributionDimensions::State(num_dims) = dims {
+            if self.component >= num_dims {
+                self.component = num_dims - 1;
+            }
+            group_horizontal(ui, |ui| {
+                let component_label = format!("State{}", self.component + 1);
+                let component_variants = (0..num_dims)
+                    .map(|component| (component, format!("State{}", component + 1)))
+                    .collect();
+                combo_box_from_string(
+                    LABEL_CONTROL_COMPONENT,
+                    (&mut self.component, component_label),
+                    ui,
+                    component_variants,
+                    TIP_CONTROL_COMPONENT,
+                );
+            });
+        }
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_CONTROL_SWITCH_ON,
+                &mut self.switch_on,
+                self.num_steps.saturating_sub(1).max(1),
+                ui,
+                TIP_CONTROL_SWITCH_ON,
+            );
+        });
+        group_vertical(ui, |ui| {
+            ui.label("OGY Control of Discrete Maps");
+            integer_slider(
+                LABEL_CONTROL_PERIOD,
+                &mut self.period,
+                MAX_PERIOD,
+                ui,
+                TIP_CONTROL_PERIOD,
+            );
+            integer_slider(
+                LABEL_CONTROL_ORBIT,
+                &mut self.orbit,
+                MAX_ORBIT,
+                ui,
+                TIP_CONTROL_ORBIT,
+            );
+            float_slider(
+                LABEL_CONTROL_PERTURBATION,
+                &mut self.max_perturbation,
+                MAX_PERTURBATION,
+                ui,
+                TIP_CONTROL_PERTURBATION,
+            );
+        });
+        group_vertical(ui, |ui| {
+            ui.label("Pyragas Control of Flows");
+            float_slider(
+                LABEL_CONTROL_GAIN,
+                &mut self.gain,
+                MAX_GAIN,
+                ui,
+                TIP_CONTROL_GAIN,
+            );
+            float_slider(
+                LABEL_CONTROL_DELAY,
+                &mut self.delay,
+                MAX_DELAY,
+                ui,
+                TIP_CONTROL_DELAY,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_TRANSIENT,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        let set_names = match &self.control_status {
+            Ok(result) if result.evolutions.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.series_plot.set_colormap(self.colormap);
+                self.signal_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_CONTROL, false, is_ready, ui, TIP_CONTROL) {
+                self.run_control = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.control_status {
+            Ok(result) => {
+                Self::set_names(result)
+                    .into_iter()
+                    .zip(result.evolutions.iter())
+                    .for_each(|((index, name), evolution)| {
+                        if result.is_discrete && evolution.target_orbit.is_empty() {
+                            ui.label(format!("{name}: no orbit of period {} found", self.period));
+                            return;
+                        }
+                        if !evolution.target_orbit.is_empty() {
+                            let orbit: Vec<String> = evolution
+                                .target_orbit
+                                .iter()
+                                .map(|point| {
+                                    let coordinates: Vec<String> =
+                                        point.iter().map(|x| format!("{x:.4}")).collect();
+                                    format!("({})", coordinates.join(", "))
+                                })
+                                .collect();
+                            ui.label(format!("{name}: target orbit {}", orbit.join(" → ")));
+                        }
+                        match result.final_control_effort(index) {
+                            Some(effort) => {
+                                ui.label(format!("{name}: final control effort = {effort:.6}"));
+                            }
+                            None => {
+                                ui.label(format!("{name}: the state escaped before the control"));
+                            }
+                        }
+                    });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.control_status.is_ok() {
+            // the chosen component before and after the switch-on above the control signal
+            let size = Vec2::new(ui.available_width(), ui.available_height() * 2.0 / 3.0);
+            ui.allocate_ui(size, |ui| self.series_plot.ui(ui));
+            self.signal_plot.ui(ui);
+        }
+    }
+}
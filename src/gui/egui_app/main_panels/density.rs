@@ -0,0 +1,288 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::DistributionDimensions;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_STEPS: usize = 100_000;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct DensityPanel {
+    #[serde(skip)] // start without computing densities
+    run_density: bool,
+    #[serde(skip)] // densities must be recomputed manually
+    line_plot: LinePlot,
+    #[serde(skip)] // densities must be recomputed manually
+    heat_map: HeatMap,
+    #[serde(skip)] // densities must be recomputed manually
+    density_status: Result<DensityResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    num_transient: usize,
+    num_steps: usize,
+    x_component: usize,
+    y_component: Option<usize>,
+    num_bins: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for DensityPanel {
+    fn default() -> Self {
+        let conf = DensityConf::default();
+        Self {
+            run_density: false,
+            line_plot: Default::default(),
+            heat_map: Default::default(),
+            density_status: Err(String::from("No density computed yet.")),
+            selected_set: 0,
+            num_transient: 1000,
+            num_steps: 10_000,
+            x_component: conf.x_component,
+            y_component: conf.y_component,
+            num_bins: conf.num_bins,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for DensityPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_density == other.run_density
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.x_component == other.x_component
+            && self.y_component == other.y_component
+            && self.num_bins == other.num_bins
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for DensityPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_density {
+            self.run_density = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.density_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let conf = DensityConf {
+            x_component: self.x_component,
+            y_component: self.y_component,
+            num_bins: self.num_bins,
+        };
+        match invariant_densities(schema, &conf) {
+            Ok(result) => {
+                self.density_status = Ok(result);
+                self.show_set(0);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl DensityPanel {
+    fn show_set(&mut self, index: usize) {
+        if let Ok(result) = &self.density_status {
+            self.selected_set = index;
+            let histogram = &result.histograms[index];
+            let labels = histogram.labels();
+            let density = histogram.density();
+            if let [x_range, y_range] = histogram.ranges() {
+                let num_bins = histogram.num_bins();
+                // empty bins get the special color
+                let values = density
+                    .into_iter()
+                    .map(|d| if d > 0.0 { Some(d) } else { None })
+                    .collect();
+                self.heat_map = HeatMap::new(
+                    values,
+                    (labels[0].as_str(), *x_range, num_bins),
+                    (labels[1].as_str(), *y_range, num_bins),
+                );
+                self.heat_map.set_colormap(self.colormap);
+            } else {
+                self.line_plot = LinePlot::new("density", labels[0].as_str(), "Density");
+                self.line_plot.add_line(
+                    String::from("Density"),
+                    &histogram.bin_centers(0),
+                    &density,
+                );
+                self.line_plot.set_colormap(self.colormap);
+            }
+        }
+    }
+
+    fn set_names(result: &DensityResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.histograms.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    fn component_selection(&mut self, num_dims: usize, ui: &mut Ui) {
+        if self.x_component >= num_dims {
+            self.x_component = 0;
+        }
+        if self.y_component.is_some_and(|y| y >= num_dims) {
+            self.y_component = None;
+        }
+        let component_name = |component: usize| format!("State{}", component + 1);
+        group_horizontal(ui, |ui| {
+            let x_variants = (0..num_dims)
+                .map(|component| (component, component_name(component)))
+                .collect();
+            combo_box_from_string(
+                LABEL_DENSITY_X,
+                (&mut self.x_component, component_name(self.x_component)),
+                ui,
+                x_variants,
+                TIP_DENSITY_X,
+            );
+        });
+        if num_dims > 1 {
+            let y_name = |component: Option<usize>| match component {
+                Some(component) => component_name(component),
+                None => String::from("None"),
+            };
+            group_horizontal(ui, |ui| {
+                let y_variants = std::iter::once(None)
+                    .chain((0..num_dims).map(Some))
+                    .map(|component| (component, y_name(component)))
+                    .collect();
+                combo_box_from_string(
+                    LABEL_DENSITY_Y,
+                    (&mut self.y_component, y_name(self.y_component)),
+                    ui,
+                    y_variants,
+                    TIP_DENSITY_Y,
+                );
+            });
+        }
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Invariant Density Configuration");
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_TRAJECTORY_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_TRAJECTORY_STEPS,
+            );
+        });
+        if let DistributionDimensions::State(num_dims) = dims {
+            self.component_selection(num_dims, ui);
+        }
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_DENSITY_BINS,
+                &mut self.num_bins,
+                MAX_DENSITY_BINS,
+                ui,
+                TIP_DENSITY_BINS,
+            );
+        });
+        let set_names = match &self.density_status {
+            Ok(result) if result.histograms.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.line_plot.set_colormap(self.colormap);
+                self.heat_map.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_DENSITY, false, is_ready, ui, TIP_DENSITY) {
+                self.run_density = true;
+            };
+            let shown_histogram = match &self.density_status {
+                Ok(result) => result.histograms.get(self.selected_set),
+                Err(_) => None,
+            };
+            if clickable_button(
+                LABEL_COPY_DENSITY,
+                false,
+                shown_histogram.is_some(),
+                ui,
+                TIP_COPY_DENSITY,
+            ) {
+                if let Some(histogram) = shown_histogram {
+                    let csv = histogram.to_csv();
+                    ui.output_mut(|output| output.copied_text = csv);
+                }
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_horizontal(ui, |ui| match &self.density_status {
+            Ok(result) => {
+                let histogram = &result.histograms[self.selected_set];
+                ui.label(format!(
+                    "Binned states: {} Bins: {}",
+                    histogram.num_states(),
+                    histogram.num_bins().pow(histogram.ranges().len() as u32)
+                ));
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if let Ok(result) = &self.density_status {
+            if result.histograms[self.selected_set].ranges().len() > 1 {
+                self.heat_map.ui(ui);
+            } else {
+                self.line_plot.ui(ui);
+            }
+        }
+    }
+}
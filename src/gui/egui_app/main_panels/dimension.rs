@@ -0,0 +1,362 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::{ChaosDataVec, ChaosFloat};
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use anyhow::{bail, Error};
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_POINTS: usize = 20_000;
+const MAX_THEILER_WINDOW: usize = 1000;
+const MAX_NUM_STEPS: usize = 50_000;
+
+struct DimensionSummary {
+    description: &'static str,
+    symbol: &'static str,
+    estimates: Vec<(String, Option<LinearFit>)>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct DimensionPanel {
+    #[serde(skip)] // start without computing a dimension
+    run_dimension: bool,
+    #[serde(skip)] // dimensions must be recomputed manually
+    dimension_plot: LogLogPlot,
+    #[serde(skip)] // dimensions must be recomputed manually
+    dimension_status: Result<DimensionSummary, String>,
+    estimator: DimensionEstimator,
+    source: PointSource,
+    max_points: usize,
+    theiler_window: usize,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for DimensionPanel {
+    fn default() -> Self {
+        let conf = CorrelationConf::default();
+        let trajectory_conf = TrajectoryConf::default();
+        let estimator = DimensionEstimator::default();
+        Self {
+            run_dimension: false,
+            dimension_plot: Self::dimension_plot(estimator),
+            dimension_status: Err(String::from("No dimension computed yet.")),
+            estimator,
+            source: Default::default(),
+            max_points: conf.max_points,
+            theiler_window: conf.theiler_window,
+            num_transient: trajectory_conf.num_transient,
+            num_steps: trajectory_conf.num_steps,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for DimensionPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_dimension == other.run_dimension
+            && self.estimator == other.estimator
+            && self.source == other.source
+            && self.max_points == other.max_points
+            && self.theiler_window == other.theiler_window
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for DimensionPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_dimension {
+            self.run_dimension = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.dimension_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        match record_trajectories(schema) {
+            Ok(recorded) => {
+                let names = self.data_set_names(&recorded.pars, recorded.trajectories.len());
+                let point_clouds = (0..recorded.trajectories.len())
+                    .map(|index| {
+                        // complete trajectories are concatenated
+                        recorded
+                            .complete_trajectories(index)
+                            .into_iter()
+                            .flatten()
+                            .cloned()
+                            .collect()
+                    })
+                    .collect::<Vec<_>>();
+                self.point_cloud_dimensions(names.into_iter().zip(point_clouds).collect());
+            }
+            Err(e) => self.set_error(e.to_string()),
+        }
+    }
+
+    fn observable_only(&self) -> bool {
+        true
+    }
+}
+
+impl DimensionPanel {
+    fn dimension_plot(estimator: DimensionEstimator) -> LogLogPlot {
+        match estimator {
+            DimensionEstimator::Correlation => LogLogPlot::new("correlation_sum", "r", "C(r)"),
+            DimensionEstimator::BoxCounting => LogLogPlot::new("box_count", "1/ε", "N(ε)"),
+        }
+    }
+
+    pub fn source(&self) -> PointSource {
+        self.source
+    }
+
+    fn correlation_conf(&self) -> CorrelationConf {
+        let theiler_window = match self.source {
+            PointSource::Ensemble => 0, // samples are not ordered in time
+            PointSource::Trajectory => self.theiler_window,
+        };
+        CorrelationConf {
+            max_points: self.max_points,
+            theiler_window,
+        }
+    }
+
+    /// Dimension of the first data set with its 95% confidence interval.
+    pub fn estimate(&self) -> Option<String> {
+        let summary = self.dimension_status.as_ref().ok()?;
+        let (_, fit) = summary.estimates.first()?;
+        fit.map(|fit| {
+            format!(
+                "{} {} = {:.3} ± {:.3}",
+                summary.description,
+                summary.symbol,
+                fit.slope,
+                fit.slope_confidence_95()
+            )
+        })
+    }
+
+    fn set_curves(
+        &mut self,
+        description: &'static str,
+        named_curves: Vec<(String, Option<ScalingCurve>)>,
+    ) {
+        self.dimension_plot = Self::dimension_plot(self.estimator);
+        let estimates = named_curves
+            .into_iter()
+            .map(|(name, curve)| {
+                if let Some(curve) = &curve {
+                    self.dimension_plot
+                        .add_line(name.clone(), &curve.log_x, &curve.log_y);
+                    if let Some(((start, end), fit)) = curve.fit {
+                        let (x_start, x_end) = (curve.log_x[start], curve.log_x[end - 1]);
+                        self.dimension_plot
+                            .set_fit([x_start, fit.value(x_start)], [x_end, fit.value(x_end)]);
+                    }
+                }
+                (name, curve.and_then(|curve| curve.scaling_fit()))
+            })
+            .collect();
+        self.dimension_plot.set_colormap(self.colormap);
+        self.dimension_status = Ok(DimensionSummary {
+            description,
+            symbol: self.estimator.symbol(),
+            estimates,
+        });
+    }
+
+    fn point_cloud_dimensions(&mut self, named_points: Vec<(String, Vec<Vec<ChaosFloat>>)>) {
+        let conf = self.correlation_conf();
+        let estimator = self.estimator;
+        let named_curves = named_points
+            .into_iter()
+            .map(|(name, points)| {
+                let curve = match estimator {
+                    DimensionEstimator::Correlation => correlation_sums(&points, &conf),
+                    DimensionEstimator::BoxCounting => box_counts(&points, None),
+                };
+                (name, curve)
+            })
+            .collect();
+        let description = match estimator {
+            DimensionEstimator::Correlation => "Correlation dimension",
+            DimensionEstimator::BoxCounting => "Box-counting dimension",
+        };
+        self.set_curves(description, named_curves);
+    }
+
+    fn boundary_dimensions(
+        &mut self,
+        named_membership: Vec<(String, (Vec<Vec<ChaosFloat>>, Vec<bool>))>,
+    ) {
+        let named_curves = named_membership
+            .into_iter()
+            .map(|(name, (points, inside))| (name, box_counts(&points, Some(&inside))))
+            .collect();
+        self.set_curves("Boundary box-counting dimension", named_curves);
+    }
+
+    fn data_set_names(&self, par: &(&'static str, Vec<f64>), num_sets: usize) -> Vec<String> {
+        let (par, par_values) = par;
+        (0..num_sets)
+            .map(|index| match par_values.get(index) {
+                Some(par_value) if num_sets > 1 => format!("{par}={par_value:.3}"),
+                _ => String::from(self.estimator.symbol()),
+            })
+            .collect()
+    }
+
+    fn try_ensemble_dimension(
+        &mut self,
+        data: ChaosDataVec<'_>,
+        par: (&'static str, Vec<f64>),
+    ) -> Result<(), Error> {
+        match data {
+            ChaosDataVec::FractalComplex(_)
+            | ChaosDataVec::FractalDual(_)
+            | ChaosDataVec::FractalPerplex(_)
+            | ChaosDataVec::FractalQuaternion(_) => {
+                if self.estimator != DimensionEstimator::BoxCounting {
+                    bail!("Fractal boundaries are measured by box counting only!");
+                }
+                let membership = fractal_membership(&data)?;
+                let names = self.data_set_names(&par, membership.len());
+                self.boundary_dimensions(names.into_iter().zip(membership).collect());
+            }
+            _ => {
+                let point_clouds = ensemble_points(&data)?;
+                let names = self.data_set_names(&par, point_clouds.len());
+                self.point_cloud_dimensions(names.into_iter().zip(point_clouds).collect());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ensemble_dimension(
+        &mut self,
+        data: Result<ChaosDataVec<'_>, Error>,
+        par: (&'static str, Vec<f64>),
+    ) {
+        if let Err(e) = data.and_then(|data| self.try_ensemble_dimension(data, par)) {
+            self.set_error(e.to_string());
+        }
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, ui: &mut Ui) {
+        ui.heading("Dimension Configuration");
+        group_horizontal(ui, |ui| {
+            combo_box(
+                LABEL_DIMENSION_ESTIMATOR,
+                &mut self.estimator,
+                ui,
+                TIP_DIMENSION_ESTIMATOR,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            combo_box(LABEL_POINT_SOURCE, &mut self.source, ui, TIP_POINT_SOURCE);
+        });
+        let is_correlation = self.estimator == DimensionEstimator::Correlation;
+        if is_correlation {
+            group_horizontal(ui, |ui| {
+                integer_slider(
+                    LABEL_MAX_POINTS,
+                    &mut self.max_points,
+                    MAX_NUM_POINTS,
+                    ui,
+                    TIP_MAX_POINTS,
+                );
+            });
+        }
+        if self.source == PointSource::Trajectory {
+            group_horizontal(ui, |ui| {
+                integer_slider(
+                    LABEL_NUM_TRANSIENT,
+                    &mut self.num_transient,
+                    MAX_NUM_STEPS,
+                    ui,
+                    TIP_NUM_TRANSIENT,
+                );
+            });
+            group_horizontal(ui, |ui| {
+                integer_slider(
+                    LABEL_TRAJECTORY_STEPS,
+                    &mut self.num_steps,
+                    MAX_NUM_STEPS,
+                    ui,
+                    TIP_TRAJECTORY_STEPS,
+                );
+            });
+            if is_correlation {
+                group_horizontal(ui, |ui| {
+                    integer_slider(
+                        LABEL_THEILER_WINDOW,
+                        &mut self.theiler_window,
+                        MAX_THEILER_WINDOW,
+                        ui,
+                        TIP_THEILER_WINDOW,
+                    );
+                });
+            }
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.dimension_plot.set_colormap(self.colormap);
+            }
+            let is_ready = is_ready || self.source == PointSource::Ensemble;
+            if clickable_button(LABEL_DIMENSION, false, is_ready, ui, TIP_DIMENSION) {
+                self.run_dimension = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.dimension_status {
+            Ok(summary) => {
+                ui.label(summary.description);
+                summary.estimates.iter().for_each(|(name, fit)| match fit {
+                    Some(fit) => {
+                        ui.label(format!(
+                            "{name}: {} = {:.3} ± {:.3} (95% CI, R² = {:.4})",
+                            summary.symbol,
+                            fit.slope,
+                            fit.slope_confidence_95(),
+                            fit.r_squared
+                        ));
+                    }
+                    None => {
+                        ui.label(format!("{name}: not enough distinct points"));
+                    }
+                });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.dimension_status.is_ok() {
+            self.dimension_plot.ui(ui);
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::{ChaosData, ChaosDataVec, ChaosFloat, DistributionDimensions, State3};
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::{Ui, Vec2};
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_TRANSIENT: usize = 10_000;
+// the false nearest neighbours search all recorded states
+const MAX_NUM_STEPS: usize = 20_000;
+const MAX_DELAY: usize = 200;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct EmbeddingPanel {
+    #[serde(skip)] // start without an embedding
+    run_embedding: bool,
+    #[serde(skip)] // embeddings must be recomputed manually
+    information_plot: LinePlot,
+    #[serde(skip)] // embeddings must be recomputed manually
+    neighbours_plot: LinePlot,
+    #[serde(skip)] // embeddings must be recomputed manually
+    reconstructed_plot: Plot3D,
+    #[serde(skip)] // embeddings must be recomputed manually
+    original_plot: Plot3D,
+    #[serde(skip)] // embeddings must be recomputed manually
+    embedding_status: Result<EmbeddingResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    component: usize,
+    max_delay: usize,
+    auto_delay: bool,
+    delay: usize,
+    auto_dimension: bool,
+    dimension: usize,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for EmbeddingPanel {
+    fn default() -> Self {
+        let trajectory_conf = TrajectoryConf::default();
+        let conf = EmbeddingConf::default();
+        Self {
+            run_embedding: false,
+            information_plot: Default::default(),
+            neighbours_plot: Default::default(),
+            reconstructed_plot: Default::default(),
+            original_plot: Default::default(),
+            embedding_status: Err(String::from("No embedding computed yet.")),
+            selected_set: 0,
+            component: conf.component,
+            max_delay: conf.max_delay,
+            auto_delay: true,
+            delay: 1,
+            auto_dimension: true,
+            dimension: 3,
+            num_transient: trajectory_conf.num_transient,
+            num_steps: 3000,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for EmbeddingPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_embedding == other.run_embedding
+            && self.component == other.component
+            && self.max_delay == other.max_delay
+            && self.auto_delay == other.auto_delay
+            && self.delay == other.delay
+            && self.auto_dimension == other.auto_dimension
+            && self.dimension == other.dimension
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for EmbeddingPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_embedding {
+            self.run_embedding = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.embedding_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let conf = EmbeddingConf {
+            component: self.component,
+            max_delay: self.max_delay,
+            delay: (!self.auto_delay).then_some(self.delay),
+            dimension: (!self.auto_dimension).then_some(self.dimension),
+        };
+        match record_trajectories(schema).and_then(|recorded| embedding_analysis(recorded, &conf)) {
+            Ok(result) => {
+                self.embedding_status = Ok(result);
+                self.show_set(0);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+
+    fn observable_only(&self) -> bool {
+        true
+    }
+}
+
+impl EmbeddingPanel {
+    fn states_3_d(points: &[Vec<ChaosFloat>]) -> ChaosData<State3> {
+        let mut data = ChaosData::default();
+        // missing components of low dimensional systems are zero
+        let component = |point: &Vec<ChaosFloat>, i: usize| point.get(i).copied().unwrap_or(0.0);
+        *data.data_mut() = points
+            .iter()
+            .map(|point| {
+                Some(State3::new(
+                    component(point, 0),
+                    component(point, 1),
+                    component(point, 2),
+                ))
+            })
+            .collect();
+        data
+    }
+
+    fn show_set(&mut self, index: usize) {
+        self.selected_set = index;
+        self.information_plot = Default::default();
+        self.neighbours_plot = Default::default();
+        self.reconstructed_plot.reset_data();
+        self.original_plot.reset_data();
+        if let Ok(result) = &self.embedding_status {
+            if let Some(Some(embedding)) = result.embeddings.get(index) {
+                let delays: Vec<f64> = (0..embedding.mutual_information.len())
+                    .map(|delay| delay as f64)
+                    .collect();
+                self.information_plot =
+                    LinePlot::new("mutual_information", "Delay", "Mutual Information");
+                self.information_plot.add_line(
+                    String::from("Mutual Information"),
+                    &delays,
+                    &embedding.mutual_information,
+                );
+                let dimensions: Vec<f64> = (1..=embedding.false_neighbours.len())
+                    .map(|dimension| dimension as f64)
+                    .collect();
+                self.neighbours_plot =
+                    LinePlot::new("false_neighbours", "Dimension", "False Neighbours");
+                self.neighbours_plot.add_line(
+                    String::from("False Neighbours"),
+                    &dimensions,
+                    &embedding.false_neighbours,
+                );
+                let reconstructed = Self::states_3_d(&embedding.reconstructed);
+                self.reconstructed_plot
+                    .add_point_series(ChaosDataVec::State3(vec![&reconstructed]));
+                let original = Self::states_3_d(&embedding.original);
+                self.original_plot
+                    .add_point_series(ChaosDataVec::State3(vec![&original]));
+            }
+        }
+        self.set_colormap();
+    }
+
+    fn set_colormap(&mut self) {
+        self.information_plot.set_colormap(self.colormap);
+        self.neighbours_plot.set_colormap(self.colormap);
+        self.reconstructed_plot.set_point_colormap(self.colormap);
+        self.original_plot.set_point_colormap(self.colormap);
+    }
+
+    fn set_names(result: &EmbeddingResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.embeddings.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Embedding Configuration");
+        if let DistributionDimensions::State(num_dims) = dims {
+            if self.component >= num_dims {
+                self.component = num_dims - 1;
+            }
+            group_horizontal(ui, |ui| {
+                let component_label = format!("State{}", self.component + 1);
+                let component_variants = (0..num_dims)
+                    .map(|component| (component, format!("State{}", component + 1)))
+                    .collect();
+                combo_box_from_string(
+                    LABEL_SPECTRUM_COMPONENT,
+                    (&mut self.component, component_label),
+                    ui,
+                    component_variants,
+                    TIP_SPECTRUM_COMPONENT,
+                );
+            });
+        }
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_TRANSIENT,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_MAX_DELAY,
+                &mut self.max_delay,
+                MAX_DELAY,
+                ui,
+                TIP_MAX_DELAY,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            add_checkbox(LABEL_AUTO_DELAY, &mut self.auto_delay, ui, TIP_AUTO_DELAY);
+            if !self.auto_delay {
+                integer_slider(
+                    LABEL_EMBEDDING_DELAY,
+                    &mut self.delay,
+                    MAX_DELAY,
+                    ui,
+                    TIP_EMBEDDING_DELAY,
+                );
+            }
+        });
+        group_horizontal(ui, |ui| {
+            add_checkbox(
+                LABEL_AUTO_DIMENSION,
+                &mut self.auto_dimension,
+                ui,
+                TIP_AUTO_DIMENSION,
+            );
+            if !self.auto_dimension {
+                integer_slider(
+                    LABEL_EMBEDDING_DIMENSION,
+                    &mut self.dimension,
+                    MAX_EMBEDDING_DIMENSION,
+                    ui,
+                    TIP_EMBEDDING_DIMENSION,
+                );
+            }
+        });
+        let set_names = match &self.embedding_status {
+            Ok(result) if result.embeddings.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.set_colormap();
+            }
+            if clickable_button(LABEL_EMBEDDING, false, is_ready, ui, TIP_EMBEDDING) {
+                self.run_embedding = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.embedding_status {
+            Ok(result) => {
+                Self::set_names(result)
+                    .into_iter()
+                    .zip(result.embeddings.iter())
+                    .for_each(|((_, name), embedding)| match embedding {
+                        Some(embedding) => {
+                            ui.label(format!(
+                                "{name}: delay = {} dimension = {}",
+                                embedding.delay, embedding.dimension
+                            ));
+                        }
+                        None => {
+                            ui.label(format!("{name}: all samples escaped or are constant"));
+                        }
+                    });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, mouse_is_over_plot: bool, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.embedding_status.is_ok() {
+            // the curves take a third of the height above both attractors
+            let curve_height = ui.available_height() / 3.0;
+            ui.columns(2, |columns| {
+                let size = Vec2::new(columns[0].available_width(), curve_height);
+                columns[0].allocate_ui(size, |ui| self.information_plot.ui(ui));
+                columns[1].allocate_ui(size, |ui| self.neighbours_plot.ui(ui));
+            });
+            ui.columns(2, |columns| {
+                columns[0].label("Reconstructed");
+                self.reconstructed_plot
+                    .ui(mouse_is_over_plot, &mut columns[0]);
+                columns[1].label("Original");
+                self.original_plot.ui(mouse_is_over_plot, &mut columns[1]);
+            });
+        }
+    }
+}
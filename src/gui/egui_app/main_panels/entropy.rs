@@ -0,0 +1,238 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::DistributionDimensions;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_STEPS: usize = 50_000;
+const MAX_DELAY: usize = 100;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct EntropyPanel {
+    #[serde(skip)] // start without computing entropies
+    run_entropy: bool,
+    #[serde(skip)] // entropies must be recomputed manually
+    entropy_plot: LinePlot,
+    #[serde(skip)] // entropies must be recomputed manually
+    entropy_status: Result<EntropyCurve, String>,
+    measure: EntropyMeasure,
+    component: usize,
+    order: usize,
+    delay: usize,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for EntropyPanel {
+    fn default() -> Self {
+        let conf = EntropyConf::default();
+        let trajectory_conf = TrajectoryConf::default();
+        Self {
+            run_entropy: false,
+            entropy_plot: Default::default(),
+            entropy_status: Err(String::from("No entropy computed yet.")),
+            measure: conf.measure,
+            component: conf.component,
+            order: conf.order,
+            delay: conf.delay,
+            num_transient: trajectory_conf.num_transient,
+            num_steps: trajectory_conf.num_steps,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for EntropyPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_entropy == other.run_entropy
+            && self.measure == other.measure
+            && self.component == other.component
+            && self.order == other.order
+            && self.delay == other.delay
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for EntropyPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_entropy {
+            self.run_entropy = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.entropy_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let conf = EntropyConf {
+            measure: self.measure,
+            component: self.component,
+            order: self.order,
+            delay: self.delay,
+        };
+        match entropy_curve(schema, &conf) {
+            Ok(curve) => {
+                let (par, par_values) = &curve.pars;
+                let (x_label, xs) = if par_values.len() == curve.entropies.len() {
+                    (*par, par_values.clone())
+                } else {
+                    (
+                        "Set",
+                        (1..=curve.entropies.len()).map(|i| i as f64).collect(),
+                    )
+                };
+                let ys: Vec<f64> = curve
+                    .entropies
+                    .iter()
+                    .map(|entropy| entropy.unwrap_or(f64::NAN))
+                    .collect();
+                let measure_label: &'static str = curve.measure.into();
+                self.entropy_plot = LinePlot::new("entropy", x_label, measure_label);
+                self.entropy_plot
+                    .add_line(String::from(measure_label), &xs, &ys);
+                self.entropy_plot.set_colormap(self.colormap);
+                self.entropy_status = Ok(curve);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        }
+    }
+
+    fn observable_only(&self) -> bool {
+        self.measure != EntropyMeasure::Topological
+    }
+}
+
+impl EntropyPanel {
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Entropy Configuration");
+        group_horizontal(ui, |ui| {
+            combo_box(
+                LABEL_ENTROPY_MEASURE,
+                &mut self.measure,
+                ui,
+                TIP_ENTROPY_MEASURE,
+            );
+        });
+        if self.measure != EntropyMeasure::Topological {
+            if let DistributionDimensions::State(num_dims) = dims {
+                if self.component >= num_dims {
+                    self.component = num_dims - 1;
+                }
+                group_horizontal(ui, |ui| {
+                    let component_label = format!("State{}", self.component + 1);
+                    let component_variants = (0..num_dims)
+                        .map(|component| (component, format!("State{}", component + 1)))
+                        .collect();
+                    combo_box_from_string(
+                        LABEL_SPECTRUM_COMPONENT,
+                        (&mut self.component, component_label),
+                        ui,
+                        component_variants,
+                        TIP_SPECTRUM_COMPONENT,
+                    );
+                });
+            }
+            if self.measure == EntropyMeasure::Permutation {
+                group_horizontal(ui, |ui| {
+                    integer_slider(
+                        LABEL_PERMUTATION_ORDER,
+                        &mut self.order,
+                        MAX_PERMUTATION_ORDER,
+                        ui,
+                        TIP_PERMUTATION_ORDER,
+                    );
+                    if self.order < 2 {
+                        self.order = 2;
+                    }
+                });
+            }
+            group_horizontal(ui, |ui| {
+                integer_slider(
+                    LABEL_PERMUTATION_DELAY,
+                    &mut self.delay,
+                    MAX_DELAY,
+                    ui,
+                    TIP_PERMUTATION_DELAY,
+                );
+            });
+            group_horizontal(ui, |ui| {
+                integer_slider(
+                    LABEL_NUM_TRANSIENT,
+                    &mut self.num_transient,
+                    MAX_NUM_STEPS,
+                    ui,
+                    TIP_NUM_TRANSIENT,
+                );
+            });
+            group_horizontal(ui, |ui| {
+                integer_slider(
+                    LABEL_TRAJECTORY_STEPS,
+                    &mut self.num_steps,
+                    MAX_NUM_STEPS,
+                    ui,
+                    TIP_TRAJECTORY_STEPS,
+                );
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.entropy_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_ENTROPY, false, is_ready, ui, TIP_ENTROPY) {
+                self.run_entropy = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_horizontal(ui, |ui| match &self.entropy_status {
+            Ok(curve) => {
+                let measure_label: &'static str = curve.measure.into();
+                ui.label(measure_label);
+                let entropies: Vec<f64> = curve.entropies.iter().flatten().copied().collect();
+                match entropies.as_slice() {
+                    [] => {
+                        ui.label("All samples escaped");
+                    }
+                    [entropy] => {
+                        ui.label(format!("{entropy:.4}"));
+                    }
+                    _ => {
+                        let min = entropies.iter().copied().fold(f64::INFINITY, f64::min);
+                        let max = entropies.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                        ui.label(format!("Min: {min:.4} Max: {max:.4}"));
+                    }
+                }
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.entropy_status.is_ok() {
+            self.entropy_plot.ui(ui);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::CouplingMatrix;
+use crate::chaos::functions::ODE_STEP_SIZE;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::{Ui, Vec2};
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_STEPS: usize = 5000;
+const ADJACENCY_HINT: &str = "0 1 0\n1 0 1\n0 1 0";
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct KuramotoPanel {
+    #[serde(skip)] // start without computing order parameters
+    run_kuramoto: bool,
+    #[serde(skip)] // order parameters must be recomputed manually
+    order_plot: LinePlot,
+    #[serde(skip)] // order parameters must be recomputed manually
+    transition_plot: LinePlot,
+    #[serde(skip)] // order parameters must be recomputed manually
+    kuramoto_status: Result<KuramotoResult, String>,
+    use_adjacency: bool,
+    adjacency_text: String,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for KuramotoPanel {
+    fn default() -> Self {
+        Self {
+            run_kuramoto: false,
+            order_plot: Default::default(),
+            transition_plot: Default::default(),
+            kuramoto_status: Err(String::from("No order parameters computed yet.")),
+            use_adjacency: false,
+            adjacency_text: String::new(),
+            num_transient: 500,
+            num_steps: 500,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for KuramotoPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_kuramoto == other.run_kuramoto
+            && self.use_adjacency == other.use_adjacency
+            && self.adjacency_text == other.adjacency_text
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for KuramotoPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_kuramoto {
+            self.run_kuramoto = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.kuramoto_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let coupling = if self.use_adjacency {
+            match CouplingMatrix::from_text(&self.adjacency_text) {
+                Ok(coupling) => Some(coupling),
+                Err(e) => {
+                    self.set_error(e.to_string());
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+        match kuramoto_order_parameters(schema, coupling) {
+            Ok(result) => {
+                self.show_result(&result);
+                self.kuramoto_status = Ok(result);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl KuramotoPanel {
+    fn show_result(&mut self, result: &KuramotoResult) {
+        let names = Self::set_names(result);
+        self.order_plot = LinePlot::new("order_parameter", "t", "Order Parameter r");
+        result
+            .order_parameters
+            .iter()
+            .zip(names)
+            .for_each(|(order_parameters, name)| {
+                let times: Vec<f64> = (1..=order_parameters.len())
+                    .map(|step| step as f64 * ODE_STEP_SIZE)
+                    .collect();
+                self.order_plot.add_line(name, &times, order_parameters);
+            });
+        self.order_plot.set_colormap(self.colormap);
+        // the synchronization transition over the swept coupling strength
+        self.transition_plot = Default::default();
+        let (par, par_values) = &result.pars;
+        if par_values.len() > 1 && par_values.len() == result.order_parameters.len() {
+            let mean_order_parameters: Vec<f64> = (0..par_values.len())
+                .map(|index| result.mean_order_parameter(index).unwrap_or(f64::NAN))
+                .collect();
+            self.transition_plot = LinePlot::new("synchronization", par, "Mean Order Parameter r");
+            self.transition_plot.add_line(
+                String::from("Mean Order Parameter"),
+                par_values,
+                &mean_order_parameters,
+            );
+        }
+        self.transition_plot.set_colormap(self.colormap);
+    }
+
+    fn set_names(result: &KuramotoResult) -> Vec<String> {
+        let (par, par_values) = &result.pars;
+        (0..result.order_parameters.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => format!("{par}={par_value:.3}"),
+                None => format!("Set {}", index + 1),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, ui: &mut Ui) {
+        ui.heading("Kuramoto Network Configuration");
+        group_vertical(ui, |ui| {
+            add_checkbox(
+                LABEL_USE_ADJACENCY,
+                &mut self.use_adjacency,
+                ui,
+                TIP_USE_ADJACENCY,
+            );
+            if self.use_adjacency {
+                egui::ScrollArea::vertical()
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(&mut self.adjacency_text)
+                                .hint_text(ADJACENCY_HINT)
+                                .code_editor()
+                                .desired_rows(5),
+                        );
+                    });
+            }
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.order_plot.set_colormap(self.colormap);
+                self.transition_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_KURAMOTO, false, is_ready, ui, TIP_KURAMOTO) {
+                self.run_kuramoto = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.kuramoto_status {
+            Ok(result) => match result.order_parameters.len() {
+                1 => match result.mean_order_parameter(0) {
+                    Some(r) => {
+                        ui.label(format!("Mean order parameter: r = {r:.4}"));
+                    }
+                    None => {
+                        ui.label("The oscillators escaped during the transient.");
+                    }
+                },
+                num_sets => {
+                    let num_synchronized = (0..num_sets)
+                        .filter_map(|index| result.mean_order_parameter(index))
+                        .filter(|r| *r > 0.9)
+                        .count();
+                    ui.label(format!(
+                        "Synchronized networks (r > 0.9): {num_synchronized} of {num_sets}"
+                    ));
+                }
+            },
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if let Ok(result) = &self.kuramoto_status {
+            if result.order_parameters.len() > 1 {
+                // r(t) above the mean order parameter over the coupling strength
+                let size = Vec2::new(ui.available_width(), ui.available_height() / 2.0);
+                ui.allocate_ui(size, |ui| self.order_plot.ui(ui));
+                self.transition_plot.ui(ui);
+            } else {
+                self.order_plot.ui(ui);
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::{Ui, Vec2};
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_TRANSIENT: usize = 10_000;
+// the space-time plot has one pixel per site and step
+const MAX_NUM_STEPS: usize = 2000;
+const MAX_RING_SITES: usize = 1000;
+const MAX_GRID_SITES: usize = 64;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct LatticePanel {
+    #[serde(skip)] // start without a lattice
+    run_lattice: bool,
+    #[serde(skip)] // lattices must be recomputed manually
+    heat_map: HeatMap,
+    #[serde(skip)] // lattices must be recomputed manually
+    line_plot: LinePlot,
+    #[serde(skip)] // lattices must be recomputed manually
+    lattice_status: Result<LatticeResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    topology: LatticeTopology,
+    num_sites: usize,
+    coupling: f64,
+    show_snapshot: bool,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for LatticePanel {
+    fn default() -> Self {
+        let conf = LatticeConf::default();
+        Self {
+            run_lattice: false,
+            heat_map: Default::default(),
+            line_plot: Default::default(),
+            lattice_status: Err(String::from("No lattice computed yet.")),
+            selected_set: 0,
+            topology: conf.topology,
+            num_sites: conf.num_sites,
+            coupling: conf.coupling,
+            show_snapshot: false,
+            num_transient: 500,
+            num_steps: 500,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for LatticePanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_lattice == other.run_lattice
+            && self.topology == other.topology
+            && self.num_sites == other.num_sites
+            && self.coupling == other.coupling
+            && self.show_snapshot == other.show_snapshot
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for LatticePanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_lattice {
+            self.run_lattice = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.lattice_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let conf = LatticeConf {
+            topology: self.topology,
+            num_sites: self.num_sites,
+            coupling: self.coupling,
+        };
+        match coupled_map_lattice(schema, &conf) {
+            Ok(result) => {
+                self.lattice_status = Ok(result);
+                self.show_set(self.selected_set);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl LatticePanel {
+    fn show_set(&mut self, index: usize) {
+        self.heat_map = Default::default();
+        self.line_plot = Default::default();
+        if let Ok(result) = &self.lattice_status {
+            self.selected_set = index.min(result.evolutions.len().saturating_sub(1));
+            if let Some(evolution) = result.evolutions.get(self.selected_set) {
+                let num_sites = result.conf.total_num_sites();
+                let side = result.conf.num_sites;
+                let site_range = (0.0, num_sites.saturating_sub(1) as f64);
+                let first_step = self.num_transient as f64;
+                let num_recorded = evolution.space_time.len();
+                self.heat_map = match (result.conf.topology, evolution.space_time.last()) {
+                    (LatticeTopology::Grid, Some(snapshot)) if self.show_snapshot => {
+                        let side_range = (0.0, side.saturating_sub(1) as f64);
+                        HeatMap::new(
+                            snapshot.iter().map(|x| Some(*x)).collect(),
+                            ("Column", side_range, side),
+                            ("Row", side_range, side),
+                        )
+                    }
+                    _ => HeatMap::new(
+                        evolution
+                            .space_time
+                            .iter()
+                            .flatten()
+                            .map(|x| Some(*x))
+                            .collect(),
+                        ("Site", site_range, num_sites),
+                        (
+                            "Step",
+                            (
+                                first_step,
+                                first_step + num_recorded.saturating_sub(1) as f64,
+                            ),
+                            num_recorded,
+                        ),
+                    ),
+                };
+                let steps: Vec<f64> = (0..num_recorded)
+                    .map(|step| first_step + step as f64)
+                    .collect();
+                self.line_plot = LinePlot::new("sync_error", "Step", "Synchronization Error");
+                self.line_plot.add_line(
+                    String::from("Synchronization Error"),
+                    &steps,
+                    &evolution.sync_errors,
+                );
+            }
+        }
+        self.heat_map.set_colormap(self.colormap);
+        self.line_plot.set_colormap(self.colormap);
+    }
+
+    fn set_names(result: &LatticeResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.evolutions.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, ui: &mut Ui) {
+        ui.heading("Coupled Map Lattice Configuration");
+        group_horizontal(ui, |ui| {
+            combo_box(
+                LABEL_LATTICE_TOPOLOGY,
+                &mut self.topology,
+                ui,
+                TIP_LATTICE_TOPOLOGY,
+            );
+        });
+        let max_num_sites = match self.topology {
+            LatticeTopology::Ring => MAX_RING_SITES,
+            LatticeTopology::Grid => MAX_GRID_SITES,
+        };
+        self.num_sites = self.num_sites.min(max_num_sites);
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_LATTICE_SITES,
+                &mut self.num_sites,
+                max_num_sites,
+                ui,
+                TIP_LATTICE_SITES,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            float_slider(
+                LABEL_LATTICE_COUPLING,
+                &mut self.coupling,
+                1.0,
+                ui,
+                TIP_LATTICE_COUPLING,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_TRANSIENT,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        let set_names = match &self.lattice_status {
+            Ok(result) if result.evolutions.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if self.topology == LatticeTopology::Grid
+                && add_checkbox(
+                    LABEL_LATTICE_SNAPSHOT,
+                    &mut self.show_snapshot,
+                    ui,
+                    TIP_LATTICE_SNAPSHOT,
+                )
+            {
+                self.show_set(self.selected_set);
+            }
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.heat_map.set_colormap(self.colormap);
+                self.line_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_LATTICE, false, is_ready, ui, TIP_LATTICE) {
+                self.run_lattice = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.lattice_status {
+            Ok(result) => {
+                Self::set_names(result)
+                    .into_iter()
+                    .zip(result.evolutions.iter())
+                    .for_each(|((_, name), evolution)| match evolution.mean_sync_error() {
+                        Some(sync_error) => {
+                            ui.label(format!(
+                                "{name}: mean synchronization error = {sync_error:.6}"
+                            ));
+                        }
+                        None => {
+                            ui.label(format!("{name}: a site escaped during the transient"));
+                        }
+                    });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.lattice_status.is_ok() {
+            // the space-time plot takes two thirds of the height above the synchronization error
+            let size = Vec2::new(ui.available_width(), ui.available_height() * 2.0 / 3.0);
+            ui.allocate_ui(size, |ui| self.heat_map.ui(ui));
+            self.line_plot.ui(ui);
+        }
+    }
+}
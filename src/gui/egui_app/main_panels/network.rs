@@ -0,0 +1,275 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::{CouplingMatrix, DistributionDimensions};
+use crate::chaos::functions::ODE_STEP_SIZE;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::{Ui, Vec2};
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_TRANSIENT: usize = 5000;
+const MAX_NUM_STEPS: usize = 5000;
+const MAX_COUPLING: f64 = 50.0;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct NetworkPanel {
+    #[serde(skip)] // start without a network
+    run_network: bool,
+    #[serde(skip)] // networks must be recomputed manually
+    node_plot: LinePlot,
+    #[serde(skip)] // networks must be recomputed manually
+    sync_plot: LinePlot,
+    #[serde(skip)] // networks must be recomputed manually
+    network_status: Result<NetworkResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    matrix_text: String,
+    coupling: f64,
+    variable: usize,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for NetworkPanel {
+    fn default() -> Self {
+        Self {
+            run_network: false,
+            node_plot: Default::default(),
+            sync_plot: Default::default(),
+            network_status: Err(String::from("No network computed yet.")),
+            selected_set: 0,
+            // two mutually coupled nodes
+            matrix_text: String::from("0 1\n1 0"),
+            coupling: 5.0,
+            variable: 0,
+            num_transient: 500,
+            num_steps: 1000,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for NetworkPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_network == other.run_network
+            && self.matrix_text == other.matrix_text
+            && self.coupling == other.coupling
+            && self.variable == other.variable
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for NetworkPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_network {
+            self.run_network = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.network_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let matrix = match CouplingMatrix::from_text(&self.matrix_text) {
+            Ok(matrix) => matrix,
+            Err(e) => {
+                self.set_error(e.to_string());
+                return;
+            }
+        };
+        let conf = NetworkConf {
+            matrix,
+            coupling: self.coupling,
+            variable: self.variable,
+        };
+        match coupled_networks(schema, &conf) {
+            Ok(result) => {
+                self.network_status = Ok(result);
+                self.show_set(self.selected_set);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl NetworkPanel {
+    fn show_set(&mut self, index: usize) {
+        self.node_plot = Default::default();
+        self.sync_plot = Default::default();
+        if let Ok(result) = &self.network_status {
+            self.selected_set = index.min(result.evolutions.len().saturating_sub(1));
+            if let Some(evolution) = result.evolutions.get(self.selected_set) {
+                let times: Vec<f64> = (1..=evolution.sync_errors.len())
+                    .map(|step| (self.num_transient + step) as f64 * ODE_STEP_SIZE)
+                    .collect();
+                let variable_label = format!("State{}", self.variable + 1);
+                self.node_plot = LinePlot::new("node_values", "t", &variable_label);
+                evolution
+                    .node_values
+                    .iter()
+                    .enumerate()
+                    .for_each(|(node, values)| {
+                        self.node_plot
+                            .add_line(format!("Node {}", node + 1), &times, values);
+                    });
+                self.sync_plot = LinePlot::new("network_sync_error", "t", "Synchronization Error");
+                self.sync_plot.add_line(
+                    String::from("Synchronization Error"),
+                    &times,
+                    &evolution.sync_errors,
+                );
+            }
+        }
+        self.node_plot.set_colormap(self.colormap);
+        self.sync_plot.set_colormap(self.colormap);
+    }
+
+    fn set_names(result: &NetworkResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.evolutions.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Network Configuration");
+        group_vertical(ui, |ui| {
+            ui.label(LABEL_NETWORK_MATRIX)
+                .on_hover_text(TIP_NETWORK_MATRIX);
+            egui::ScrollArea::vertical()
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.matrix_text)
+                            .code_editor()
+                            .desired_rows(5),
+                    );
+                });
+        });
+        if let DistributionDimensions::State(num_dims) = dims {
+            if self.variable >= num_dims {
+                self.variable = num_dims - 1;
+            }
+            group_horizontal(ui, |ui| {
+                let variable_label = format!("State{}", self.variable + 1);
+                let variable_variants = (0..num_dims)
+                    .map(|variable| (variable, format!("State{}", variable + 1)))
+                    .collect();
+                combo_box_from_string(
+                    LABEL_NETWORK_VARIABLE,
+                    (&mut self.variable, variable_label),
+                    ui,
+                    variable_variants,
+                    TIP_NETWORK_VARIABLE,
+                );
+            });
+        }
+        group_horizontal(ui, |ui| {
+            float_slider(
+                LABEL_NETWORK_COUPLING,
+                &mut self.coupling,
+                MAX_COUPLING,
+                ui,
+                TIP_NETWORK_COUPLING,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_TRANSIENT,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        let set_names = match &self.network_status {
+            Ok(result) if result.evolutions.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.node_plot.set_colormap(self.colormap);
+                self.sync_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_NETWORK, false, is_ready, ui, TIP_NETWORK) {
+                self.run_network = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.network_status {
+            Ok(result) => {
+                Self::set_names(result)
+                    .into_iter()
+                    .zip(result.evolutions.iter())
+                    .for_each(|((_, name), evolution)| match evolution.mean_sync_error() {
+                        Some(sync_error) => {
+                            ui.label(format!(
+                                "{name}: mean synchronization error = {sync_error:.6}"
+                            ));
+                        }
+                        None => {
+                            ui.label(format!("{name}: a node escaped during the transient"));
+                        }
+                    });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.network_status.is_ok() {
+            // the coupled variable of all nodes above the synchronization error
+            let size = Vec2::new(ui.available_width(), ui.available_height() / 2.0);
+            ui.allocate_ui(size, |ui| self.node_plot.ui(ui));
+            self.sync_plot.ui(ui);
+        }
+    }
+}
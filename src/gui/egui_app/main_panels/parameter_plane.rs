@@ -0,0 +1,200 @@
+use crate::chaos::analysis::*;
+use crate::chaos::data::DistributionDimensions;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_STEPS: usize = 10_000;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct ParameterPlanePanel {
+    #[serde(skip)] // start without computing a plane
+    run_parameter_plane: bool,
+    #[serde(skip)] // parameter plane must be recomputed manually
+    heat_map: HeatMap,
+    #[serde(skip)] // parameter plane must be recomputed manually
+    plane_status: Result<ParameterPlaneSummary, String>,
+    summary: ParameterPlaneSummary,
+    component: usize,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for ParameterPlanePanel {
+    fn default() -> Self {
+        let conf = ParameterPlaneConf::default();
+        Self {
+            run_parameter_plane: false,
+            heat_map: Default::default(),
+            plane_status: Err(String::from("No parameter plane computed yet.")),
+            summary: conf.summary,
+            component: conf.component,
+            num_transient: conf.num_transient,
+            num_steps: conf.num_steps,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for ParameterPlanePanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_parameter_plane == other.run_parameter_plane
+            && self.summary == other.summary
+            && self.component == other.component
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl ParameterPlanePanel {
+    pub fn parameter_plane_toggle(&mut self) -> bool {
+        if self.run_parameter_plane {
+            self.run_parameter_plane = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn conf(&self) -> ParameterPlaneConf {
+        ParameterPlaneConf {
+            summary: self.summary,
+            component: self.component,
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.plane_status = Err(error);
+    }
+
+    pub fn parameter_plane(&mut self, schema: ParameterPlaneSchema) {
+        match parameter_plane(schema) {
+            Ok(result) => {
+                let ParameterPlaneResult {
+                    pars_x: (par_x, x_values),
+                    pars_y: (par_y, y_values),
+                    summary,
+                    values,
+                } = result;
+                let axis = |par: &str, par_values: &[f64]| {
+                    let range = (
+                        par_values.first().copied().unwrap_or_default(),
+                        par_values.last().copied().unwrap_or_default(),
+                    );
+                    (par.to_owned(), range, par_values.len())
+                };
+                let (x_label, x_range, width) = axis(par_x, &x_values);
+                let (y_label, y_range, height) = axis(par_y, &y_values);
+                self.heat_map = HeatMap::new(
+                    values,
+                    (x_label.as_str(), x_range, width),
+                    (y_label.as_str(), y_range, height),
+                );
+                self.heat_map.set_colormap(self.colormap);
+                self.plane_status = Ok(summary);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Parameter Plane Configuration");
+        group_horizontal(ui, |ui| {
+            combo_box(
+                LABEL_PLANE_SUMMARY,
+                &mut self.summary,
+                ui,
+                TIP_PLANE_SUMMARY,
+            );
+        });
+        if let DistributionDimensions::State(num_dims) = dims {
+            if self.component >= num_dims {
+                self.component = num_dims - 1;
+            }
+            if matches!(
+                self.summary,
+                ParameterPlaneSummary::ZeroOne | ParameterPlaneSummary::Period
+            ) {
+                group_horizontal(ui, |ui| {
+                    let component_label = format!("State{}", self.component + 1);
+                    let component_variants = (0..num_dims)
+                        .map(|component| (component, format!("State{}", component + 1)))
+                        .collect();
+                    combo_box_from_string(
+                        LABEL_SPECTRUM_COMPONENT,
+                        (&mut self.component, component_label),
+                        ui,
+                        component_variants,
+                        TIP_PLANE_COMPONENT,
+                    );
+                });
+            }
+        }
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.heat_map.set_colormap(self.colormap);
+            }
+            if clickable_button(
+                LABEL_PARAMETER_PLANE,
+                false,
+                is_ready,
+                ui,
+                TIP_PARAMETER_PLANE,
+            ) {
+                self.run_parameter_plane = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_horizontal(ui, |ui| match &self.plane_status {
+            Ok(summary) => {
+                let summary_label: &'static str = (*summary).into();
+                ui.label(summary_label);
+                if let Some((min, max)) = self.heat_map.value_range() {
+                    ui.label(format!("Min: {min:.4} Max: {max:.4}"));
+                }
+                ui.label(format!(
+                    "Cells without valid samples: {}",
+                    self.heat_map.num_invalid()
+                ));
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.plane_status.is_ok() {
+            self.heat_map.ui(ui);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_TRANSIENT: usize = 10_000;
+// the matrix grows quadratically with the number of states
+const MAX_NUM_STEPS: usize = 2000;
+const MAX_MIN_LINE: usize = 100;
+const MAX_STRIDE: usize = 100;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct RecurrencePanel {
+    #[serde(skip)] // start without a recurrence analysis
+    run_recurrence: bool,
+    #[serde(skip)] // recurrence plots must be recomputed manually
+    heat_map: HeatMap,
+    #[serde(skip)] // recurrence plots must be recomputed manually
+    recurrence_status: Result<RecurrenceResult, String>,
+    #[serde(skip)] // index of the shown data set
+    selected_set: usize,
+    num_transient: usize,
+    num_steps: usize,
+    threshold: f64,
+    min_line: usize,
+    stride: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for RecurrencePanel {
+    fn default() -> Self {
+        let trajectory_conf = TrajectoryConf::default();
+        let conf = RecurrenceConf::default();
+        Self {
+            run_recurrence: false,
+            heat_map: Default::default(),
+            recurrence_status: Err(String::from("No recurrence plot computed yet.")),
+            selected_set: 0,
+            num_transient: trajectory_conf.num_transient,
+            num_steps: 1000,
+            threshold: conf.threshold,
+            min_line: conf.min_line,
+            stride: conf.stride,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for RecurrencePanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_recurrence == other.run_recurrence
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.threshold == other.threshold
+            && self.min_line == other.min_line
+            && self.stride == other.stride
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for RecurrencePanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_recurrence {
+            self.run_recurrence = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            // the stride thins out the recorded states
+            num_steps: self.num_steps * self.stride,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.recurrence_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let conf = RecurrenceConf {
+            threshold: self.threshold,
+            min_line: self.min_line,
+            stride: self.stride,
+        };
+        match record_trajectories(schema) {
+            Ok(recorded) => {
+                self.recurrence_status = Ok(recurrence_analysis(recorded, &conf));
+                self.show_set(0);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+
+    fn observable_only(&self) -> bool {
+        true
+    }
+}
+
+impl RecurrencePanel {
+    fn show_set(&mut self, index: usize) {
+        self.selected_set = index;
+        self.heat_map = Default::default();
+        if let Ok(result) = &self.recurrence_status {
+            if let Some(Some(matrix)) = result.matrices.get(index) {
+                let n = matrix.size();
+                let values = (0..n)
+                    .flat_map(|i| {
+                        (0..n).map(move |j| Some(if matrix.is_recurrent(i, j) { 1.0 } else { 0.0 }))
+                    })
+                    .collect();
+                let time_range = (0.0, n.saturating_sub(1) as f64 * result.sample_time);
+                self.heat_map = HeatMap::new(values, ("t", time_range, n), ("t", time_range, n));
+                self.heat_map.set_colormap(self.colormap);
+            }
+        }
+    }
+
+    fn set_names(result: &RecurrenceResult) -> Vec<(usize, String)> {
+        let (par, par_values) = &result.pars;
+        (0..result.matrices.len())
+            .map(|index| match par_values.get(index) {
+                Some(par_value) => (index, format!("{par}={par_value:.3}")),
+                None => (index, format!("Set {}", index + 1)),
+            })
+            .collect()
+    }
+
+    pub fn conf_ui(&mut self, is_ready: bool, ui: &mut Ui) {
+        ui.heading("Recurrence Configuration");
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_TRANSIENT,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_RECURRENCE_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_RECURRENCE_STEPS,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_RECURRENCE_STRIDE,
+                &mut self.stride,
+                MAX_STRIDE,
+                ui,
+                TIP_RECURRENCE_STRIDE,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            float_slider(
+                LABEL_RECURRENCE_THRESHOLD,
+                &mut self.threshold,
+                1.0,
+                ui,
+                TIP_RECURRENCE_THRESHOLD,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_MIN_LINE,
+                &mut self.min_line,
+                MAX_MIN_LINE,
+                ui,
+                TIP_MIN_LINE,
+            );
+        });
+        let set_names = match &self.recurrence_status {
+            Ok(result) if result.matrices.len() > 1 => Self::set_names(result),
+            _ => Vec::new(),
+        };
+        if let Some((_, selected_name)) = set_names.get(self.selected_set).cloned() {
+            let mut selected_set = self.selected_set;
+            group_horizontal(ui, |ui| {
+                if combo_box_from_string(
+                    LABEL_SHOWN_SET,
+                    (&mut selected_set, selected_name),
+                    ui,
+                    set_names,
+                    TIP_SHOWN_SET,
+                ) {
+                    self.show_set(selected_set);
+                }
+            });
+        }
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.heat_map.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_RECURRENCE, false, is_ready, ui, TIP_RECURRENCE) {
+                self.run_recurrence = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_vertical(ui, |ui| match &self.recurrence_status {
+            Ok(result) => {
+                Self::set_names(result)
+                    .into_iter()
+                    .zip(result.measures.iter())
+                    .for_each(|((_, name), measures)| match measures {
+                        Some(rqa) => {
+                            ui.label(format!(
+                                "{name}: RR = {:.4} DET = {:.4} LAM = {:.4} L = {:.2}",
+                                rqa.recurrence_rate,
+                                rqa.determinism,
+                                rqa.laminarity,
+                                rqa.mean_diagonal_length
+                            ));
+                        }
+                        None => {
+                            ui.label(format!("{name}: all samples escaped"));
+                        }
+                    });
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.recurrence_status.is_ok() {
+            self.heat_map.ui(ui);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_STEPS: usize = 10_000;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct RotationPanel {
+    #[serde(skip)] // start without computing rotation numbers
+    run_rotation: bool,
+    #[serde(skip)] // rotation numbers must be recomputed manually
+    line_plot: LinePlot,
+    #[serde(skip)] // rotation numbers must be recomputed manually
+    rotation_status: Result<RotationCurve, String>,
+    num_transient: usize,
+    num_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for RotationPanel {
+    fn default() -> Self {
+        Self {
+            run_rotation: false,
+            line_plot: Default::default(),
+            rotation_status: Err(String::from("No rotation numbers computed yet.")),
+            num_transient: 500,
+            num_steps: 1000,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for RotationPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_rotation == other.run_rotation
+            && self.num_transient == other.num_transient
+            && self.num_steps == other.num_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for RotationPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_rotation {
+            self.run_rotation = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: self.num_steps,
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.rotation_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        match rotation_curve(schema) {
+            Ok(curve) => {
+                let (par, par_values) = &curve.pars;
+                let num_maps = curve.rotation_numbers.len();
+                let (x_label, xs) = if par_values.len() == num_maps {
+                    (*par, par_values.clone())
+                } else {
+                    ("Set", (1..=num_maps).map(|i| i as f64).collect())
+                };
+                let ys: Vec<f64> = curve
+                    .rotation_numbers
+                    .iter()
+                    .map(|rotation_number| rotation_number.unwrap_or(f64::NAN))
+                    .collect();
+                // the devil's staircase over the frequency ω
+                self.line_plot = LinePlot::new("rotation", x_label, "Rotation Number");
+                self.line_plot
+                    .add_line(String::from("Rotation Number"), &xs, &ys);
+                self.line_plot.set_colormap(self.colormap);
+                self.rotation_status = Ok(curve);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        };
+    }
+}
+
+impl RotationPanel {
+    pub fn conf_ui(&mut self, is_ready: bool, ui: &mut Ui) {
+        ui.heading("Rotation Number Configuration");
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_STEPS,
+                &mut self.num_steps,
+                MAX_NUM_STEPS,
+                ui,
+                TIP_NUM_STEPS,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.line_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(LABEL_ROTATION, false, is_ready, ui, TIP_ROTATION) {
+                self.run_rotation = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_horizontal(ui, |ui| match &self.rotation_status {
+            Ok(curve) => match (curve.rotation_numbers.as_slice(), curve.lockings.as_slice()) {
+                ([Some(rotation_number)], [locking]) => {
+                    ui.label(format!("Rotation Number: {rotation_number:.6}"));
+                    if let Some((p, q)) = locking {
+                        ui.label(format!("Mode-locked at {p}/{q}"));
+                    }
+                }
+                _ => {
+                    let num_locked = curve.lockings.iter().flatten().count();
+                    ui.label(format!(
+                        "Mode-locked parameters: {num_locked} of {}",
+                        curve.lockings.len()
+                    ));
+                    let max_spread = curve
+                        .spreads
+                        .iter()
+                        .flatten()
+                        .fold(0.0_f64, |max, spread| max.max(*spread));
+                    ui.label(format!("Largest spread over samples: {max_spread:.4}"));
+                }
+            },
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.rotation_status.is_ok() {
+            self.line_plot.ui(ui);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use super::TrajectoryAnalysisPanel;
+use crate::chaos::analysis::*;
+use crate::chaos::data::{ChaosFloat, DistributionDimensions};
+use crate::gui::plot::*;
+use crate::gui::tooltips::*;
+use crate::gui::*;
+use egui::Ui;
+use serde::{Deserialize, Serialize};
+
+const MAX_NUM_TRANSIENT: usize = 10_000;
+const MIN_LOG2_STEPS: usize = 4;
+const MAX_LOG2_STEPS: usize = 16;
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct SpectrumPanel {
+    #[serde(skip)] // start without computing a spectrum
+    run_spectrum: bool,
+    #[serde(skip)] // spectra must be recomputed manually
+    spectrum_plot: LogLogPlot,
+    #[serde(skip)] // spectra must be recomputed manually
+    spectrum_status: Result<Vec<Option<ChaosFloat>>, String>,
+    component: usize,
+    window: SpectralWindow,
+    num_transient: usize,
+    log2_steps: usize,
+    colormap: SeriesColors,
+}
+
+impl Default for SpectrumPanel {
+    fn default() -> Self {
+        let conf = TrajectoryConf::default();
+        Self {
+            run_spectrum: false,
+            spectrum_plot: LogLogPlot::new("spectrum", "Frequency", "Power Spectral Density"),
+            spectrum_status: Err(String::from("No spectrum computed yet.")),
+            component: 0,
+            window: Default::default(),
+            num_transient: conf.num_transient,
+            log2_steps: conf.num_steps.ilog2() as usize,
+            colormap: Default::default(),
+        }
+    }
+}
+
+impl PartialEq for SpectrumPanel {
+    fn eq(&self, other: &Self) -> bool {
+        // only compare options for reset
+        self.run_spectrum == other.run_spectrum
+            && self.component == other.component
+            && self.window == other.window
+            && self.num_transient == other.num_transient
+            && self.log2_steps == other.log2_steps
+            && self.colormap == other.colormap
+    }
+}
+
+impl TrajectoryAnalysisPanel for SpectrumPanel {
+    fn toggle(&mut self) -> bool {
+        if self.run_spectrum {
+            self.run_spectrum = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conf(&self) -> TrajectoryConf {
+        TrajectoryConf {
+            num_transient: self.num_transient,
+            num_steps: 1 << self.log2_steps.max(MIN_LOG2_STEPS),
+        }
+    }
+
+    fn set_error(&mut self, error: String) {
+        self.spectrum_status = Err(error);
+    }
+
+    fn run(&mut self, schema: TrajectorySchema) {
+        let recorded = match record_trajectories(schema) {
+            Ok(recorded) => recorded,
+            Err(e) => {
+                self.set_error(e.to_string());
+                return;
+            }
+        };
+        if self.component >= recorded.num_dims {
+            self.set_error(String::from("The selected state component does not exist!"));
+            return;
+        }
+        let SpectrumResult {
+            pars: (par, par_values),
+            component,
+            spectra,
+        } = power_spectra(&recorded, self.component, self.window);
+        self.spectrum_plot.reset_lines();
+        let dominant_frequencies = spectra
+            .iter()
+            .enumerate()
+            .map(|(index, spectrum)| {
+                let spectrum = spectrum.as_ref()?;
+                let name = match par_values.get(index) {
+                    Some(par_value) => format!("{par}={par_value:.3}"),
+                    None => format!("State{}", component + 1),
+                };
+                let (log_frequencies, log_densities): (Vec<_>, Vec<_>) = spectrum
+                    .frequencies
+                    .iter()
+                    .zip(spectrum.densities.iter())
+                    .map(|(f, p)| (f.log10(), p.log10()))
+                    .unzip();
+                self.spectrum_plot
+                    .add_line(name, &log_frequencies, &log_densities);
+                spectrum.dominant_frequency()
+            })
+            .collect();
+        self.spectrum_plot.set_colormap(self.colormap);
+        self.spectrum_status = Ok(dominant_frequencies);
+    }
+
+    fn observable_only(&self) -> bool {
+        true
+    }
+}
+
+impl SpectrumPanel {
+    pub fn conf_ui(&mut self, is_ready: bool, dims: DistributionDimensions, ui: &mut Ui) {
+        ui.heading("Power Spectrum Configuration");
+        if let DistributionDimensions::State(num_dims) = dims {
+            if self.component >= num_dims {
+                self.component = num_dims - 1;
+            }
+            group_horizontal(ui, |ui| {
+                let component_label = format!("State{}", self.component + 1);
+                let component_variants = (0..num_dims)
+                    .map(|component| (component, format!("State{}", component + 1)))
+                    .collect();
+                combo_box_from_string(
+                    LABEL_SPECTRUM_COMPONENT,
+                    (&mut self.component, component_label),
+                    ui,
+                    component_variants,
+                    TIP_SPECTRUM_COMPONENT,
+                );
+            });
+        }
+        group_horizontal(ui, |ui| {
+            combo_box(
+                LABEL_SPECTRUM_WINDOW,
+                &mut self.window,
+                ui,
+                TIP_SPECTRUM_WINDOW,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_NUM_TRANSIENT,
+                &mut self.num_transient,
+                MAX_NUM_TRANSIENT,
+                ui,
+                TIP_NUM_TRANSIENT,
+            );
+        });
+        group_horizontal(ui, |ui| {
+            integer_slider(
+                LABEL_SPECTRUM_STEPS,
+                &mut self.log2_steps,
+                MAX_LOG2_STEPS,
+                ui,
+                TIP_SPECTRUM_STEPS,
+            );
+            self.log2_steps = self.log2_steps.max(MIN_LOG2_STEPS);
+            ui.label(format!("{} steps", 1_usize << self.log2_steps));
+        });
+        group_horizontal(ui, |ui| {
+            if combo_box(LABEL_COLORMAP, &mut self.colormap, ui, TIP_COLORMAP) {
+                self.spectrum_plot.set_colormap(self.colormap);
+            }
+            if clickable_button(
+                LABEL_SPECTRUM,
+                false,
+                is_ready && matches!(dims, DistributionDimensions::State(_)),
+                ui,
+                TIP_SPECTRUM,
+            ) {
+                self.run_spectrum = true;
+            };
+        });
+    }
+
+    fn show_summary(&self, ui: &mut Ui) {
+        group_horizontal(ui, |ui| match &self.spectrum_status {
+            Ok(dominant_frequencies) => {
+                let num_escaped = dominant_frequencies.iter().filter(|f| f.is_none()).count();
+                if let [Some(frequency)] = dominant_frequencies.as_slice() {
+                    ui.label(format!("Dominant frequency: {frequency:.4}"));
+                }
+                ui.label(format!(
+                    "Configurations without complete samples: {num_escaped}"
+                ));
+            }
+            Err(e) => {
+                ui.label(e.as_str());
+            }
+        });
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        self.show_summary(ui);
+        if self.spectrum_status.is_ok() {
+            self.spectrum_plot.ui(ui);
+        }
+    }
+}
@@ -3,12 +3,18 @@ mod plot_3_d;
 mod plot_colors;
 mod plot_data;
 mod plot_data_variants;
+mod plot_heat_map;
+mod plot_lines;
+mod plot_log_log;
 mod plot_styles;
 mod plot_utils;
 pub use self::plot_data::DEFAULT_MAX_SERIES;
 pub use plot_2_d::Plot2D;
 pub use plot_3_d::Plot3D;
 pub use plot_colors::SeriesColors;
+pub use plot_heat_map::HeatMap;
+pub use plot_lines::LinePlot;
+pub use plot_log_log::LogLogPlot;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
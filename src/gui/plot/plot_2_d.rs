@@ -489,6 +489,7 @@ impl Plot2D {
     delegate! {
         to self.plot_data{
             pub fn series_color_mut(&mut self)-> &mut SeriesColorChoice;
+            pub fn get_parameter(&self) -> Option<&'static str>;
             pub fn get_parameter_values(&self) -> &Vec<f64>;
             #[call(clear)]
             pub fn reset_data(&mut self);
@@ -0,0 +1,128 @@
+use egui::{Color32, ColorImage, TextureHandle, TextureOptions, Ui, Vec2};
+use egui_plot::{Plot, PlotImage, PlotPoint};
+
+use super::plot_colors::{FromRGB, SeriesColors};
+
+#[derive(Default)]
+pub struct HeatMap {
+    // row-major with the y-axis as outer index
+    values: Vec<Option<f64>>,
+    width: usize,
+    height: usize,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+    x_label: String,
+    y_label: String,
+    colormap: SeriesColors,
+    texture: Option<TextureHandle>,
+}
+
+impl HeatMap {
+    pub fn new(
+        values: Vec<Option<f64>>,
+        (x_label, x_range, width): (&str, (f64, f64), usize),
+        (y_label, y_range, height): (&str, (f64, f64), usize),
+    ) -> Self {
+        Self {
+            values,
+            width,
+            height,
+            x_range,
+            y_range,
+            x_label: x_label.to_owned(),
+            y_label: y_label.to_owned(),
+            colormap: Default::default(),
+            texture: None,
+        }
+    }
+
+    pub fn set_colormap(&mut self, colormap: SeriesColors) {
+        if self.colormap != colormap {
+            self.colormap = colormap;
+            self.texture = None;
+        }
+    }
+
+    pub fn value_range(&self) -> Option<(f64, f64)> {
+        self.values.iter().flatten().fold(None, |extrema, v| {
+            Some(match extrema {
+                None => (*v, *v),
+                Some((min, max)) => (v.min(min), v.max(max)),
+            })
+        })
+    }
+
+    pub fn num_invalid(&self) -> usize {
+        self.values.iter().filter(|v| v.is_none()).count()
+    }
+
+    fn color_image(&self) -> ColorImage {
+        let (min, max) = self.value_range().unwrap_or((0.0, 1.0));
+        let value_span = max - min;
+        let invalid_color = <Color32 as FromRGB>::from_rgb(self.colormap.special_color());
+        // first image row shows the largest y-value
+        let pixels = (0..self.height)
+            .rev()
+            .flat_map(|row| self.values[row * self.width..(row + 1) * self.width].iter())
+            .map(|v| match v {
+                Some(v) => {
+                    let h = if value_span > 0.0 {
+                        ((v - min) / value_span) as f32
+                    } else {
+                        0.5
+                    };
+                    <Color32 as FromRGB>::from_rgb(self.colormap.color(h))
+                }
+                None => invalid_color,
+            })
+            .collect();
+        ColorImage {
+            size: [self.width, self.height],
+            pixels,
+        }
+    }
+
+    fn cell_size(range: (f64, f64), num_cells: usize) -> f64 {
+        if num_cells > 1 && range.1 > range.0 {
+            (range.1 - range.0) / (num_cells - 1) as f64
+        } else {
+            1.0
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        if self.values.is_empty() || self.values.len() != self.width * self.height {
+            return;
+        }
+        let texture_id = match &self.texture {
+            Some(texture) => texture.id(),
+            None => {
+                // colors are only recomputed after new values or a new color map
+                let texture =
+                    ui.ctx()
+                        .load_texture("heat_map", self.color_image(), TextureOptions::NEAREST);
+                let texture_id = texture.id();
+                self.texture = Some(texture);
+                texture_id
+            }
+        };
+        let cell_x = Self::cell_size(self.x_range, self.width);
+        let cell_y = Self::cell_size(self.y_range, self.height);
+        let center = PlotPoint::new(
+            0.5 * (self.x_range.0 + self.x_range.1),
+            0.5 * (self.y_range.0 + self.y_range.1),
+        );
+        let size = Vec2::new(
+            (cell_x * self.width as f64) as f32,
+            (cell_y * self.height as f64) as f32,
+        );
+        let image = PlotImage::new(texture_id, center, size);
+        Plot::new("heat_map")
+            .x_axis_label(self.x_label.as_str())
+            .y_axis_label(self.y_label.as_str())
+            .set_margin_fraction(Vec2::new(0.01, 0.01))
+            .show(ui, |plot_ui| {
+                plot_ui.image(image);
+            });
+    }
+}
@@ -0,0 +1,80 @@
+use egui::{Color32, Ui, Vec2};
+use egui_plot::{Legend, Line, Plot, PlotPoints, Points};
+
+use super::plot_colors::{FromRGB, SeriesColors};
+
+struct NamedLine {
+    name: String,
+    points: Vec<[f64; 2]>,
+}
+
+/// Lines on linear axes, e.g. a measure over the parameter values of a sweep.
+#[derive(Default)]
+pub struct LinePlot {
+    id: &'static str,
+    x_label: String,
+    y_label: String,
+    lines: Vec<NamedLine>,
+    colormap: SeriesColors,
+}
+
+impl LinePlot {
+    pub fn new(id: &'static str, x_label: &str, y_label: &str) -> Self {
+        Self {
+            id,
+            x_label: x_label.to_owned(),
+            y_label: y_label.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add_line(&mut self, name: String, xs: &[f64], ys: &[f64]) {
+        let points = xs
+            .iter()
+            .zip(ys.iter())
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|(x, y)| [*x, *y])
+            .collect();
+        self.lines.push(NamedLine { name, points });
+    }
+
+    pub fn set_colormap(&mut self, colormap: SeriesColors) {
+        self.colormap = colormap;
+    }
+
+    fn line_color(&self, index: usize) -> Color32 {
+        let h = if self.lines.len() > 1 {
+            index as f32 / (self.lines.len() - 1) as f32
+        } else {
+            0.5
+        };
+        <Color32 as FromRGB>::from_rgb(self.colormap.color(h))
+    }
+
+    pub fn ui(&self, ui: &mut Ui) {
+        let mut plot = Plot::new(self.id)
+            .x_axis_label(self.x_label.as_str())
+            .y_axis_label(self.y_label.as_str())
+            .set_margin_fraction(Vec2::new(0.01, 0.05));
+        if self.lines.len() > 1 {
+            plot = plot.legend(Legend::default());
+        }
+        plot.show(ui, |plot_ui| {
+            self.lines.iter().enumerate().for_each(|(index, line)| {
+                let color = self.line_color(index);
+                plot_ui.line(
+                    Line::new(PlotPoints::from(line.points.clone()))
+                        .color(color)
+                        .name(line.name.as_str()),
+                );
+                // markers show the sampled values, e.g. a single parameter
+                plot_ui.points(
+                    Points::new(PlotPoints::from(line.points.clone()))
+                        .radius(2.0)
+                        .color(color)
+                        .name(line.name.as_str()),
+                );
+            });
+        });
+    }
+}
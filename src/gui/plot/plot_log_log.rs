@@ -0,0 +1,111 @@
+use egui::{Color32, Ui, Vec2};
+use egui_plot::{log_grid_spacer, Legend, Line, LineStyle, Plot, PlotPoints};
+
+use super::plot_colors::{FromRGB, SeriesColors};
+
+struct LogLogLine {
+    name: String,
+    // log10 of both coordinates
+    points: Vec<[f64; 2]>,
+    // fitted line over a range of the points
+    fit: Option<[[f64; 2]; 2]>,
+}
+
+/// Lines on logarithmic axes, all coordinates are given as log10 values.
+#[derive(Default)]
+pub struct LogLogPlot {
+    id: &'static str,
+    x_label: &'static str,
+    y_label: &'static str,
+    lines: Vec<LogLogLine>,
+    colormap: SeriesColors,
+}
+
+impl LogLogPlot {
+    pub fn new(id: &'static str, x_label: &'static str, y_label: &'static str) -> Self {
+        Self {
+            id,
+            x_label,
+            y_label,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_line(&mut self, name: String, log_x: &[f64], log_y: &[f64]) {
+        let points = log_x
+            .iter()
+            .zip(log_y.iter())
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|(x, y)| [*x, *y])
+            .collect();
+        self.lines.push(LogLogLine {
+            name,
+            points,
+            fit: None,
+        });
+    }
+
+    /// Shows a fit of the latest line, given by its start and end point.
+    pub fn set_fit(&mut self, start: [f64; 2], end: [f64; 2]) {
+        if let Some(line) = self.lines.last_mut() {
+            line.fit = Some([start, end]);
+        }
+    }
+
+    pub fn reset_lines(&mut self) {
+        self.lines.clear();
+    }
+
+    pub fn set_colormap(&mut self, colormap: SeriesColors) {
+        self.colormap = colormap;
+    }
+
+    fn line_color(&self, index: usize) -> Color32 {
+        let h = if self.lines.len() > 1 {
+            index as f32 / (self.lines.len() - 1) as f32
+        } else {
+            0.5
+        };
+        <Color32 as FromRGB>::from_rgb(self.colormap.color(h))
+    }
+
+    pub fn ui(&self, ui: &mut Ui) {
+        let (x_label, y_label) = (self.x_label, self.y_label);
+        let mut plot = Plot::new(self.id)
+            .x_axis_label(x_label)
+            .y_axis_label(y_label)
+            .set_margin_fraction(Vec2::new(0.01, 0.05))
+            .x_grid_spacer(log_grid_spacer(10))
+            .y_grid_spacer(log_grid_spacer(10))
+            .x_axis_formatter(|x, _, _| format!("1e{x:.0}"))
+            .y_axis_formatter(|y, _, _| format!("1e{y:.0}"))
+            .label_formatter(move |name, point| {
+                format!(
+                    "{name}\n{x_label}={:.4e}\n{y_label}={:.4e}",
+                    10_f64.powf(point.x),
+                    10_f64.powf(point.y)
+                )
+            });
+        if self.lines.len() > 1 {
+            plot = plot.legend(Legend::default());
+        }
+        plot.show(ui, |plot_ui| {
+            self.lines.iter().enumerate().for_each(|(index, line)| {
+                let color = self.line_color(index);
+                plot_ui.line(
+                    Line::new(PlotPoints::from(line.points.clone()))
+                        .color(color)
+                        .name(line.name.as_str()),
+                );
+                if let Some(fit) = line.fit {
+                    plot_ui.line(
+                        Line::new(PlotPoints::from(fit.to_vec()))
+                            .color(color)
+                            .style(LineStyle::dashed_loose())
+                            .width(2.0),
+                    );
+                }
+            });
+        });
+    }
+}
@@ -13,11 +13,18 @@ pub const TIP_NUM_PARAMS: &str = "Set the number of parameters. They are evenly
 
 pub const LABEL_NUM_EXECS: &str = "Nr Executions";
 pub const TIP_NUM_EXECS: &str = "Set the number of executions per frame. Defines how many times a discrete map is applied between two frames, and how many infinitesimal steps an ODE solver performs. Set to 1 and use the number of frames for visualizations.";
+pub const LABEL_INTEGRATION_SCHEME: &str = "Integrator";
+pub const TIP_INTEGRATION_SCHEME: &str = "Choose the integration scheme of the Hamiltonian systems. The adaptive Dormand-Prince solver resolves close encounters, but its energy drifts over long times. The implicit midpoint rule and the Gauss-Legendre method of order 4 are symplectic with a fixed step size, so their energy error stays bounded.";
+pub const LABEL_ENERGY: &str = "Energy";
+pub const TIP_ENERGY: &str = "The energy (or Jacobi constant) of the Hamiltonian system that all states are moved to. The last momentum (or velocity) of each state is chosen such that it lies on this energy surface. States without such a momentum are removed.";
+pub const LABEL_FIX_ENERGY: &str = "Fix Energy";
+pub const TIP_FIX_ENERGY: &str = "Move all states onto the chosen energy surface and restart their integration. Use it together with a Poincaré section in the plot configuration to study the orbits of a single energy.";
 pub const LABEL_RUN: &str = "▶";
 pub const LABEL_PAUSE: &str = "◼";
 pub const TIP_RUN: &str = "Run or pause the execution of a chaotic function. Useful for immediately stopping a high CPU load to reconfigure.";
 pub const LABEL_MAIN_MODE: &str = "Main Mode";
-pub const TIP_MAIN_MODE: &str = "Show a plot or run a benchmark of chaotic data.";
+pub const TIP_MAIN_MODE: &str =
+    "Show a plot, run a benchmark, compute a parameter plane, power spectra, the fractal dimension, basins of attraction, recurrence plots, entropy curves, invariant densities, rotation numbers, delay embeddings, coupled map lattices, the synchronization of Kuramoto oscillators and coupled flows or the control of chaos.";
 pub const LABEL_INIT_MODE: &str = "Mode";
 pub const TIP_INIT_MODE: &str = "Choose the general type of chaotic data to initialize. Chaotic functions are selectable based on this selection.";
 pub const LABEL_NUM_SAMPLES: &str = "Nr Samples";
@@ -25,7 +32,7 @@ pub const TIP_NUM_SAMPLES: &str = "Set the number of samples. This defines how m
 pub const TIP_BUTTON_DECREASE_NUM_STATES: &str = "Decrease the dimensionality of the data points.";
 pub const TIP_BUTTON_INCREASE_NUM_STATES: &str = "Increase the dimensionality of the data points.";
 pub const TIP_INIT_PANEL: &str =
-    "Configure initial chaotic data. Then choose which function to apply.";
+    "Configure initial chaotic data. Then choose which function to apply. Alternatively, import measured data.";
 pub const TIP_DISTRIBUTIONS_ALL: &str = "Select the general class of initial distributions to choose from. Mesh (deterministic as well) creates a grid with (#samples)^(#meshes) points in total.";
 pub const TIP_DISTRIBUTIONS_NO_MESH: &str =
     "Select between probabilistic and deterministic initial distributions.";
@@ -46,7 +53,24 @@ pub const TIP_TRAJECTORY: &str = "Display a trajectory with a set length. This d
 pub const LABEL_COLORMAP: &str = "Color Map";
 pub const TIP_COLORMAP: &str = "Select the color map that creates colors for the plot points.";
 pub const LABEL_COLOR_PER_POINT: &str = "Coloring Mode";
-pub const TIP_COLOR_PER_POINT: &str = "How to color the points: \n- A single color. \n- A color per series to follow distribution evolution. \n- A color per point to follow its trajectory. \n- Mapping of a feature to a color space (min => 0, max => 1).";
+pub const TIP_COLOR_PER_POINT: &str = "How to color the points: \n- A single color. \n- A color per series to follow distribution evolution. \n- A color per point to follow its trajectory. \n- Mapping of a feature to a color space (min => 0, max => 1), e.g. the colour coordinate c of the iterated function systems.";
+pub const LABEL_POINCARE: &str = "Poincaré Section";
+pub const TIP_POINCARE: &str = "Only plot the points where the trajectories of a differential system cross a hyperplane. Crossings are linearly interpolated between two consecutive solver steps. Keep a long trajectory to collect the section points over time.";
+pub const LABEL_SECTION_AXIS: &str = "Section Axis";
+pub const TIP_SECTION_AXIS: &str =
+    "Choose a state feature whose axis is the normal vector of the hyperplane.";
+pub const LABEL_SECTION_NORMAL: &str = "Section Normal";
+pub const TIP_SECTION_NORMAL: &str = "Set the normal vector n of the hyperplane n·x = c, e.g. (1, 1, 0) for the diagonal plane x + y = c. A normal with a single component of one is an axis preset.";
+pub const LABEL_SECTION_VALUE: &str = "Section Offset";
+pub const TIP_SECTION_VALUE: &str =
+    "Set the offset c of the hyperplane n·x = c, e.g. z = 27 for the Lorenz system.";
+pub const LABEL_SECTION_DIRECTION: &str = "Crossing";
+pub const TIP_SECTION_DIRECTION: &str =
+    "Only count crossings in the direction of the normal vector, against it, or both.";
+pub const LABEL_RETURN_MAP: &str = "Return Map";
+pub const TIP_RETURN_MAP: &str = "Plot successive local maxima of a state feature against each other (x_n on the X-Axis, x_n+1 on the Y-Axis), e.g. the Lorenz map of z. With an active Poincaré section, successive crossings are used instead of maxima. Return maps are available for differential systems.";
+pub const LABEL_RETURN_MAP_FEATURE: &str = "Return Map Feature";
+pub const TIP_RETURN_MAP_FEATURE: &str = "Choose the state feature of the return map.";
 pub const LABEL_POINT_SIZE: &str = "Point Size";
 pub const TIP_POINT_SIZE: &str = "Set a fixed size for shapes such as points.";
 
@@ -135,3 +159,136 @@ pub const TIP_NUM_ITERATIONS: &str = "Determine the number of iterations for eac
 
 pub const LABEL_BENCHMARK: &str = "Benchmark Execution";
 pub const TIP_BENCHMARK: &str = "Execute the benchmark with the current settings.";
+
+// parameter plane
+pub const LABEL_PLANE_SUMMARY: &str = "Summary";
+pub const TIP_PLANE_SUMMARY: &str = "Choose the value computed per parameter pair: \n- Largest Lyapunov exponent of a twin trajectory (mean over all samples). \n- Period of the attractor (0 if aperiodic, most frequent over all samples). \n- Fraction of samples that escaped the simulation. \n- Rational rotation number p/q of the Circle map where all samples lock on the same periodic orbit. Quasiperiodic and chaotic cells count as invalid, so the Arnold tongues are coloured by their rotation number. \n- 0-1 test for chaos of the selected state component (K close to 0 for regular and 1 for chaotic dynamics, mean over all samples). Cheaper than the Lyapunov exponent. ODEs are sampled every 5 steps.";
+pub const TIP_PLANE_COMPONENT: &str =
+    "Choose the state component observed by the 0-1 test and by the period of ODEs.";
+pub const LABEL_NUM_TRANSIENT: &str = "Transient Steps";
+pub const TIP_NUM_TRANSIENT: &str =
+    "Set the number of steps that are discarded before the summary or spectrum is computed.";
+pub const LABEL_NUM_STEPS: &str = "Summary Steps";
+pub const TIP_NUM_STEPS: &str =
+    "Set the number of steps used for the summary. An ODE step has a length of 0.1.";
+pub const LABEL_PARAMETER_PLANE: &str = "Compute Plane";
+pub const TIP_PARAMETER_PLANE: &str = "Compute the summary for all pairs of the two parameter ranges selected in the execution panel. Each pair evolves the chosen initial state distribution.";
+pub const LABEL_SPECTRUM_COMPONENT: &str = "Component";
+pub const TIP_SPECTRUM_COMPONENT: &str =
+    "Choose the state component whose time series is transformed.";
+pub const LABEL_SPECTRUM_WINDOW: &str = "Window";
+pub const TIP_SPECTRUM_WINDOW: &str = "Choose the window applied to the mean-free time series before the FFT. Tapered windows reduce the leakage of sharp peaks into neighbouring frequencies.";
+pub const LABEL_SPECTRUM_STEPS: &str = "Log2 Steps";
+pub const TIP_SPECTRUM_STEPS: &str = "Set the length of the recorded time series as a power of two. An ODE step has a length of 0.1, a map step a length of 1.";
+pub const LABEL_SPECTRUM: &str = "Compute Spectrum";
+pub const TIP_SPECTRUM: &str = "Record the chosen component for every sample and parameter of the execution panel and plot the averaged power spectral density on log axes. Discrete peaks indicate periodic or quasi-periodic motion, a broad-band spectrum indicates chaos. Samples that escape are ignored.";
+pub const LABEL_POINT_SOURCE: &str = "Points";
+pub const TIP_POINT_SOURCE: &str = "Choose the point cloud that samples the attractor: \n- Ensemble: the valid states of the current plot data after some executions. \n- Trajectory: the recorded states of every initial sample of the chosen chaotic function.";
+pub const LABEL_MAX_POINTS: &str = "Max Points";
+pub const TIP_MAX_POINTS: &str = "Set the maximum number of points used for the correlation sums. Larger clouds are thinned out evenly.";
+pub const LABEL_TRAJECTORY_STEPS: &str = "Recorded Steps";
+pub const TIP_TRAJECTORY_STEPS: &str =
+    "Set the number of recorded steps per sample. An ODE step has a length of 0.1.";
+pub const LABEL_THEILER_WINDOW: &str = "Theiler Window";
+pub const TIP_THEILER_WINDOW: &str = "Exclude pairs of trajectory points that are closer in time than this number of steps. Avoids underestimating the dimension due to temporally correlated points of flows.";
+pub const LABEL_DIMENSION: &str = "Compute Dimension";
+pub const TIP_DIMENSION: &str = "Compute the dimension of the attractor or fractal boundary. The dimension is the slope of the longest log-log range with a constant local slope (dashed line) and is reported with its 95% confidence interval next to the description of the chaotic function.";
+pub const LABEL_DIMENSION_ESTIMATOR: &str = "Estimator";
+pub const TIP_DIMENSION_ESTIMATOR: &str = "Choose how the dimension is estimated: \n- Correlation Sum: Grassberger-Procaccia sums C(r), the fraction of point pairs closer than r, give the correlation dimension D2. \n- Box Counting: the number of boxes N(ε) of size ε that cover the points gives the box-counting dimension D0. For fractals, the boxes containing both bounded and escaped samples are counted, which measures the boundary of the set. Run the fractal until all samples are decided.";
+pub const LABEL_BASIN_CELLS: &str = "Grid Cells";
+pub const TIP_BASIN_CELLS: &str = "Set the number of grid cells along the largest extent of all final states. Samples whose final states visit the same or adjacent cells belong to the same attractor. Increase to separate nearby attractors, decrease if a single chaotic attractor is split up.";
+pub const LABEL_SHOWN_SET: &str = "Shown Set";
+pub const TIP_SHOWN_SET: &str =
+    "Choose the parameter value of the execution panel whose result is shown.";
+pub const LABEL_BASINS: &str = "Compute Basins";
+pub const TIP_BASINS: &str = "Run every initial state of a grid created by exactly two Mesh distributions (use Fixed values for other states) and cluster the recorded final states into distinct attractors. Each grid point is coloured by the attractor it ends on, sorted by decreasing basin size along the color map. Diverging samples get the special color.";
+pub const LABEL_RECURRENCE_STEPS: &str = "Compared States";
+pub const TIP_RECURRENCE_STEPS: &str = "Set the number of states of the trajectory that are compared pairwise. The recurrence matrix grows quadratically with this number.";
+pub const LABEL_RECURRENCE_STRIDE: &str = "Stride";
+pub const TIP_RECURRENCE_STRIDE: &str = "Compare only every n-th recorded state. Increase for ODEs to avoid thick diagonals of densely sampled flows. An ODE step has a length of 0.1.";
+pub const LABEL_RECURRENCE_THRESHOLD: &str = "Threshold";
+pub const TIP_RECURRENCE_THRESHOLD: &str = "Set the recurrence radius relative to the largest extent of the trajectory. Two states recur if their euclidean distance is at most this radius.";
+pub const LABEL_MIN_LINE: &str = "Min Line";
+pub const TIP_MIN_LINE: &str = "Set the shortest diagonal and vertical line that counts for the determinism, laminarity and mean diagonal length.";
+pub const LABEL_RECURRENCE: &str = "Compute Recurrence";
+pub const TIP_RECURRENCE: &str = "Record the trajectory of the first non-escaping sample for every parameter of the execution panel and compute its recurrence matrix and quantification (RQA) without the line of identity: \n- RR: recurrence rate, the fraction of recurrent pairs. \n- DET: determinism, the fraction of recurrent points on diagonal lines. \n- LAM: laminarity, the fraction of recurrent points on vertical lines. \n- L: mean length of the diagonal lines.";
+pub const LABEL_ENTROPY_MEASURE: &str = "Measure";
+pub const TIP_ENTROPY_MEASURE: &str = "Choose the entropy measure or the 0-1 test: \n- Permutation Entropy: Shannon entropy of the ordinal patterns of the selected state component, normalized to [0, 1]. Averaged over all non-escaping samples. \n- Topological Entropy: growth rate of the number of monotone laps of the n-th iterate. Only estimated for the Logistic, Tent, Gauss and Circle maps. \n- 0-1 Test: Gottwald-Melbourne test for chaos of the selected state component (K close to 0 for regular and 1 for chaotic dynamics). Averaged over all non-escaping samples.";
+pub const LABEL_PERMUTATION_ORDER: &str = "Order";
+pub const TIP_PERMUTATION_ORDER: &str = "Set the number of values of an ordinal pattern. The series should be much longer than order! steps.";
+pub const LABEL_PERMUTATION_DELAY: &str = "Delay";
+pub const TIP_PERMUTATION_DELAY: &str = "Set the number of steps between two values of an ordinal pattern or of the 0-1 test. Increase for densely sampled ODEs.";
+pub const LABEL_ENTROPY: &str = "Compute Entropy";
+pub const TIP_ENTROPY: &str = "Estimate the entropy for every parameter of the execution panel. With a parameter range the entropy curve has the same axis as the bifurcation diagram.";
+pub const LABEL_DENSITY_X: &str = "X Component";
+pub const TIP_DENSITY_X: &str = "Choose the state component that is binned along the x-axis.";
+pub const LABEL_DENSITY_Y: &str = "Y Component";
+pub const TIP_DENSITY_Y: &str = "Choose a second state component for a 2D histogram shown as heat map, or None for a 1D histogram shown as line.";
+pub const LABEL_DENSITY_BINS: &str = "Bins";
+pub const TIP_DENSITY_BINS: &str = "Set the number of bins along each axis. The bins span the extent of all visited states of all parameters.";
+pub const LABEL_DENSITY: &str = "Compute Density";
+pub const TIP_DENSITY: &str = "Iterate all samples for every parameter of the execution panel and bin every state after the transient steps. The counts are normalized to a density that integrates to one. Empty bins of a 2D histogram get the special color.";
+pub const LABEL_COPY_DENSITY: &str = "Copy CSV";
+pub const TIP_COPY_DENSITY: &str = "Copy the bin centers and densities of the shown histogram as comma separated values to the clipboard.";
+pub const LABEL_ROTATION: &str = "Compute Rotation";
+pub const TIP_ROTATION: &str = "Iterate the lift of the Circle map without the modulo for every parameter of the execution panel. The rotation number is the mean advance of the phase per step, averaged over all samples. Select a range of ω to plot the devil's staircase of mode-locked plateaus.";
+pub const LABEL_MAX_DELAY: &str = "Max Delay";
+pub const TIP_MAX_DELAY: &str = "Set the largest delay of the average mutual information curve.";
+pub const LABEL_AUTO_DELAY: &str = "Automatic Delay";
+pub const TIP_AUTO_DELAY: &str = "Choose the delay from the first minimum of the average mutual information. Discrete maps usually need a delay of one, since their mutual information decays without a minimum.";
+pub const LABEL_EMBEDDING_DELAY: &str = "Delay";
+pub const TIP_EMBEDDING_DELAY: &str = "Set the number of steps between two delay coordinates.";
+pub const LABEL_AUTO_DIMENSION: &str = "Automatic Dimension";
+pub const TIP_AUTO_DIMENSION: &str =
+    "Choose the smallest embedding dimension with less than 1% false nearest neighbours.";
+pub const LABEL_EMBEDDING_DIMENSION: &str = "Dimension";
+pub const TIP_EMBEDDING_DIMENSION: &str =
+    "Set the embedding dimension that is reported in the summary.";
+pub const LABEL_EMBEDDING: &str = "Compute Embedding";
+pub const TIP_EMBEDDING: &str = "Record the trajectory of the first non-escaping sample for every parameter of the execution panel and reconstruct its attractor from delay coordinates of the selected component. The left plot shows the first three delay coordinates, the right plot the first three components of the original states.";
+pub const LABEL_CSV_DATA: &str = "CSV Data";
+pub const TIP_CSV_DATA: &str = "Paste comma, semicolon or tab separated values with one column per state component (at most 4), or drop a CSV file onto the window. A header row is optional, a column named t or time holds the sample times. Lines starting with # are skipped.";
+pub const LABEL_IMPORT: &str = "Import";
+pub const TIP_IMPORT: &str = "Import the pasted data as a read-only trajectory. The plot replays one imported state per execution, so projections and colormaps work as for generated data. Chaotic functions need newly generated initial data.";
+pub const LABEL_ANALYSE_IMPORTED: &str = "Analyse Imported Data";
+pub const TIP_ANALYSE_IMPORTED: &str = "Use the imported trajectory instead of the chaotic function for the power spectrum, the trajectory dimension, recurrence plots, the permutation entropy, the 0-1 test and delay embeddings. Model-based analyses keep the chaotic function of the execute panel. The transient steps skip the first rows.";
+pub const LABEL_LATTICE_TOPOLOGY: &str = "Topology";
+pub const TIP_LATTICE_TOPOLOGY: &str = "Arrange the sites on a ring with two neighbours or on a 2D grid with four neighbours. Both have periodic boundaries.";
+pub const LABEL_LATTICE_SITES: &str = "Sites";
+pub const TIP_LATTICE_SITES: &str = "The number of sites of the ring or along each axis of the grid. Each site starts from one sample of the 1D initial distribution.";
+pub const LABEL_LATTICE_COUPLING: &str = "Coupling";
+pub const TIP_LATTICE_COUPLING: &str = "The diffusive coupling strength ε. Every site becomes (1 - ε) f(x) plus ε times the mean of f over its neighbours.";
+pub const LABEL_LATTICE_SNAPSHOT: &str = "Final Snapshot";
+pub const TIP_LATTICE_SNAPSHOT: &str =
+    "Show the grid after the last step instead of the space-time plot of all sites in row order.";
+pub const LABEL_LATTICE: &str = "Compute Lattice";
+pub const TIP_LATTICE: &str = "Couple copies of the chosen 1D map (Logistic, Tent, Gauss or Circle) for every parameter of the execution panel. The heat map shows the site values per step, the line plot the synchronization error, i.e. the standard deviation over all sites.";
+pub const LABEL_USE_ADJACENCY: &str = "Adjacency Matrix";
+pub const TIP_USE_ADJACENCY: &str = "Couple the oscillators through a weighted adjacency matrix instead of all-to-all. Enter one row per line with entries separated by spaces, commas or semicolons. Row i holds the couplings into oscillator i and the matrix needs one row per sample.";
+pub const LABEL_KURAMOTO: &str = "Compute Order Parameter";
+pub const TIP_KURAMOTO: &str = "Integrate the Kuramoto network for every coupling strength of the execution panel. The upper plot shows the order parameter r over time, the lower plot its mean after the transient over K, i.e. the synchronization transition.";
+pub const LABEL_NETWORK_MATRIX: &str = "Coupling Matrix";
+pub const TIP_NETWORK_MATRIX: &str = "One row per node with entries separated by spaces, commas or semicolons. Row i holds the couplings into node i, e.g. '0 1' and '1 0' couple two nodes mutually while '0 0' and '1 0' let node 2 follow the master node 1. Each node starts from one sample of the initial distribution.";
+pub const LABEL_NETWORK_VARIABLE: &str = "Coupled Variable";
+pub const TIP_NETWORK_VARIABLE: &str =
+    "The state variable through which the nodes are coupled. All other variables evolve freely.";
+pub const LABEL_NETWORK_COUPLING: &str = "Coupling";
+pub const TIP_NETWORK_COUPLING: &str = "The diffusive coupling strength σ. The coupled variable of node i gains σ times the weighted sum of its differences to all other nodes.";
+pub const LABEL_NETWORK: &str = "Compute Network";
+pub const TIP_NETWORK: &str = "Couple copies of the chosen differential system for every parameter of the execution panel. The upper plot shows the coupled variable of all nodes, the lower plot the synchronization error, i.e. the root mean square distance of the nodes to their mean state.";
+pub const LABEL_CONTROL_COMPONENT: &str = "Component";
+pub const TIP_CONTROL_COMPONENT: &str = "The plotted state component. Pyragas control feeds the delayed difference back into this component.";
+pub const LABEL_CONTROL_SWITCH_ON: &str = "Switch On";
+pub const TIP_CONTROL_SWITCH_ON: &str = "The recorded step at which the control is switched on. The plot shows the uncontrolled chaotic phase before and the controlled orbit after this step.";
+pub const LABEL_CONTROL_PERIOD: &str = "Period";
+pub const TIP_CONTROL_PERIOD: &str = "The period of the unstable orbit to stabilize. Newton's method finds the periodic orbits from the states of an uncontrolled trajectory.";
+pub const LABEL_CONTROL_ORBIT: &str = "Orbit";
+pub const TIP_CONTROL_ORBIT: &str = "The target among the orbits with the chosen period. Orbit 1 is approached most closely by the chaotic trajectory. Larger values fall back to the last orbit found.";
+pub const LABEL_CONTROL_PERTURBATION: &str = "Max Perturbation";
+pub const TIP_CONTROL_PERTURBATION: &str = "The largest allowed change of the map parameter (r of the Logistic map, a of the Hénon map). OGY control waits until the chaotic motion comes close enough to the orbit.";
+pub const LABEL_CONTROL_GAIN: &str = "Gain";
+pub const TIP_CONTROL_GAIN: &str = "The gain K of the delayed feedback K (x(t - τ) - x(t)).";
+pub const LABEL_CONTROL_DELAY: &str = "Delay";
+pub const TIP_CONTROL_DELAY: &str = "The delay τ of the feedback. It must match the period of the target orbit, the feedback vanishes once the orbit is stabilized.";
+pub const LABEL_CONTROL: &str = "Compute Control";
+pub const TIP_CONTROL: &str = "Stabilize an unstable periodic orbit for every parameter of the execution panel. Discrete maps use OGY control with small perturbations of the map parameter, flows the delayed feedback control of Pyragas. The lower plot shows the parameter perturbation or the feedback force.";